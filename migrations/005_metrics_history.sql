@@ -0,0 +1,26 @@
+-- Historical metrics for time-series charts
+-- Background sampler writes raw samples, maintenance rolls them up into coarser resolutions
+
+CREATE TABLE IF NOT EXISTS metric_samples (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    endpoint_id INTEGER NOT NULL,
+    node_id TEXT NOT NULL, -- ID nodu, nebo '_cluster' pro agregaci za celý cluster
+    node_name TEXT NOT NULL,
+    resolution INTEGER NOT NULL, -- délka bucketu v sekundách (30 = raw, 300, 3600)
+    ts INTEGER NOT NULL, -- unix timestamp (sekundy), u agregací začátek bucketu
+    cpu_percent REAL,
+    heap_percent REAL,
+    ram_percent REAL,
+    disk_percent REAL,
+    nodes INTEGER, -- jen cluster řádky
+    active_shards INTEGER,
+    unassigned_shards INTEGER,
+    FOREIGN KEY (endpoint_id) REFERENCES endpoints(id) ON DELETE CASCADE
+);
+
+-- Jeden řádek na bucket (rollup je díky tomu idempotentní)
+CREATE UNIQUE INDEX IF NOT EXISTS idx_metric_samples_series
+    ON metric_samples(endpoint_id, node_id, resolution, ts);
+
+-- Index pro mazání podle retence
+CREATE INDEX IF NOT EXISTS idx_metric_samples_retention ON metric_samples(resolution, ts);
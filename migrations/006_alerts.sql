@@ -0,0 +1,37 @@
+-- Alerting rules, incidents and notification channels
+-- Rules are evaluated by the metrics sampler for every sampled endpoint
+
+CREATE TABLE IF NOT EXISTS alert_rules (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK(kind IN ('cluster_status', 'node_heap', 'node_cpu', 'node_disk', 'unassigned_shards', 'docs_stalled')),
+    threshold REAL, -- procenta / počet shardů (cluster_status a docs_stalled nepoužívají)
+    target TEXT, -- 'yellow' / 'red' pro cluster_status, index pattern pro docs_stalled
+    duration_secs INTEGER NOT NULL DEFAULT 0, -- podmínka musí platit alespoň takto dlouho
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Incident = jedno spuštění pravidla pro endpoint a subjekt (node, cluster, pattern)
+CREATE TABLE IF NOT EXISTS alert_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    rule_id INTEGER NOT NULL,
+    endpoint_id INTEGER NOT NULL,
+    subject TEXT NOT NULL, -- ID nodu, '_cluster' nebo index pattern
+    message TEXT NOT NULL,
+    value REAL,
+    started_at INTEGER NOT NULL, -- unix timestamp (sekundy)
+    resolved_at INTEGER,
+    FOREIGN KEY (rule_id) REFERENCES alert_rules(id) ON DELETE CASCADE,
+    FOREIGN KEY (endpoint_id) REFERENCES endpoints(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_alert_events_open ON alert_events(resolved_at);
+CREATE INDEX IF NOT EXISTS idx_alert_events_started ON alert_events(started_at DESC);
+
+-- Notifikační kanály (jediný řádek)
+CREATE TABLE IF NOT EXISTS alert_settings (
+    id INTEGER PRIMARY KEY CHECK(id = 1),
+    webhook_url TEXT,
+    notify_command TEXT
+);
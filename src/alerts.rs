@@ -0,0 +1,639 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use crate::db::Database;
+use crate::db::models::{AlertRule, AlertSettings, Endpoint, MetricSample};
+use crate::es::EsClient;
+use crate::es::api::ClusterHealth;
+use crate::models::Watermarks;
+use crate::sampler::CLUSTER_NODE_ID;
+
+/// Typy pravidel a jejich popisky
+pub const RULE_KINDS: [(&str, &str); 6] = [
+    ("cluster_status", "Cluster status"),
+    ("node_heap", "Node heap %"),
+    ("node_cpu", "Node CPU %"),
+    ("node_disk", "Node disk %"),
+    ("unassigned_shards", "Unassigned shards"),
+    ("docs_stalled", "Doc count stopped growing"),
+];
+
+/// Úrovně disk watermarku, proti kterým lze hlídat `node_disk` místo pevného prahu
+pub const WATERMARK_LEVELS: [&str; 3] = ["low", "high", "flood_stage"];
+
+const NOTIFY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Po kolika po sobě jdoucích vzorcích bez subjektu (node opustil cluster) se incident uzavře
+const GONE_AFTER_SAMPLES: u32 = 3;
+
+pub fn kind_label(kind: &str) -> &'static str {
+    RULE_KINDS.iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, label)| *label)
+        .unwrap_or("Unknown")
+}
+
+/// Typy s číselným prahem (`node_disk` může místo něj použít watermark clusteru)
+pub fn needs_threshold(kind: &str) -> bool {
+    matches!(kind, "node_heap" | "node_cpu" | "node_disk" | "unassigned_shards")
+}
+
+pub fn format_duration(secs: i64) -> String {
+    if secs <= 0 {
+        "0s".to_string()
+    } else if secs % 3600 == 0 {
+        format!("{}h", secs / 3600)
+    } else if secs % 60 == 0 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Lidsky čitelná podmínka pravidla, např. "Node heap > 85% for 2m"
+pub fn describe(rule: &AlertRule) -> String {
+    let threshold = rule.threshold.unwrap_or(0.0);
+    let condition = match rule.kind.as_str() {
+        "cluster_status" if rule.target.as_deref() == Some("red") => "Cluster status is red".to_string(),
+        "cluster_status" => "Cluster status != green".to_string(),
+        "node_heap" => format!("Node heap > {}%", threshold),
+        "node_cpu" => format!("Node CPU > {}%", threshold),
+        "node_disk" if rule.threshold.is_none() => {
+            format!("Node disk > {} watermark", rule.target.as_deref().unwrap_or("low").replace('_', " "))
+        }
+        "node_disk" => format!("Node disk > {}%", threshold),
+        "unassigned_shards" => format!("Unassigned shards > {}", threshold),
+        "docs_stalled" => format!("Doc count of {} not growing", rule.target.as_deref().unwrap_or("*")),
+        other => other.to_string(),
+    };
+
+    if rule.duration_secs > 0 {
+        format!("{} for {}", condition, format_duration(rule.duration_secs))
+    } else {
+        condition
+    }
+}
+
+/// Data, proti kterým se pravidla vyhodnocují (stejný vzorek jako historie metrik)
+pub struct AlertSnapshot<'a> {
+    pub health: &'a ClusterHealth,
+    pub samples: &'a [MetricSample],
+    pub doc_counts: HashMap<String, u64>,
+    pub watermarks: Watermarks,                 // efektivní disk watermarky clusteru
+    pub disk_totals: HashMap<String, u64>,      // velikost disku podle node ID (pro watermark v bajtech)
+}
+
+/// Stav podmínky pro jeden subjekt (node, cluster, pattern)
+#[derive(Debug, PartialEq)]
+struct Observation {
+    subject: String,
+    value: Option<f64>,
+    active: bool,
+    detail: String,
+}
+
+fn observe(rule: &AlertRule, snapshot: &AlertSnapshot, previous_docs: Option<u64>) -> Vec<Observation> {
+    let threshold = rule.threshold.unwrap_or(0.0);
+    let health = snapshot.health;
+
+    match rule.kind.as_str() {
+        "cluster_status" => {
+            let active = match rule.target.as_deref() {
+                Some("red") => health.status == "red",
+                _ => health.status != "green",
+            };
+            vec![Observation {
+                subject: CLUSTER_NODE_ID.to_string(),
+                value: None,
+                active,
+                detail: format!("cluster {} is {}", health.cluster_name, health.status),
+            }]
+        }
+        "node_disk" if uses_watermark(rule) => {
+            let level = rule.target.as_deref().unwrap_or("low");
+            let Some(watermark) = snapshot.watermarks.level(level) else {
+                return Vec::new();
+            };
+            snapshot.samples.iter()
+                .filter(|s| s.node_id != CLUSTER_NODE_ID)
+                .map(|s| {
+                    let total = snapshot.disk_totals.get(&s.node_id).copied().unwrap_or(0);
+                    let limit = watermark.used_percent(total);
+                    Observation {
+                        subject: s.node_id.clone(),
+                        value: s.disk_percent,
+                        active: s.disk_percent.is_some_and(|v| v > limit),
+                        detail: format!("{} disk {:.0}% (> {} watermark {})",
+                            s.node_name, s.disk_percent.unwrap_or(0.0), level, watermark.label()),
+                    }
+                })
+                .collect()
+        }
+        "node_heap" | "node_cpu" | "node_disk" => {
+            let metric = rule.kind.trim_start_matches("node_");
+            snapshot.samples.iter()
+                .filter(|s| s.node_id != CLUSTER_NODE_ID)
+                .map(|s| {
+                    let value = match metric {
+                        "heap" => s.heap_percent,
+                        "cpu" => s.cpu_percent,
+                        _ => s.disk_percent,
+                    };
+                    Observation {
+                        subject: s.node_id.clone(),
+                        value,
+                        active: value.is_some_and(|v| v > threshold),
+                        detail: format!("{} {} {:.0}% (> {}%)", s.node_name, metric, value.unwrap_or(0.0), threshold),
+                    }
+                })
+                .collect()
+        }
+        "unassigned_shards" => {
+            let value = health.unassigned_shards as f64;
+            vec![Observation {
+                subject: CLUSTER_NODE_ID.to_string(),
+                value: Some(value),
+                active: value > threshold,
+                detail: format!("{} unassigned shards", health.unassigned_shards),
+            }]
+        }
+        "docs_stalled" => {
+            let pattern = rule.target.clone().unwrap_or_else(|| "*".to_string());
+            let Some(count) = snapshot.doc_counts.get(&pattern).copied() else {
+                return Vec::new();
+            };
+            vec![Observation {
+                active: previous_docs.is_some_and(|previous| count <= previous),
+                value: Some(count as f64),
+                detail: format!("{} doc count stuck at {}", pattern, count),
+                subject: pattern,
+            }]
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pending {
+    since: i64,
+    firing: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Transition {
+    None,
+    Fire,
+    Resolve,
+}
+
+/// Jeden krok stavového automatu: podmínka musí platit `duration` sekund, než se pravidlo spustí
+fn step(pending: Option<Pending>, active: bool, now: i64, duration: i64) -> (Option<Pending>, Transition) {
+    match (pending, active) {
+        (None, false) => (None, Transition::None),
+        (Some(p), false) => (None, if p.firing { Transition::Resolve } else { Transition::None }),
+        (current, true) => {
+            let mut p = current.unwrap_or(Pending { since: now, firing: false });
+            if !p.firing && now - p.since >= duration {
+                p.firing = true;
+                return (Some(p), Transition::Fire);
+            }
+            (Some(p), Transition::None)
+        }
+    }
+}
+
+#[derive(Default)]
+struct EvaluatorState {
+    pending: HashMap<(i64, i64, String), Pending>,   // (endpoint, pravidlo, subjekt)
+    doc_counts: HashMap<(i64, i64), u64>,            // poslední počet dokumentů pro docs_stalled
+    missing: HashMap<(i64, i64, String), u32>,       // počet vzorků, ve kterých subjekt chyběl
+    seeded: HashSet<i64>,                            // endpointy s načtenými otevřenými incidenty
+}
+
+impl EvaluatorState {
+    /// Subjekty pravidla, které chyběly `GONE_AFTER_SAMPLES` vzorků po sobě; vrací ty s otevřeným incidentem
+    fn expire_missing(&mut self, endpoint_id: i64, rule_id: i64, seen: &HashSet<String>) -> Vec<String> {
+        self.missing.retain(|(e, r, subject), _| *e != endpoint_id || *r != rule_id || !seen.contains(subject));
+
+        let absent: Vec<(i64, i64, String)> = self.pending.keys()
+            .filter(|(e, r, subject)| *e == endpoint_id && *r == rule_id && !seen.contains(subject))
+            .cloned()
+            .collect();
+
+        let mut resolved = Vec::new();
+        for key in absent {
+            let count = self.missing.entry(key.clone()).or_default();
+            *count += 1;
+            if *count < GONE_AFTER_SAMPLES {
+                continue;
+            }
+            self.missing.remove(&key);
+            if self.pending.remove(&key).is_some_and(|p| p.firing) {
+                resolved.push(key.2);
+            }
+        }
+        resolved
+    }
+}
+
+/// Vyhodnocuje pravidla po každém vzorku metrik
+#[derive(Default)]
+pub struct AlertEvaluator {
+    state: Mutex<EvaluatorState>,
+}
+
+impl AlertEvaluator {
+    pub async fn evaluate(
+        &self,
+        db: &Database,
+        endpoint: &Endpoint,
+        client: &EsClient,
+        health: &ClusterHealth,
+        samples: &[MetricSample],
+        now: i64,
+    ) -> anyhow::Result<()> {
+        let rules: Vec<AlertRule> = db.get_alert_rules().await?
+            .into_iter()
+            .filter(|r| r.enabled)
+            .collect();
+
+        let mut state = self.state.lock().await;
+
+        // Po restartu navážeme na otevřené incidenty, aby se znovu nezakládaly
+        if state.seeded.insert(endpoint.id) {
+            for event in db.get_open_alert_events(Some(endpoint.id)).await? {
+                state.pending.insert(
+                    (endpoint.id, event.rule_id, event.subject),
+                    Pending { since: event.started_at, firing: true },
+                );
+            }
+        }
+
+        let rule_ids: HashSet<i64> = rules.iter().map(|r| r.id).collect();
+        state.pending.retain(|(e, rule_id, _), _| *e != endpoint.id || rule_ids.contains(rule_id));
+        state.doc_counts.retain(|(e, rule_id), _| *e != endpoint.id || rule_ids.contains(rule_id));
+        state.missing.retain(|(e, rule_id, _), _| *e != endpoint.id || rule_ids.contains(rule_id));
+
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let mut doc_counts = HashMap::new();
+        for rule in rules.iter().filter(|r| r.kind == "docs_stalled") {
+            let pattern = rule.target.clone().unwrap_or_else(|| "*".to_string());
+            if doc_counts.contains_key(&pattern) {
+                continue;
+            }
+            match load_doc_count(client, &pattern).await {
+                Ok(count) => {
+                    doc_counts.insert(pattern, count);
+                }
+                Err(e) => tracing::warn!("Failed to load doc count for {}: {}", pattern, e),
+            }
+        }
+
+        // Watermarky a velikosti disků jen pro `node_disk` pravidla bez pevného prahu
+        let mut watermarks = Watermarks::default();
+        let mut disk_totals = HashMap::new();
+        let mut watermarks_loaded = false;
+        if rules.iter().any(uses_watermark) {
+            match tokio::try_join!(load_watermarks(client), load_disk_totals(client)) {
+                Ok((loaded, totals)) => {
+                    watermarks = loaded;
+                    disk_totals = totals;
+                    watermarks_loaded = true;
+                }
+                Err(e) => tracing::warn!("Failed to load disk watermarks: {}", e),
+            }
+        }
+
+        let snapshot = AlertSnapshot { health, samples, doc_counts, watermarks, disk_totals };
+        let settings = db.get_alert_settings().await.unwrap_or_default();
+
+        for rule in &rules {
+            // Data pravidla se nepodařilo načíst - stav nechat beze změny, jinak by incident zbytečně skončil
+            let loaded = match rule.kind.as_str() {
+                "docs_stalled" => snapshot.doc_counts.contains_key(rule.target.as_deref().unwrap_or("*")),
+                _ => !uses_watermark(rule) || watermarks_loaded,
+            };
+            if !loaded {
+                continue;
+            }
+
+            let previous_docs = state.doc_counts.get(&(endpoint.id, rule.id)).copied();
+            let observations = observe(rule, &snapshot, previous_docs);
+            let mut seen = HashSet::new();
+
+            for obs in observations {
+                if rule.kind == "docs_stalled"
+                    && let Some(count) = obs.value
+                {
+                    state.doc_counts.insert((endpoint.id, rule.id), count as u64);
+                }
+
+                let key = (endpoint.id, rule.id, obs.subject.clone());
+                let (next, transition) = step(state.pending.get(&key).copied(), obs.active, now, rule.duration_secs);
+                match next {
+                    Some(p) => state.pending.insert(key, p),
+                    None => state.pending.remove(&key),
+                };
+
+                let message = format!("{}: {}", rule.name, obs.detail);
+                match transition {
+                    Transition::Fire => {
+                        db.open_alert_event(rule.id, endpoint.id, &obs.subject, &message, obs.value, now).await?;
+                        notify(&settings, endpoint, rule, &message, obs.value, true).await;
+                    }
+                    Transition::Resolve => {
+                        db.resolve_alert_event(rule.id, endpoint.id, &obs.subject, now).await?;
+                        notify(&settings, endpoint, rule, &message, obs.value, false).await;
+                    }
+                    Transition::None => {}
+                }
+                seen.insert(obs.subject);
+            }
+
+            // Subjekt zmizel (např. node opustil cluster) - incident uzavřeme až po několika vzorcích
+            for subject in state.expire_missing(endpoint.id, rule.id, &seen) {
+                db.resolve_alert_event(rule.id, endpoint.id, &subject, now).await?;
+                let message = format!("{}: {} no longer reported", rule.name, subject);
+                notify(&settings, endpoint, rule, &message, None, false).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `node_disk` bez pevného prahu hlídá watermark clusteru
+fn uses_watermark(rule: &AlertRule) -> bool {
+    rule.kind == "node_disk" && rule.threshold.is_none()
+}
+
+async fn load_doc_count(client: &EsClient, pattern: &str) -> anyhow::Result<u64> {
+    let indices: Vec<Value> = client.get(&format!("/_cat/indices/{}?format=json&h=docs.count", pattern)).await?;
+    Ok(indices.iter()
+        .filter_map(|i| i["docs.count"].as_str()?.parse::<u64>().ok())
+        .sum())
+}
+
+/// Efektivní watermarky stejně jako advisor (transient > persistent > defaults)
+async fn load_watermarks(client: &EsClient) -> anyhow::Result<Watermarks> {
+    let settings: Value = client.get("/_cluster/settings?include_defaults=true&flat_settings=true").await?;
+    Ok(Watermarks::from_settings(&settings))
+}
+
+async fn load_disk_totals(client: &EsClient) -> anyhow::Result<HashMap<String, u64>> {
+    let stats: Value = client.get("/_nodes/stats/fs?filter_path=nodes.*.fs.total.total_in_bytes").await?;
+    Ok(stats["nodes"].as_object()
+        .map(|nodes| nodes.iter()
+            .filter_map(|(id, node)| Some((id.clone(), node["fs"]["total"]["total_in_bytes"].as_u64()?)))
+            .collect())
+        .unwrap_or_default())
+}
+
+/// Odešle notifikaci na webhook a spustí notifikační příkaz (chyby jen loguje)
+pub async fn notify(
+    settings: &AlertSettings,
+    endpoint: &Endpoint,
+    rule: &AlertRule,
+    message: &str,
+    value: Option<f64>,
+    firing: bool,
+) {
+    let state = if firing { "firing" } else { "resolved" };
+    let title = format!("Elastic Explorer [{}] {} {}", endpoint.name, rule.name, state);
+
+    if let Some(url) = settings.webhook_url.as_deref().filter(|u| !u.trim().is_empty()) {
+        let payload = json!({
+            "endpoint": endpoint.name,
+            "rule": rule.name,
+            "kind": rule.kind,
+            "condition": describe(rule),
+            "state": state,
+            "message": message,
+            "value": value,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Err(e) = send_webhook(url, &payload).await {
+            tracing::warn!("Alert webhook failed: {}", e);
+        }
+    }
+
+    if let Some(command) = settings.notify_command.as_deref().filter(|c| !c.trim().is_empty())
+        && let Err(e) = run_command(command, &title, message, state).await
+    {
+        tracing::warn!("Alert notify command failed: {}", e);
+    }
+}
+
+pub async fn send_webhook(url: &str, payload: &Value) -> anyhow::Result<()> {
+    let response = reqwest::Client::builder()
+        .timeout(NOTIFY_TIMEOUT)
+        .build()?
+        .post(url)
+        .json(payload)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(anyhow::anyhow!("Webhook returned HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+/// Spustí příkaz bez shellu; titulek a zpráva se předají jako poslední dva argumenty
+/// (např. `notify-send`) a také v proměnných ALERT_TITLE / ALERT_MESSAGE / ALERT_STATE
+pub async fn run_command(command: &str, title: &str, message: &str, state: &str) -> anyhow::Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().ok_or_else(|| anyhow::anyhow!("Empty command"))?;
+
+    let mut child = tokio::process::Command::new(program)
+        .args(parts)
+        .arg(title)
+        .arg(message)
+        .env("ALERT_TITLE", title)
+        .env("ALERT_MESSAGE", message)
+        .env("ALERT_STATE", state)
+        .kill_on_drop(true)
+        .spawn()?;
+
+    match tokio::time::timeout(NOTIFY_TIMEOUT, child.wait()).await {
+        Ok(status) => {
+            let status = status?;
+            if !status.success() {
+                return Err(anyhow::anyhow!("Command exited with {}", status));
+            }
+            Ok(())
+        }
+        Err(_) => Err(anyhow::anyhow!("Command timed out")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: &str, threshold: Option<f64>, target: Option<&str>, duration_secs: i64) -> AlertRule {
+        AlertRule {
+            id: 1,
+            name: "test".to_string(),
+            kind: kind.to_string(),
+            threshold,
+            target: target.map(|t| t.to_string()),
+            duration_secs,
+            enabled: true,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn health(status: &str) -> ClusterHealth {
+        ClusterHealth {
+            cluster_name: "c".to_string(),
+            status: status.to_string(),
+            timed_out: false,
+            number_of_nodes: 1,
+            number_of_data_nodes: 1,
+            active_primary_shards: 1,
+            active_shards: 1,
+            relocating_shards: 0,
+            initializing_shards: 0,
+            unassigned_shards: 2,
+        }
+    }
+
+    fn sample(node_id: &str, heap: Option<f64>) -> MetricSample {
+        MetricSample {
+            endpoint_id: 1,
+            node_id: node_id.to_string(),
+            node_name: format!("{}-name", node_id),
+            resolution: 30,
+            ts: 0,
+            cpu_percent: None,
+            heap_percent: heap,
+            ram_percent: None,
+            disk_percent: None,
+            nodes: None,
+            active_shards: None,
+            unassigned_shards: None,
+        }
+    }
+
+    #[test]
+    fn test_observes_node_and_cluster_conditions() {
+        let health = health("yellow");
+        let samples = vec![sample("a", Some(90.0)), sample("b", Some(50.0)), sample(CLUSTER_NODE_ID, Some(70.0))];
+        let snapshot = AlertSnapshot {
+            health: &health,
+            samples: &samples,
+            doc_counts: HashMap::new(),
+            watermarks: Watermarks::default(),
+            disk_totals: HashMap::new(),
+        };
+
+        let heap = observe(&rule("node_heap", Some(85.0), None, 0), &snapshot, None);
+        assert_eq!(heap.len(), 2);
+        assert!(heap.iter().find(|o| o.subject == "a").unwrap().active);
+        assert!(!heap.iter().find(|o| o.subject == "b").unwrap().active);
+
+        assert!(observe(&rule("cluster_status", None, Some("yellow"), 0), &snapshot, None)[0].active);
+        assert!(!observe(&rule("cluster_status", None, Some("red"), 0), &snapshot, None)[0].active);
+        assert!(observe(&rule("unassigned_shards", Some(0.0), None, 0), &snapshot, None)[0].active);
+    }
+
+    #[test]
+    fn test_node_disk_uses_cluster_watermarks() {
+        const GB: u64 = 1024 * 1024 * 1024;
+        let health = health("green");
+        let mut samples = vec![sample("a", None), sample("b", None)];
+        samples[0].disk_percent = Some(82.0);
+        samples[1].disk_percent = Some(96.0);
+        let settings = serde_json::json!({
+            "transient": { "cluster.routing.allocation.disk.watermark.low": "80%" },
+            "persistent": { "cluster.routing.allocation.disk.watermark.low": "90%" },
+            "defaults": { "cluster.routing.allocation.disk.watermark.flood_stage": "5gb" }
+        });
+        let snapshot = AlertSnapshot {
+            health: &health,
+            samples: &samples,
+            doc_counts: HashMap::new(),
+            watermarks: Watermarks::from_settings(&settings),
+            disk_totals: HashMap::from([("a".to_string(), 100 * GB), ("b".to_string(), 100 * GB)]),
+        };
+
+        // Transient low watermark (80 %) má přednost před persistent
+        let low = observe(&rule("node_disk", None, Some("low"), 0), &snapshot, None);
+        assert!(low.iter().all(|o| o.active));
+
+        // Flood stage jako volné místo: 5 GB ze 100 GB = 95 % využití
+        let flood = observe(&rule("node_disk", None, Some("flood_stage"), 0), &snapshot, None);
+        assert!(!flood.iter().find(|o| o.subject == "a").unwrap().active);
+        assert!(flood.iter().find(|o| o.subject == "b").unwrap().active);
+
+        // Pevný práh se vyhodnocuje jako dřív
+        assert!(!observe(&rule("node_disk", Some(85.0), None, 0), &snapshot, None)[0].active);
+    }
+
+    #[test]
+    fn test_docs_stalled_needs_previous_count() {
+        let health = health("green");
+        let doc_counts = HashMap::from([("logs-*".to_string(), 100)]);
+        let snapshot = AlertSnapshot {
+            health: &health,
+            samples: &[],
+            doc_counts,
+            watermarks: Watermarks::default(),
+            disk_totals: HashMap::new(),
+        };
+        let r = rule("docs_stalled", None, Some("logs-*"), 0);
+
+        assert!(!observe(&r, &snapshot, None)[0].active);
+        assert!(observe(&r, &snapshot, Some(100))[0].active);
+        assert!(!observe(&r, &snapshot, Some(90))[0].active);
+    }
+
+    #[test]
+    fn test_fires_after_duration_and_resolves() {
+        let (p, t) = step(None, true, 100, 120);
+        assert_eq!(t, Transition::None);
+        let (p, t) = step(p, true, 200, 120);
+        assert_eq!(t, Transition::None);
+        let (p, t) = step(p, true, 220, 120);
+        assert_eq!(t, Transition::Fire);
+        let (p, t) = step(p, true, 250, 120);
+        assert_eq!(t, Transition::None);
+        let (p, t) = step(p, false, 280, 120);
+        assert_eq!((p, t), (None, Transition::Resolve));
+
+        // Krátký výkyv pod dobou trvání nic nespustí
+        let (p, _) = step(None, true, 0, 120);
+        assert_eq!(step(p, false, 30, 120), (None, Transition::None));
+    }
+
+    #[test]
+    fn test_resolves_missing_subject_after_several_samples() {
+        let mut state = EvaluatorState::default();
+        let key = (1, 1, "a".to_string());
+        state.pending.insert(key.clone(), Pending { since: 0, firing: true });
+        let none = HashSet::new();
+
+        // Jeden chybějící vzorek (výpadek ES) incident neuzavře
+        assert!(state.expire_missing(1, 1, &none).is_empty());
+        assert!(state.expire_missing(1, 1, &HashSet::from(["a".to_string()])).is_empty());
+        assert!(state.expire_missing(1, 1, &none).is_empty());
+        assert!(state.expire_missing(1, 1, &none).is_empty());
+        assert_eq!(state.expire_missing(1, 1, &none), vec!["a".to_string()]);
+        assert!(!state.pending.contains_key(&key));
+        assert!(state.missing.is_empty());
+    }
+
+    #[test]
+    fn test_describes_rules() {
+        assert_eq!(describe(&rule("cluster_status", None, Some("yellow"), 120)), "Cluster status != green for 2m");
+        assert_eq!(describe(&rule("node_heap", Some(85.0), None, 0)), "Node heap > 85%");
+        assert_eq!(describe(&rule("node_disk", None, Some("flood_stage"), 0)), "Node disk > flood stage watermark");
+        assert_eq!(format_duration(90), "90s");
+        assert_eq!(format_duration(3600), "1h");
+    }
+}
@@ -142,11 +142,11 @@ pub fn init_directories() -> Result<()> {
         fs::rename(&legacy_db, &target_db)
             .context("Failed to move legacy database to app root")?;
     }
-    if legacy_data_dir.exists() {
-        if fs::read_dir(&legacy_data_dir).map(|mut i| i.next().is_none()).unwrap_or(false) {
-            fs::remove_dir(&legacy_data_dir)
-                .context("Failed to remove legacy data directory")?;
-        }
+    if legacy_data_dir.exists()
+        && fs::read_dir(&legacy_data_dir).map(|mut i| i.next().is_none()).unwrap_or(false)
+    {
+        fs::remove_dir(&legacy_data_dir)
+            .context("Failed to remove legacy data directory")?;
     }
 
     Ok(())
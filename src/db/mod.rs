@@ -10,7 +10,7 @@ use std::str::FromStr;
 use base64::Engine;
 
 use crate::config;
-use crate::db::models::{CreateEndpoint, Endpoint, UpdateEndpoint, SavedQuery, ConsoleHistory, CreateConsoleHistory};
+use crate::db::models::{CreateEndpoint, Endpoint, UpdateEndpoint, SavedQuery, ConsoleHistory, CreateConsoleHistory, MetricSample, MetricPoint, AlertRule, CreateAlertRule, AlertEvent, AlertSettings};
 
 pub struct Database {
     pool: SqlitePool,
@@ -87,6 +87,20 @@ impl Database {
                 .context("Failed to run migration 004")?;
         }
 
+        // Migration 005 - Metrics history
+        let migration_005 = include_str!("../../migrations/005_metrics_history.sql");
+        sqlx::raw_sql(migration_005)
+            .execute(pool)
+            .await
+            .context("Failed to run migration 005")?;
+
+        // Migration 006 - Alerting
+        let migration_006 = include_str!("../../migrations/006_alerts.sql");
+        sqlx::raw_sql(migration_006)
+            .execute(pool)
+            .await
+            .context("Failed to run migration 006")?;
+
         tracing::info!("Migrations completed successfully");
         Ok(())
     }
@@ -368,4 +382,266 @@ impl Database {
 
         Ok(())
     }
+
+    /// Uloží vzorky metrik (existující bucket přepíše)
+    pub async fn save_metric_samples(&self, samples: &[MetricSample]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for sample in samples {
+            sqlx::query(
+                "INSERT OR REPLACE INTO metric_samples
+                 (endpoint_id, node_id, node_name, resolution, ts, cpu_percent, heap_percent, ram_percent, disk_percent, nodes, active_shards, unassigned_shards)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(sample.endpoint_id)
+            .bind(&sample.node_id)
+            .bind(&sample.node_name)
+            .bind(sample.resolution)
+            .bind(sample.ts)
+            .bind(sample.cpu_percent)
+            .bind(sample.heap_percent)
+            .bind(sample.ram_percent)
+            .bind(sample.disk_percent)
+            .bind(sample.nodes)
+            .bind(sample.active_shards)
+            .bind(sample.unassigned_shards)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to save metric sample")?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Agreguje vzorky z jemnějšího rozlišení do hrubšího (jen kompletní buckety v daném rozsahu)
+    pub async fn rollup_metric_samples(&self, source: i64, target: i64, since: i64, until: i64) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT OR REPLACE INTO metric_samples
+             (endpoint_id, node_id, node_name, resolution, ts, cpu_percent, heap_percent, ram_percent, disk_percent, nodes, active_shards, unassigned_shards)
+             SELECT endpoint_id, node_id, MAX(node_name), ?, (ts / ?) * ?,
+                    AVG(cpu_percent), AVG(heap_percent), AVG(ram_percent), AVG(disk_percent),
+                    MAX(nodes), CAST(ROUND(AVG(active_shards)) AS INTEGER), MAX(unassigned_shards)
+             FROM metric_samples
+             WHERE resolution = ? AND ts >= ? AND ts < ?
+             GROUP BY endpoint_id, node_id, ts / ?"
+        )
+        .bind(target)
+        .bind(target)
+        .bind(target)
+        .bind(source)
+        .bind(since)
+        .bind(until)
+        .bind(target)
+        .execute(&self.pool)
+        .await
+        .context("Failed to roll up metric samples")?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Smaže vzorky daného rozlišení starší než `before`
+    pub async fn purge_metric_samples(&self, resolution: i64, before: i64) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM metric_samples WHERE resolution = ? AND ts < ?")
+            .bind(resolution)
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .context("Failed to purge metric samples")?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Získá časovou řadu jednoho nodu (nebo `_cluster`) v daném rozlišení
+    pub async fn get_metric_points(
+        &self,
+        endpoint_id: i64,
+        node_id: &str,
+        resolution: i64,
+        since: i64,
+    ) -> Result<Vec<MetricPoint>> {
+        let points = sqlx::query_as::<_, MetricPoint>(
+            "SELECT ts, cpu_percent, heap_percent, ram_percent, disk_percent, nodes, active_shards, unassigned_shards
+             FROM metric_samples
+             WHERE endpoint_id = ? AND node_id = ? AND resolution = ? AND ts >= ?
+             ORDER BY ts"
+        )
+        .bind(endpoint_id)
+        .bind(node_id)
+        .bind(resolution)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch metric points")?;
+
+        Ok(points)
+    }
+
+    /// Získá všechna pravidla alertů
+    pub async fn get_alert_rules(&self) -> Result<Vec<AlertRule>> {
+        let rules = sqlx::query_as::<_, AlertRule>(
+            "SELECT * FROM alert_rules ORDER BY name"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch alert rules")?;
+
+        Ok(rules)
+    }
+
+    /// Vytvoří nové pravidlo
+    pub async fn create_alert_rule(&self, rule: CreateAlertRule) -> Result<i64> {
+        let result = sqlx::query(
+            "INSERT INTO alert_rules (name, kind, threshold, target, duration_secs)
+             VALUES (?, ?, ?, ?, ?)"
+        )
+        .bind(&rule.name)
+        .bind(&rule.kind)
+        .bind(rule.threshold)
+        .bind(&rule.target)
+        .bind(rule.duration_secs)
+        .execute(&self.pool)
+        .await
+        .context("Failed to insert alert rule")?;
+
+        tracing::info!("Created alert rule: {} (id: {})", rule.name, result.last_insert_rowid());
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Zapne / vypne pravidlo (vypnutím se uzavřou jeho otevřené incidenty)
+    pub async fn set_alert_rule_enabled(&self, id: i64, enabled: bool, now: i64) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE alert_rules SET enabled = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to update alert rule")?;
+
+        if !enabled {
+            sqlx::query("UPDATE alert_events SET resolved_at = ? WHERE rule_id = ? AND resolved_at IS NULL")
+                .bind(now)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .context("Failed to resolve alert events")?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Smaže pravidlo (incidenty se smažou kaskádou)
+    pub async fn delete_alert_rule(&self, id: i64) -> Result<()> {
+        sqlx::query("DELETE FROM alert_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete alert rule")?;
+
+        tracing::info!("Deleted alert rule: {}", id);
+        Ok(())
+    }
+
+    /// Založí incident
+    pub async fn open_alert_event(
+        &self,
+        rule_id: i64,
+        endpoint_id: i64,
+        subject: &str,
+        message: &str,
+        value: Option<f64>,
+        started_at: i64,
+    ) -> Result<i64> {
+        let result = sqlx::query(
+            "INSERT INTO alert_events (rule_id, endpoint_id, subject, message, value, started_at)
+             VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(rule_id)
+        .bind(endpoint_id)
+        .bind(subject)
+        .bind(message)
+        .bind(value)
+        .bind(started_at)
+        .execute(&self.pool)
+        .await
+        .context("Failed to insert alert event")?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    /// Uzavře otevřený incident pravidla pro daný subjekt
+    pub async fn resolve_alert_event(&self, rule_id: i64, endpoint_id: i64, subject: &str, resolved_at: i64) -> Result<()> {
+        sqlx::query(
+            "UPDATE alert_events SET resolved_at = ?
+             WHERE rule_id = ? AND endpoint_id = ? AND subject = ? AND resolved_at IS NULL"
+        )
+        .bind(resolved_at)
+        .bind(rule_id)
+        .bind(endpoint_id)
+        .bind(subject)
+        .execute(&self.pool)
+        .await
+        .context("Failed to resolve alert event")?;
+
+        Ok(())
+    }
+
+    /// Otevřené incidenty (volitelně jen pro endpoint)
+    pub async fn get_open_alert_events(&self, endpoint_id: Option<i64>) -> Result<Vec<AlertEvent>> {
+        let events = sqlx::query_as::<_, AlertEvent>(
+            "SELECT * FROM alert_events
+             WHERE resolved_at IS NULL AND (? IS NULL OR endpoint_id = ?)
+             ORDER BY started_at DESC"
+        )
+        .bind(endpoint_id)
+        .bind(endpoint_id)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch open alert events")?;
+
+        Ok(events)
+    }
+
+    /// Posledních N incidentů endpointu
+    pub async fn get_alert_events(&self, endpoint_id: i64, limit: i64) -> Result<Vec<AlertEvent>> {
+        let events = sqlx::query_as::<_, AlertEvent>(
+            "SELECT * FROM alert_events WHERE endpoint_id = ? ORDER BY started_at DESC LIMIT ?"
+        )
+        .bind(endpoint_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .context("Failed to fetch alert events")?;
+
+        Ok(events)
+    }
+
+    /// Notifikační kanály (prázdné, pokud ještě nebyly uloženy)
+    pub async fn get_alert_settings(&self) -> Result<AlertSettings> {
+        let settings = sqlx::query_as::<_, AlertSettings>(
+            "SELECT webhook_url, notify_command FROM alert_settings WHERE id = 1"
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("Failed to fetch alert settings")?;
+
+        Ok(settings.unwrap_or_default())
+    }
+
+    /// Uloží notifikační kanály
+    pub async fn save_alert_settings(&self, settings: &AlertSettings) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO alert_settings (id, webhook_url, notify_command) VALUES (1, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET webhook_url = excluded.webhook_url, notify_command = excluded.notify_command"
+        )
+        .bind(&settings.webhook_url)
+        .bind(&settings.notify_command)
+        .execute(&self.pool)
+        .await
+        .context("Failed to save alert settings")?;
+
+        Ok(())
+    }
 }
@@ -76,3 +76,75 @@ pub struct CreateConsoleHistory {
     pub response_status: Option<i32>,
     pub response_body: Option<String>,
 }
+
+/// Jeden vzorek metrik nodu nebo clusteru (`node_id = "_cluster"`)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MetricSample {
+    pub endpoint_id: i64,
+    pub node_id: String,
+    pub node_name: String,
+    pub resolution: i64,
+    pub ts: i64,
+    pub cpu_percent: Option<f64>,
+    pub heap_percent: Option<f64>,
+    pub ram_percent: Option<f64>,
+    pub disk_percent: Option<f64>,
+    pub nodes: Option<i64>,
+    pub active_shards: Option<i64>,
+    pub unassigned_shards: Option<i64>,
+}
+
+/// Bod časové řady pro grafy
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MetricPoint {
+    pub ts: i64,
+    pub cpu_percent: Option<f64>,
+    pub heap_percent: Option<f64>,
+    pub ram_percent: Option<f64>,
+    pub disk_percent: Option<f64>,
+    pub nodes: Option<i64>,
+    pub active_shards: Option<i64>,
+    pub unassigned_shards: Option<i64>,
+}
+
+/// Pravidlo pro alerting
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AlertRule {
+    pub id: i64,
+    pub name: String,
+    pub kind: String,
+    pub threshold: Option<f64>,
+    pub target: Option<String>,
+    pub duration_secs: i64,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAlertRule {
+    pub name: String,
+    pub kind: String,
+    pub threshold: Option<f64>,
+    pub target: Option<String>,
+    pub duration_secs: i64,
+}
+
+/// Incident spuštěného pravidla (`resolved_at = None` = stále aktivní)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AlertEvent {
+    pub id: i64,
+    pub rule_id: i64,
+    pub endpoint_id: i64,
+    pub subject: String,
+    pub message: String,
+    pub value: Option<f64>,
+    pub started_at: i64,
+    pub resolved_at: Option<i64>,
+}
+
+/// Notifikační kanály alertů
+#[derive(Debug, Clone, Default, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AlertSettings {
+    pub webhook_url: Option<String>,
+    pub notify_command: Option<String>,
+}
@@ -157,7 +157,6 @@ impl EsClient {
     }
 
     /// Získá index templates
-    #[allow(dead_code)]
     pub async fn get_index_templates(&self) -> Result<Value> {
         // Pro ES 7.8+ použij /_index_template
         // Pro starší verze /_template
@@ -174,7 +173,6 @@ impl EsClient {
     }
 
     /// Získá component templates (ES 7.8+)
-    #[allow(dead_code)]
     pub async fn get_component_templates(&self) -> Result<Value> {
         if let Some(version) = self.version()
             && (version.major >= 8 || (version.major == 7 && version.minor >= 8)) {
@@ -34,6 +34,26 @@ impl EsVersion {
             patch: parts[2].parse().context("Invalid patch version")?,
         })
     }
+
+    /// Vrátí true pokud je verze >= major.minor
+    pub fn at_least(&self, major: u32, minor: u32) -> bool {
+        self.major > major || (self.major == major && self.minor >= minor)
+    }
+}
+
+/// Vytáhne čitelný důvod chyby z ES error response (`error.root_cause[0].reason` / `error.reason`)
+pub fn extract_error_reason(body: &str) -> String {
+    let json: Value = match serde_json::from_str(body) {
+        Ok(json) => json,
+        Err(_) => return body.trim().to_string(),
+    };
+
+    let error = &json["error"];
+    error["root_cause"][0]["reason"].as_str()
+        .or_else(|| error["reason"].as_str())
+        .or_else(|| error.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| body.trim().to_string())
 }
 
 #[derive(Debug, Deserialize)]
@@ -274,4 +294,21 @@ mod tests {
         assert_eq!(v2.minor, 11);
         assert_eq!(v2.patch, 1);
     }
+
+    #[test]
+    fn test_extract_error_reason() {
+        let body = r#"{"error":{"root_cause":[{"type":"x","reason":"index already exists"}],"reason":"outer"},"status":400}"#;
+        assert_eq!(extract_error_reason(body), "index already exists");
+        assert_eq!(extract_error_reason(r#"{"error":{"reason":"outer"}}"#), "outer");
+        assert_eq!(extract_error_reason("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_version_at_least() {
+        let v = EsVersion::from_string("7.17.0").unwrap();
+        assert!(v.at_least(7, 9));
+        assert!(v.at_least(6, 8));
+        assert!(!v.at_least(8, 0));
+        assert!(!v.at_least(7, 18));
+    }
 }
@@ -1,4 +1,4 @@
 pub mod api;
 pub mod client;
 
-pub use client::EsClient;
+pub use client::{EsClient, extract_error_reason};
@@ -0,0 +1,561 @@
+use axum::{
+    extract::State,
+    response::Html,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use askama::Template;
+use serde_json::Value;
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::handlers::shards::{ShardInfo, load_shards_data};
+use crate::templates::{AdvisorTemplate, PageContext};
+use crate::models::{AdvisorData, AdvisorFinding, IndexInfo, NodeSizing, Severity, Watermarks};
+use crate::utils::{format_bytes, parse_size_to_bytes};
+
+const GB: u64 = 1024 * 1024 * 1024;
+/// Doporučené rozmezí velikosti shardu
+const LARGE_SHARD_BYTES: u64 = 50 * GB;
+const SMALL_SHARD_BYTES: u64 = GB;
+/// Doporučený strop počtu shardů na GB heapu
+const MAX_SHARDS_PER_HEAP_GB: f64 = 20.0;
+/// Od kolika procentních bodů pod low watermarkem se node hlásí jako "blízko"
+const WATERMARK_MARGIN: f64 = 5.0;
+/// Minimální počet malých indexů se stejným prefixem, aby šlo o sérii (denní indexy apod.)
+const SMALL_SERIES_MIN: usize = 3;
+const ITEMS_SHOWN: usize = 10;
+
+/// Systémové indexy si repliky a velikost spravují samy
+fn is_system_index(index: &str) -> bool {
+    index.starts_with('.')
+}
+
+/// Prefix série indexů bez datové / číselné přípony (`logs-2024.05.01` -> `logs-`)
+fn series_prefix(index: &str) -> &str {
+    let stem = index.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.' || c == '-' || c == '_');
+    if stem.is_empty() {
+        return index;
+    }
+    // Oddělovač za názvem série zůstane součástí prefixu
+    let separator = index[stem.len()..].chars().next().filter(|c| !c.is_ascii_digit()).map_or(0, char::len_utf8);
+    &index[..stem.len() + separator]
+}
+
+/// Nejmenší dělitel počtu primárních shardů, který pojme data (shrink umí jen na dělitele)
+fn shrink_target(primaries: u64, primary_bytes: u64) -> u64 {
+    let needed = primary_bytes.div_ceil(LARGE_SHARD_BYTES).max(1);
+    (needed..=primaries)
+        .find(|candidate| primaries.is_multiple_of(*candidate))
+        .unwrap_or(primaries)
+}
+
+fn truncated(mut items: Vec<String>) -> (Vec<String>, usize) {
+    let more = items.len().saturating_sub(ITEMS_SHOWN);
+    items.truncate(ITEMS_SHOWN);
+    (items, more)
+}
+
+fn finding(
+    severity: Severity,
+    category: &'static str,
+    title: String,
+    detail: &str,
+    suggestion: &str,
+    items: Vec<String>,
+) -> AdvisorFinding {
+    let (items, more_items) = truncated(items);
+    AdvisorFinding {
+        severity,
+        category,
+        title,
+        detail: detail.to_string(),
+        suggestion: suggestion.to_string(),
+        items,
+        more_items,
+    }
+}
+
+/// Node, na kterém shard leží (relokující shard: "a -> ip id b" se počítá ke zdroji)
+fn shard_node(shard: &ShardInfo) -> Option<&str> {
+    match shard.node.split(" -> ").next() {
+        Some("UNASSIGNED") | Some("") | None => None,
+        Some(node) => Some(node),
+    }
+}
+
+/// Přiřadí shardy a jejich velikost k nodům
+fn count_node_shards(nodes: &mut [NodeSizing], shards: &[ShardInfo]) {
+    let positions: HashMap<String, usize> = nodes.iter().enumerate()
+        .map(|(i, n)| (n.name.clone(), i))
+        .collect();
+    for shard in shards {
+        if let Some(&i) = shard_node(shard).and_then(|node| positions.get(node)) {
+            nodes[i].shards += 1;
+            nodes[i].store_bytes += parse_size_to_bytes(&shard.store);
+        }
+    }
+}
+
+fn check_large_shards(shards: &[ShardInfo]) -> Option<AdvisorFinding> {
+    let mut large: Vec<(&ShardInfo, u64)> = shards.iter()
+        .map(|s| (s, parse_size_to_bytes(&s.store)))
+        .filter(|(_, bytes)| *bytes > LARGE_SHARD_BYTES)
+        .collect();
+    if large.is_empty() {
+        return None;
+    }
+    large.sort_by_key(|(_, bytes)| std::cmp::Reverse(*bytes));
+
+    let items = large.iter()
+        .map(|(s, bytes)| format!("{} [{}] {} - {} on {}", s.index, s.shard, s.prirep, format_bytes(*bytes), s.node))
+        .collect();
+    Some(finding(
+        Severity::Warning,
+        "Shard size",
+        format!("{} shard(s) larger than 50 GB", large.len()),
+        "Large shards make recovery and relocation slow and can delay the cluster returning to green after a node failure.",
+        "Split the index to more primaries (`POST <index>/_split/<target>`), or for time series data roll over by size with ILM `max_primary_shard_size: 50gb`.",
+        items,
+    ))
+}
+
+fn check_oversharded(indices: &[IndexInfo]) -> Option<AdvisorFinding> {
+    let mut items = Vec::new();
+    for idx in indices.iter().filter(|i| !is_system_index(&i.index)) {
+        let primaries: u64 = idx.pri.parse().unwrap_or(0);
+        let primary_bytes = parse_size_to_bytes(&idx.pri_store_size);
+        if primaries <= 1 || primary_bytes / primaries >= SMALL_SHARD_BYTES {
+            continue;
+        }
+        let target = shrink_target(primaries, primary_bytes);
+        if target < primaries {
+            items.push(format!(
+                "{}: {} primaries averaging {} - shrink to {}",
+                idx.index, primaries, format_bytes(primary_bytes / primaries), target
+            ));
+        }
+    }
+    if items.is_empty() {
+        return None;
+    }
+    Some(finding(
+        Severity::Warning,
+        "Shard size",
+        format!("{} oversharded index(es) with primaries under 1 GB", items.len()),
+        "Every shard costs heap, file handles and cluster state; many tiny shards waste resources without adding throughput.",
+        "Shrink to the suggested primary count (`POST <index>/_shrink/<target>` after making the index read-only on one node), and lower `number_of_shards` in the index template for new indices.",
+        items,
+    ))
+}
+
+fn check_small_series(indices: &[IndexInfo]) -> Option<AdvisorFinding> {
+    // Série malých indexů (typicky denní) - jednotlivě v pořádku, dohromady zbytečné shardy
+    let mut series: BTreeMap<&str, (usize, u64, u64)> = BTreeMap::new();
+    for idx in indices.iter().filter(|i| !is_system_index(&i.index)) {
+        let primaries: u64 = idx.pri.parse().unwrap_or(0);
+        let primary_bytes = parse_size_to_bytes(&idx.pri_store_size);
+        if primaries == 0 || primary_bytes / primaries >= SMALL_SHARD_BYTES {
+            continue;
+        }
+        let prefix = series_prefix(&idx.index);
+        if prefix == idx.index {
+            continue;
+        }
+        let entry = series.entry(prefix).or_default();
+        entry.0 += 1;
+        entry.1 += primary_bytes;
+        entry.2 += primaries;
+    }
+
+    let mut items: Vec<(usize, String)> = series.into_iter()
+        .filter(|(_, (count, _, _))| *count >= SMALL_SERIES_MIN)
+        .map(|(prefix, (count, bytes, primaries))| (count, format!(
+            "{}*: {} indices, {} primaries, {} total (avg shard {})",
+            prefix, count, primaries, format_bytes(bytes), format_bytes(bytes / primaries)
+        )))
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+    items.sort_by_key(|(count, _)| std::cmp::Reverse(*count));
+
+    Some(finding(
+        Severity::Info,
+        "Shard size",
+        format!("{} index series with shards under 1 GB", items.len()),
+        "Daily or per-tenant indices that stay small multiply the shard count without benefit.",
+        "Switch to weekly or monthly indices, or use a data stream with ILM rollover by size (`max_primary_shard_size`) instead of by date. Reindex old small indices into one when they become read-only.",
+        items.into_iter().map(|(_, item)| item).collect(),
+    ))
+}
+
+fn check_heap_ratio(nodes: &[NodeSizing]) -> Option<AdvisorFinding> {
+    let items: Vec<String> = nodes.iter()
+        .filter(|n| n.is_data)
+        .filter_map(|n| n.shards_per_heap_gb().map(|ratio| (n, ratio)))
+        .filter(|(_, ratio)| *ratio > MAX_SHARDS_PER_HEAP_GB)
+        .map(|(n, ratio)| format!("{}: {} shards / {} heap = {} per GB", n.name, n.shards, n.heap_formatted(), ratio))
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+    Some(finding(
+        Severity::Warning,
+        "Heap",
+        format!("{} node(s) with more than 20 shards per GB of heap", items.len()),
+        "Each shard keeps segment metadata and mappings in heap; too many shards per node leads to memory pressure and long GC pauses.",
+        "Reduce the shard count (shrink oversharded indices, consolidate small indices, delete or snapshot old data), or add heap / data nodes. Keep heap at most 50% of RAM and under ~31 GB.",
+        items,
+    ))
+}
+
+fn check_distribution(nodes: &[NodeSizing]) -> Option<AdvisorFinding> {
+    let data_nodes: Vec<&NodeSizing> = nodes.iter().filter(|n| n.is_data).collect();
+    if data_nodes.len() < 2 {
+        return None;
+    }
+
+    let counts: Vec<usize> = data_nodes.iter().map(|n| n.shards).collect();
+    let (min, max) = (*counts.iter().min()?, *counts.iter().max()?);
+    let avg = counts.iter().sum::<usize>() as f64 / counts.len() as f64;
+    let count_uneven = max - min >= 3 && (max - min) as f64 > avg * 0.2;
+
+    let bytes: Vec<u64> = data_nodes.iter().map(|n| n.store_bytes).collect();
+    let (min_bytes, max_bytes) = (*bytes.iter().min()?, *bytes.iter().max()?);
+    let bytes_uneven = max_bytes - min_bytes > 10 * GB && max_bytes as f64 > min_bytes as f64 * 1.5;
+
+    if !count_uneven && !bytes_uneven {
+        return None;
+    }
+
+    let mut sorted = data_nodes.clone();
+    sorted.sort_by_key(|n| std::cmp::Reverse(n.shards));
+    let items = sorted.iter()
+        .map(|n| format!("{}: {} shards, {}", n.name, n.shards, n.store_formatted()))
+        .collect();
+    Some(finding(
+        Severity::Warning,
+        "Distribution",
+        if count_uneven {
+            format!("Uneven shard distribution ({} to {} shards per data node)", min, max)
+        } else {
+            format!("Uneven data distribution ({} to {} per data node)", format_bytes(min_bytes), format_bytes(max_bytes))
+        },
+        "Busy nodes become hot spots for indexing and search, and run out of disk first.",
+        "Check allocation filters and awareness attributes, `index.routing.allocation.total_shards_per_node`, and nodes above the low watermark (they accept no new shards). Allocation Explain on the Shards page shows why a shard stays put.",
+        items,
+    ))
+}
+
+fn check_replicas(indices: &[IndexInfo], data_nodes: usize) -> Option<AdvisorFinding> {
+    let items: Vec<String> = indices.iter()
+        .filter(|i| !is_system_index(&i.index) && i.status == "open" && i.rep.trim() == "0")
+        .map(|i| i.index.clone())
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+
+    if data_nodes <= 1 {
+        return Some(finding(
+            Severity::Info,
+            "Resilience",
+            format!("{} index(es) without replicas on a single data node", items.len()),
+            "With one data node replicas cannot be allocated, so snapshots are the only protection against data loss.",
+            "Register a snapshot repository and schedule SLM snapshots, or add a second data node and set `index.number_of_replicas: 1`.",
+            items,
+        ));
+    }
+    Some(finding(
+        Severity::Warning,
+        "Resilience",
+        format!("{} index(es) with 0 replicas", items.len()),
+        "Losing the node that holds a primary makes the index red and the data is gone unless it is in a snapshot.",
+        "Add a replica: `PUT <index>/_settings {\"index.number_of_replicas\": 1}` (or set it in the index template). Zero replicas only make sense temporarily during bulk loads.",
+        items,
+    ))
+}
+
+fn check_watermarks(nodes: &[NodeSizing], watermarks: &Watermarks) -> Vec<AdvisorFinding> {
+    let mut flood = Vec::new();
+    let mut high = Vec::new();
+    let mut low = Vec::new();
+    let mut near = Vec::new();
+
+    for node in nodes.iter().filter(|n| n.is_data && n.disk_total_bytes > 0) {
+        let used = node.disk_used_percent();
+        let item = format!("{}: {:.1}% used, {} free of {}",
+            node.name, used, format_bytes(node.disk_available_bytes), node.disk_total_formatted());
+        if used >= watermarks.flood_stage.used_percent(node.disk_total_bytes) {
+            flood.push(item);
+        } else if used >= watermarks.high.used_percent(node.disk_total_bytes) {
+            high.push(item);
+        } else if used >= watermarks.low.used_percent(node.disk_total_bytes) {
+            low.push(item);
+        } else if used >= watermarks.low.used_percent(node.disk_total_bytes) - WATERMARK_MARGIN {
+            near.push(item);
+        }
+    }
+
+    let free_disk = "Delete or snapshot and remove old indices, force-merge indices with many deleted docs, shorten ILM retention, or add disk / data nodes.";
+    let mut findings = Vec::new();
+    if !flood.is_empty() {
+        findings.push(finding(
+            Severity::Critical,
+            "Disk",
+            format!("{} node(s) above the flood stage watermark", flood.len()),
+            "Indices with a shard on these nodes are blocked for writes (`index.blocks.read_only_allow_delete`).",
+            &format!("{} The block is released automatically once usage drops below the high watermark.", free_disk),
+            flood,
+        ));
+    }
+    if !high.is_empty() {
+        findings.push(finding(
+            Severity::Critical,
+            "Disk",
+            format!("{} node(s) above the high watermark", high.len()),
+            "Elasticsearch is relocating shards away from these nodes, which adds load to the rest of the cluster.",
+            free_disk,
+            high,
+        ));
+    }
+    if !low.is_empty() {
+        findings.push(finding(
+            Severity::Warning,
+            "Disk",
+            format!("{} node(s) above the low watermark", low.len()),
+            "No new shards (including replicas of new indices) are allocated to these nodes.",
+            free_disk,
+            low,
+        ));
+    }
+    if !near.is_empty() {
+        findings.push(finding(
+            Severity::Info,
+            "Disk",
+            format!("{} node(s) within 5% of the low watermark", near.len()),
+            "These nodes will stop receiving new shards soon.",
+            "Plan capacity: check growth on the metrics history chart and adjust ILM retention or add disk before the low watermark is reached.",
+            near,
+        ));
+    }
+    findings
+}
+
+/// Všechny kontroly nad už načtenými daty (seřazeno podle závažnosti)
+fn analyze(
+    indices: &[IndexInfo],
+    shards: &[ShardInfo],
+    nodes: &mut [NodeSizing],
+    watermarks: &Watermarks,
+) -> Vec<AdvisorFinding> {
+    count_node_shards(nodes, shards);
+    let data_nodes = nodes.iter().filter(|n| n.is_data).count();
+
+    let mut findings: Vec<AdvisorFinding> = [
+        check_large_shards(shards),
+        check_oversharded(indices),
+        check_small_series(indices),
+        check_heap_ratio(nodes),
+        check_distribution(nodes),
+        check_replicas(indices, data_nodes),
+    ].into_iter().flatten().collect();
+    findings.extend(check_watermarks(nodes, watermarks));
+
+    findings.sort_by_key(|f| f.severity);
+    findings
+}
+
+fn parse_nodes(stats: &Value) -> Vec<NodeSizing> {
+    let mut nodes: Vec<NodeSizing> = stats["nodes"].as_object()
+        .map(|nodes| nodes.values().map(|node| {
+            // Role "data", "data_hot", "data_content" ...
+            let is_data = node["roles"].as_array()
+                .map(|roles| roles.iter().filter_map(|r| r.as_str()).any(|r| r.starts_with("data")))
+                .unwrap_or(true);
+            NodeSizing {
+                name: node["name"].as_str().unwrap_or("-").to_string(),
+                is_data,
+                shards: 0,
+                store_bytes: 0,
+                heap_max_bytes: node["jvm"]["mem"]["heap_max_in_bytes"].as_u64().unwrap_or(0),
+                disk_total_bytes: node["fs"]["total"]["total_in_bytes"].as_u64().unwrap_or(0),
+                disk_available_bytes: node["fs"]["total"]["available_in_bytes"].as_u64().unwrap_or(0),
+            }
+        }).collect())
+        .unwrap_or_default();
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    nodes
+}
+
+async fn load_advisor_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+) -> anyhow::Result<AdvisorData> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    // Stejné `_cat/shards` jako stránka Shards, `_cat/indices` jako stránka Indices
+    let shards = load_shards_data(state, endpoint, "*").await?;
+    let indices: Vec<IndexInfo> = client.get("/_cat/indices?format=json&bytes=b&expand_wildcards=all").await?;
+    let stats: Value = client.get("/_nodes/stats/jvm,fs").await?;
+    let watermarks = client.get::<Value>("/_cluster/settings?include_defaults=true&flat_settings=true").await
+        .map(|settings| Watermarks::from_settings(&settings))
+        .unwrap_or_default();
+
+    let mut nodes = parse_nodes(&stats);
+    let findings = analyze(&indices, &shards.all_shards, &mut nodes, &watermarks);
+
+    Ok(AdvisorData {
+        findings,
+        total_shards: shards.all_shards.len(),
+        total_indices: indices.len(),
+        total_store_bytes: nodes.iter().map(|n| n.store_bytes).sum(),
+        nodes,
+        watermarks: watermarks.label(),
+    })
+}
+
+/// GET /advisor - Analýza velikosti shardů, replik, rozložení a disku
+pub async fn advisor_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let (data, error) = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_advisor_data(&state, endpoint)
+    ).await {
+        Ok(Ok(d)) => (Some(d), None),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load advisor data: {}", e);
+            (None, Some(e.to_string()))
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading advisor data");
+            (None, Some("Timeout loading advisor data".to_string()))
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = AdvisorTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::models::Watermark;
+
+    fn index(name: &str, pri: &str, rep: &str, pri_store: u64) -> IndexInfo {
+        serde_json::from_value(json!({
+            "health": "green", "status": "open", "index": name, "uuid": "u",
+            "pri": pri, "rep": rep, "docs.count": "1", "docs.deleted": "0",
+            "store.size": pri_store.to_string(), "pri.store.size": pri_store.to_string()
+        })).unwrap()
+    }
+
+    fn shard(index: &str, prirep: &str, store: &str, node: &str) -> ShardInfo {
+        ShardInfo {
+            index: index.to_string(),
+            shard: "0".to_string(),
+            prirep: prirep.to_string(),
+            state: "STARTED".to_string(),
+            docs: "1".to_string(),
+            store: store.to_string(),
+            node: node.to_string(),
+            unassigned_reason: None,
+        }
+    }
+
+    fn node(name: &str, heap_gb: u64, disk_used_percent: u64) -> NodeSizing {
+        NodeSizing {
+            name: name.to_string(),
+            is_data: true,
+            shards: 0,
+            store_bytes: 0,
+            heap_max_bytes: heap_gb * GB,
+            disk_total_bytes: 100 * GB,
+            disk_available_bytes: (100 - disk_used_percent) * GB,
+        }
+    }
+
+    #[test]
+    fn test_watermark_parsing() {
+        assert_eq!(Watermark::parse("85%"), Some(Watermark::UsedPercent(85.0)));
+        assert_eq!(Watermark::parse("0.9"), Some(Watermark::UsedPercent(90.0)));
+        assert_eq!(Watermark::parse("10gb"), Some(Watermark::FreeBytes(10 * GB)));
+        assert_eq!(Watermark::FreeBytes(10 * GB).used_percent(100 * GB), 90.0);
+
+        let settings = json!({
+            "persistent": { "cluster.routing.allocation.disk.watermark.low": "80%" },
+            "defaults": {
+                "cluster.routing.allocation.disk.watermark.low": "85%",
+                "cluster.routing.allocation.disk.watermark.high": "90%",
+                "cluster.routing.allocation.disk.watermark.flood_stage": "95%"
+            }
+        });
+        let watermarks = Watermarks::from_settings(&settings);
+        assert_eq!(watermarks.low, Watermark::UsedPercent(80.0));
+        assert_eq!(watermarks.high, Watermark::UsedPercent(90.0));
+    }
+
+    #[test]
+    fn test_shrink_target_and_series() {
+        // 6 primárních shardů po 100 MB -> 1 shard
+        assert_eq!(shrink_target(6, 600 * 1024 * 1024), 1);
+        // 70 GB potřebuje 2 shardy, 6 je dělitelné 2
+        assert_eq!(shrink_target(6, 70 * GB), 2);
+        // 110 GB potřebuje 3, 4 primární -> nejbližší dělitel 4
+        assert_eq!(shrink_target(4, 110 * GB), 4);
+        assert_eq!(series_prefix("logs-2024.05.01"), "logs-");
+        assert_eq!(series_prefix("12345"), "12345");
+    }
+
+    #[test]
+    fn test_analyze_findings() {
+        let indices = vec![
+            index("big", "1", "1", 60 * GB),
+            index("tiny-sharded", "5", "1", 500 * 1024 * 1024),
+            index("no-replica", "1", "0", GB),
+            index("logs-2024.05.01", "1", "1", 1024),
+            index("logs-2024.05.02", "1", "1", 1024),
+            index("logs-2024.05.03", "1", "1", 1024),
+            index(".system", "1", "0", 1024),
+        ];
+        let mut shards = vec![
+            shard("big", "p", "60gb", "node-a"),
+            shard("big", "r", "60gb", "node-b -> 10.0.0.3 xyz node-c"),
+        ];
+        // 50 malých shardů na node-a (25 na GB heapu při 2 GB)
+        shards.extend((0..50).map(|_| shard("tiny-sharded", "p", "100mb", "node-a")));
+        let mut nodes = vec![node("node-a", 2, 50), node("node-b", 8, 91), node("node-c", 8, 82)];
+
+        let findings = analyze(&indices, &shards, &mut nodes, &Watermarks::default());
+        let titles: Vec<&str> = findings.iter().map(|f| f.title.as_str()).collect();
+
+        assert_eq!(nodes[0].shards, 51);
+        assert_eq!(nodes[1].shards, 1);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert!(titles.contains(&"1 node(s) above the high watermark"));
+        assert!(titles.contains(&"2 shard(s) larger than 50 GB"));
+        assert!(titles.contains(&"1 oversharded index(es) with primaries under 1 GB"));
+        assert!(titles.contains(&"1 index series with shards under 1 GB"));
+        assert!(titles.contains(&"1 node(s) with more than 20 shards per GB of heap"));
+        assert!(titles.contains(&"Uneven shard distribution (0 to 51 shards per data node)"));
+        assert!(titles.contains(&"1 index(es) with 0 replicas"));
+        assert!(titles.contains(&"1 node(s) within 5% of the low watermark"));
+    }
+}
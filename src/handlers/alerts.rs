@@ -0,0 +1,347 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::HashMap;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::alerts::{self, RULE_KINDS};
+use crate::db::models::{AlertSettings, CreateAlertRule};
+use crate::handlers::endpoints::{AppState, get_active_endpoint};
+use crate::models::{AlertEventRow, AlertRuleRow, AlertsData};
+use crate::templates::{AlertsBadgeTemplate, AlertsTemplate, PageContext};
+
+/// Počet incidentů zobrazených na stránce
+const EVENTS_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct AlertRuleRequest {
+    pub name: String,
+    pub kind: String,
+    pub threshold: Option<f64>,
+    pub target: Option<String>,
+    #[serde(default)]
+    pub duration_secs: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ToggleRuleRequest {
+    pub enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AlertSettingsRequest {
+    pub webhook_url: Option<String>,
+    pub notify_command: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct AlertActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<AlertActionResponse>) {
+    (status, Json(AlertActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+fn success_response(message: String) -> Json<AlertActionResponse> {
+    Json(AlertActionResponse { success: true, message: Some(message), error: None })
+}
+
+/// Prázdný řetězec = None
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// Zkontroluje pravidlo a doplní výchozí hodnoty podle typu
+fn validate_rule(req: AlertRuleRequest) -> Result<CreateAlertRule, String> {
+    let name = req.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Rule name is required".to_string());
+    }
+    if !RULE_KINDS.iter().any(|(kind, _)| *kind == req.kind) {
+        return Err(format!("Unknown rule kind '{}'", req.kind));
+    }
+    if req.duration_secs < 0 {
+        return Err("Duration must not be negative".to_string());
+    }
+
+    let mut threshold = None;
+    let mut target = None;
+
+    // `node_disk` s úrovní watermarku se vyhodnocuje proti nastavení clusteru místo prahu
+    let disk_watermark = match req.kind.as_str() {
+        "node_disk" => non_empty(req.target.clone()),
+        _ => None,
+    };
+    if let Some(level) = &disk_watermark
+        && !alerts::WATERMARK_LEVELS.contains(&level.as_str())
+    {
+        return Err(format!("Unknown watermark level '{}'", level));
+    }
+
+    if alerts::needs_threshold(&req.kind) && disk_watermark.is_none() {
+        let value = req.threshold.ok_or_else(|| "Threshold is required for this rule".to_string())?;
+        if req.kind != "unassigned_shards" && !(0.0..=100.0).contains(&value) {
+            return Err("Threshold must be a percentage between 0 and 100".to_string());
+        }
+        if value < 0.0 {
+            return Err("Threshold must not be negative".to_string());
+        }
+        threshold = Some(value);
+    }
+
+    match req.kind.as_str() {
+        "cluster_status" => {
+            target = Some(match non_empty(req.target).as_deref() {
+                Some("red") => "red".to_string(),
+                _ => "yellow".to_string(),
+            });
+        }
+        "docs_stalled" => {
+            let pattern = non_empty(req.target).ok_or_else(|| "Index pattern is required".to_string())?;
+            if pattern.contains(char::is_whitespace) || pattern.contains('/') {
+                return Err(format!("Invalid index pattern '{}'", pattern));
+            }
+            if req.duration_secs == 0 {
+                return Err("Set a duration - the doc count is compared between samples".to_string());
+            }
+            target = Some(pattern);
+        }
+        "node_disk" => target = disk_watermark,
+        _ => {}
+    }
+
+    Ok(CreateAlertRule { name, kind: req.kind, threshold, target, duration_secs: req.duration_secs })
+}
+
+async fn load_alerts_data(state: &AppState, endpoint_id: i64) -> anyhow::Result<AlertsData> {
+    let rules = state.db.get_alert_rules().await?;
+    let events = state.db.get_alert_events(endpoint_id, EVENTS_LIMIT).await?;
+    let settings = state.db.get_alert_settings().await?;
+
+    let names: HashMap<i64, String> = rules.iter().map(|r| (r.id, r.name.clone())).collect();
+    let mut firing: HashMap<i64, usize> = HashMap::new();
+    for event in events.iter().filter(|e| e.resolved_at.is_none()) {
+        *firing.entry(event.rule_id).or_default() += 1;
+    }
+
+    let rules = rules.into_iter().map(|rule| AlertRuleRow {
+        kind_label: alerts::kind_label(&rule.kind).to_string(),
+        condition: alerts::describe(&rule),
+        firing: firing.get(&rule.id).copied().unwrap_or(0),
+        rule,
+    }).collect();
+
+    let events = events.into_iter().map(|event| AlertEventRow {
+        rule_name: names.get(&event.rule_id).cloned().unwrap_or_else(|| format!("#{}", event.rule_id)),
+        event,
+    }).collect();
+
+    Ok(AlertsData { rules, events, settings })
+}
+
+/// GET /alerts - Pravidla alertů, incidenty a notifikační kanály
+pub async fn alerts_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint_id = active_endpoint.as_ref().unwrap().id;
+
+    let (data, error) = match load_alerts_data(&state, endpoint_id).await {
+        Ok(d) => (Some(d), None),
+        Err(e) => {
+            tracing::error!("Failed to load alerts: {}", e);
+            (None, Some(e.to_string()))
+        }
+    };
+
+    let template = AlertsTemplate {
+        ctx: PageContext::new(active_endpoint),
+        data,
+        error,
+        kinds: RULE_KINDS.to_vec(),
+    };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /alerts/badge - Počet aktivních alertů do navigace (partial)
+pub async fn alerts_badge(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let open = match get_active_endpoint(&state, &jar).await {
+        Some(endpoint) => state.db.get_open_alert_events(Some(endpoint.id)).await
+            .map(|events| events.len())
+            .unwrap_or(0),
+        None => 0,
+    };
+
+    AlertsBadgeTemplate { open }.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// POST /alerts/rules - Vytvoří pravidlo
+pub async fn create_alert_rule(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<AlertRuleRequest>,
+) -> Result<Json<AlertActionResponse>, (StatusCode, Json<AlertActionResponse>)> {
+    let rule = validate_rule(req).map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+    let name = rule.name.clone();
+
+    state.db.create_alert_rule(rule).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(success_response(format!("Pravidlo '{}' bylo vytvořeno", name)))
+}
+
+/// POST /alerts/rules/{id}/toggle - Zapne / vypne pravidlo
+pub async fn toggle_alert_rule(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i64>,
+    Json(req): Json<ToggleRuleRequest>,
+) -> Result<Json<AlertActionResponse>, (StatusCode, Json<AlertActionResponse>)> {
+    state.db.set_alert_rule_enabled(id, req.enabled, chrono::Utc::now().timestamp()).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let message = if req.enabled { "Pravidlo bylo zapnuto" } else { "Pravidlo bylo vypnuto" };
+    Ok(success_response(message.to_string()))
+}
+
+/// POST /alerts/rules/{id}/delete - Smaže pravidlo včetně jeho incidentů
+pub async fn delete_alert_rule(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i64>,
+) -> Result<Json<AlertActionResponse>, (StatusCode, Json<AlertActionResponse>)> {
+    state.db.delete_alert_rule(id).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(success_response("Pravidlo bylo smazáno".to_string()))
+}
+
+/// POST /alerts/settings - Uloží webhook a notifikační příkaz
+pub async fn save_alert_settings(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<AlertSettingsRequest>,
+) -> Result<Json<AlertActionResponse>, (StatusCode, Json<AlertActionResponse>)> {
+    let settings = AlertSettings {
+        webhook_url: non_empty(req.webhook_url),
+        notify_command: non_empty(req.notify_command),
+    };
+
+    if let Some(url) = &settings.webhook_url
+        && !(url.starts_with("http://") || url.starts_with("https://"))
+    {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Webhook URL must start with http:// or https://".to_string()));
+    }
+
+    state.db.save_alert_settings(&settings).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(success_response("Notifikační kanály byly uloženy".to_string()))
+}
+
+/// POST /alerts/test - Odešle testovací notifikaci na uložené kanály
+pub async fn test_alert_notification(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<AlertActionResponse>, (StatusCode, Json<AlertActionResponse>)> {
+    let settings = state.db.get_alert_settings().await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if settings.webhook_url.is_none() && settings.notify_command.is_none() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "No notification channel configured".to_string()));
+    }
+
+    let message = "Test notification from Elastic Explorer";
+    let mut errors = Vec::new();
+
+    if let Some(url) = &settings.webhook_url {
+        let payload = json!({
+            "rule": "test",
+            "state": "test",
+            "message": message,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Err(e) = alerts::send_webhook(url, &payload).await {
+            errors.push(format!("Webhook: {}", e));
+        }
+    }
+
+    if let Some(command) = &settings.notify_command
+        && let Err(e) = alerts::run_command(command, "Elastic Explorer", message, "test").await
+    {
+        errors.push(format!("Command: {}", e));
+    }
+
+    if !errors.is_empty() {
+        return Err(error_response(StatusCode::BAD_GATEWAY, errors.join("; ")));
+    }
+
+    Ok(success_response("Testovací notifikace byla odeslána".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(kind: &str, threshold: Option<f64>, target: Option<&str>, duration_secs: i64) -> AlertRuleRequest {
+        AlertRuleRequest {
+            name: "rule".to_string(),
+            kind: kind.to_string(),
+            threshold,
+            target: target.map(|t| t.to_string()),
+            duration_secs,
+        }
+    }
+
+    #[test]
+    fn test_validates_thresholds() {
+        assert!(validate_rule(request("node_heap", None, None, 0)).is_err());
+        assert!(validate_rule(request("node_heap", Some(120.0), None, 0)).is_err());
+        assert!(validate_rule(request("unassigned_shards", Some(10.0), None, 0)).is_ok());
+        assert!(validate_rule(request("unknown", None, None, 0)).is_err());
+
+        // Práh se u typů bez čísla zahodí
+        let rule = validate_rule(request("cluster_status", Some(5.0), Some(""), 120)).unwrap();
+        assert_eq!(rule.threshold, None);
+        assert_eq!(rule.target.as_deref(), Some("yellow"));
+    }
+
+    #[test]
+    fn test_node_disk_accepts_watermark_level() {
+        let rule = validate_rule(request("node_disk", Some(85.0), Some("high"), 0)).unwrap();
+        assert_eq!((rule.threshold, rule.target.as_deref()), (None, Some("high")));
+        assert!(validate_rule(request("node_disk", None, Some("medium"), 0)).is_err());
+        assert!(validate_rule(request("node_disk", None, None, 0)).is_err());
+
+        let rule = validate_rule(request("node_disk", Some(80.0), Some(""), 0)).unwrap();
+        assert_eq!((rule.threshold, rule.target), (Some(80.0), None));
+    }
+
+    #[test]
+    fn test_docs_stalled_requires_pattern_and_duration() {
+        assert!(validate_rule(request("docs_stalled", None, None, 600)).is_err());
+        assert!(validate_rule(request("docs_stalled", None, Some("logs-*"), 0)).is_err());
+        let rule = validate_rule(request("docs_stalled", None, Some(" logs-* "), 600)).unwrap();
+        assert_eq!(rule.target.as_deref(), Some("logs-*"));
+    }
+}
@@ -0,0 +1,357 @@
+use axum::{
+    extract::{Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{AliasesTemplate, PageContext};
+use crate::es::extract_error_reason;
+use crate::models::{AliasEntry, AliasGroup, AliasesData};
+
+#[derive(Debug, Deserialize)]
+pub struct AliasesQuery {
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default = "default_hide_internal")]
+    pub hide_internal: bool,
+}
+
+fn default_hide_internal() -> bool {
+    true
+}
+
+/// Jedna akce pro `POST /_aliases`
+#[derive(Debug, Deserialize)]
+pub struct AliasActionRequest {
+    pub action: String,                 // add | remove
+    pub index: String,                  // index nebo pattern
+    pub alias: String,
+    #[serde(default)]
+    pub filter: String,                 // raw JSON query
+    #[serde(default)]
+    pub index_routing: String,
+    #[serde(default)]
+    pub search_routing: String,
+    #[serde(default)]
+    pub is_write_index: Option<bool>,
+    #[serde(default)]
+    pub is_hidden: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AliasActionsRequest {
+    pub actions: Vec<AliasActionRequest>,
+}
+
+#[derive(Serialize)]
+pub struct AliasActionsResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Zpracuje odpověď `GET /_alias` do skupin podle názvu aliasu
+fn parse_aliases(response: &Value) -> Vec<AliasGroup> {
+    let mut groups: BTreeMap<String, Vec<AliasEntry>> = BTreeMap::new();
+
+    if let Some(indices) = response.as_object() {
+        for (index, body) in indices {
+            let Some(aliases) = body["aliases"].as_object() else {
+                continue;
+            };
+            for (alias, def) in aliases {
+                // Routing je v odpovědi jako "index_routing"/"search_routing" (ES 5+)
+                let routing = |key: &str| def[key].as_str().map(|s| s.to_string());
+                groups.entry(alias.clone()).or_default().push(AliasEntry {
+                    alias: alias.clone(),
+                    index: index.clone(),
+                    filter: def.get("filter").and_then(|f| serde_json::to_string_pretty(f).ok()),
+                    index_routing: routing("index_routing"),
+                    search_routing: routing("search_routing"),
+                    is_write_index: def["is_write_index"].as_bool(),
+                    is_hidden: def["is_hidden"].as_bool().unwrap_or(false),
+                });
+            }
+        }
+    }
+
+    groups.into_iter()
+        .map(|(name, mut entries)| {
+            entries.sort_by(|a, b| a.index.cmp(&b.index));
+            AliasGroup { name, entries }
+        })
+        .collect()
+}
+
+/// Převede akce z UI na body pro `POST /_aliases` (všechny akce proběhnou atomicky)
+fn build_alias_actions(actions: &[AliasActionRequest]) -> Result<Value, String> {
+    if actions.is_empty() {
+        return Err("No alias actions".to_string());
+    }
+
+    let mut result = Vec::new();
+    for action in actions {
+        let index = action.index.trim();
+        let alias = action.alias.trim();
+        if index.is_empty() || alias.is_empty() {
+            return Err("Index and alias are required for every action".to_string());
+        }
+        if alias != alias.to_lowercase() || alias.starts_with('_') || alias.starts_with('-') {
+            return Err(format!("Invalid alias name '{}'", alias));
+        }
+
+        match action.action.as_str() {
+            "add" => {
+                let mut def = serde_json::Map::new();
+                def.insert("index".to_string(), json!(index));
+                def.insert("alias".to_string(), json!(alias));
+                if !action.filter.trim().is_empty() {
+                    let filter: Value = serde_json::from_str(&action.filter)
+                        .map_err(|e| format!("Filter for {} is not valid JSON: {}", alias, e))?;
+                    if !filter.is_object() {
+                        return Err(format!("Filter for {} must be a query object", alias));
+                    }
+                    def.insert("filter".to_string(), filter);
+                }
+                if !action.index_routing.trim().is_empty() {
+                    def.insert("index_routing".to_string(), json!(action.index_routing.trim()));
+                }
+                if !action.search_routing.trim().is_empty() {
+                    def.insert("search_routing".to_string(), json!(action.search_routing.trim()));
+                }
+                if let Some(is_write_index) = action.is_write_index {
+                    def.insert("is_write_index".to_string(), json!(is_write_index));
+                }
+                if let Some(is_hidden) = action.is_hidden {
+                    def.insert("is_hidden".to_string(), json!(is_hidden));
+                }
+                result.push(json!({ "add": def }));
+            }
+            "remove" => {
+                result.push(json!({ "remove": { "index": index, "alias": alias } }));
+            }
+            other => return Err(format!("Unknown alias action: {}", other)),
+        }
+    }
+
+    Ok(json!({ "actions": result }))
+}
+
+/// GET /aliases - Přehled všech aliasů a jejich indexů
+pub async fn aliases_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<AliasesQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_aliases_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load aliases: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading aliases");
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = AliasesTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_aliases_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &AliasesQuery,
+) -> anyhow::Result<AliasesData> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let response: Value = client.get("/_alias").await?;
+    let mut aliases = parse_aliases(&response);
+
+    let filter = query.filter.trim().to_string();
+    aliases.retain(|group| {
+        if query.hide_internal && group.name.starts_with('.') {
+            return false;
+        }
+        filter.is_empty()
+            || filter.split(',')
+                .map(|p| p.trim())
+                .filter(|p| !p.is_empty())
+                .any(|p| {
+                    let pattern = if p.contains('*') { p.to_string() } else { format!("*{}*", p) };
+                    matches_pattern(&group.name, &pattern)
+                        || group.entries.iter().any(|e| matches_pattern(&e.index, &pattern))
+                })
+    });
+
+    let rows: Vec<Value> = client.get("/_cat/indices?format=json&h=index").await?;
+    let mut indices: Vec<String> = rows.iter()
+        .filter_map(|r| r["index"].as_str().map(|s| s.to_string()))
+        .filter(|i| !query.hide_internal || !i.starts_with('.'))
+        .collect();
+    indices.sort();
+
+    let total_entries = aliases.iter().map(|g| g.entries.len()).sum();
+
+    Ok(AliasesData {
+        aliases,
+        indices,
+        filter,
+        hide_internal: query.hide_internal,
+        total_entries,
+    })
+}
+
+/// POST /aliases/actions - Provede add/remove akce v jednom atomickém `_aliases` requestu
+pub async fn alias_actions(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<AliasActionsRequest>,
+) -> Result<Json<AliasActionsResponse>, (StatusCode, Json<AliasActionsResponse>)> {
+    let error_response = |status: StatusCode, error: String| {
+        (status, Json(AliasActionsResponse { success: false, message: None, error: Some(error) }))
+    };
+
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or_else(|| error_response(StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let body = build_alias_actions(&req.actions)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let client = create_es_client(&state, &endpoint, false).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+
+    let (status, response) = client.post_raw("/_aliases", body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Applied {} alias action(s)", req.actions.len());
+
+    Ok(Json(AliasActionsResponse {
+        success: true,
+        message: Some(format!("Aliasy aktualizovány ({} akcí)", req.actions.len())),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action(action: &str, index: &str, alias: &str) -> AliasActionRequest {
+        AliasActionRequest {
+            action: action.to_string(),
+            index: index.to_string(),
+            alias: alias.to_string(),
+            filter: String::new(),
+            index_routing: String::new(),
+            search_routing: String::new(),
+            is_write_index: None,
+            is_hidden: None,
+        }
+    }
+
+    #[test]
+    fn test_build_alias_actions_swap() {
+        let body = build_alias_actions(&[
+            action("remove", "logs-v1", "logs"),
+            AliasActionRequest {
+                filter: r#"{"term": {"env": "prod"}}"#.to_string(),
+                index_routing: "1".to_string(),
+                is_write_index: Some(true),
+                ..action("add", "logs-v2", "logs")
+            },
+        ]).unwrap();
+
+        assert_eq!(body["actions"][0]["remove"]["index"], "logs-v1");
+        let add = &body["actions"][1]["add"];
+        assert_eq!(add["index"], "logs-v2");
+        assert_eq!(add["filter"]["term"]["env"], "prod");
+        assert_eq!(add["index_routing"], "1");
+        assert_eq!(add["is_write_index"], true);
+        assert!(add.get("search_routing").is_none());
+    }
+
+    #[test]
+    fn test_build_alias_actions_errors() {
+        assert!(build_alias_actions(&[]).is_err());
+        assert!(build_alias_actions(&[action("rename", "a", "b")]).is_err());
+        assert!(build_alias_actions(&[action("add", "a", "Logs")]).is_err());
+        assert!(build_alias_actions(&[AliasActionRequest { filter: "{".to_string(), ..action("add", "a", "b") }]).is_err());
+    }
+
+    #[test]
+    fn test_parse_aliases() {
+        let groups = parse_aliases(&json!({
+            "logs-v2": { "aliases": { "logs": { "is_write_index": true }, "prod": { "filter": { "term": { "env": "prod" } } } } },
+            "logs-v1": { "aliases": { "logs": { "is_write_index": false } } },
+            "other": { "aliases": {} }
+        }));
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "logs");
+        assert_eq!(groups[0].entries[0].index, "logs-v1");
+        assert_eq!(groups[0].write_index(), Some("logs-v2"));
+        assert!(groups[1].has_filter());
+        assert_eq!(groups[1].write_index(), Some("logs-v2"));
+    }
+
+    #[test]
+    fn test_write_index_switch_keeps_alias_definition() {
+        let groups = parse_aliases(&json!({
+            "logs-v1": { "aliases": { "prod": {
+                "filter": { "term": { "env": "prod" } },
+                "index_routing": "1",
+                "search_routing": "1,2",
+                "is_write_index": true
+            } } },
+            "logs-v2": { "aliases": { "prod": { "filter": { "term": { "env": "prod" } }, "is_hidden": true } } }
+        }));
+
+        let actions: Vec<AliasActionRequest> = serde_json::from_str(&groups[0].write_index_actions_json("logs-v2")).unwrap();
+        let body = build_alias_actions(&actions).unwrap();
+
+        let old = &body["actions"][0]["add"];
+        assert_eq!(old["index"], "logs-v1");
+        assert_eq!(old["filter"]["term"]["env"], "prod");
+        assert_eq!(old["index_routing"], "1");
+        assert_eq!(old["search_routing"], "1,2");
+        assert_eq!(old["is_write_index"], false);
+        assert!(old.get("is_hidden").is_none());
+
+        let new = &body["actions"][1]["add"];
+        assert_eq!(new["index"], "logs-v2");
+        assert_eq!(new["filter"]["term"]["env"], "prod");
+        assert_eq!(new["is_write_index"], true);
+        assert_eq!(new["is_hidden"], true);
+    }
+}
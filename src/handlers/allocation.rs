@@ -0,0 +1,396 @@
+use axum::{
+    extract::{Query, State},
+    response::Html,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::templates::{AllocationExplainTemplate, PageContext, UnassignedShardsTemplate};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{
+    AllocationCause, AllocationDecider, AllocationExplain, NodeAllocationDecision, UnassignedShard,
+    UnassignedSummaryData,
+};
+
+/// Maximální počet shardů, pro které souhrn volá `_cluster/allocation/explain`
+const MAX_EXPLAINED_SHARDS: usize = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct ExplainQuery {
+    pub index: String,
+    pub shard: u64,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(default)]
+    pub node: String,                   // aktuální node u přiřazených (initializing / started) kopií
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(|v| v.as_str()).map(|s| s.to_string())
+}
+
+/// Shrne `store` sekci node rozhodnutí do jednoho řádku
+fn store_summary(store: &Value) -> Option<String> {
+    if store.is_null() {
+        return None;
+    }
+    if let Some(exception) = store.get("store_exception") {
+        let reason = exception["reason"].as_str()
+            .or_else(|| exception["type"].as_str())
+            .unwrap_or("unknown");
+        return Some(format!("store exception: {}", reason));
+    }
+    if let Some(in_sync) = store["in_sync"].as_bool() {
+        return Some(if in_sync { "in-sync copy".to_string() } else { "stale copy".to_string() });
+    }
+    if store.get("found").and_then(|v| v.as_bool()) == Some(false) {
+        return Some("no copy found".to_string());
+    }
+    store["matching_size_in_bytes"].as_u64()
+        .map(|bytes| format!("{} matching", crate::utils::format_bytes(bytes)))
+}
+
+/// Parsuje odpověď `_cluster/allocation/explain`
+fn parse_explain(response: &Value) -> AllocationExplain {
+    let unassigned = &response["unassigned_info"];
+
+    let node_decisions = response["node_allocation_decisions"].as_array()
+        .map(|nodes| nodes.iter().map(|node| NodeAllocationDecision {
+            node_id: node["node_id"].as_str().unwrap_or("").to_string(),
+            node_name: node["node_name"].as_str().unwrap_or("").to_string(),
+            node_decision: node["node_decision"].as_str().unwrap_or("").to_string(),
+            weight_ranking: node["weight_ranking"].as_u64(),
+            store: store_summary(&node["store"]),
+            deciders: node["deciders"].as_array()
+                .map(|deciders| deciders.iter().map(|d| AllocationDecider {
+                    decider: d["decider"].as_str().unwrap_or("").to_string(),
+                    decision: d["decision"].as_str().unwrap_or("").to_string(),
+                    explanation: d["explanation"].as_str().unwrap_or("").to_string(),
+                }).collect())
+                .unwrap_or_default(),
+        }).collect())
+        .unwrap_or_default();
+
+    AllocationExplain {
+        index: response["index"].as_str().unwrap_or("").to_string(),
+        shard: response["shard"].as_u64().unwrap_or(0),
+        primary: response["primary"].as_bool().unwrap_or(false),
+        current_state: response["current_state"].as_str().unwrap_or("").to_string(),
+        current_node: response["current_node"]["name"].as_str().map(|s| s.to_string()),
+        unassigned_reason: str_field(unassigned, "reason"),
+        unassigned_at: str_field(unassigned, "at"),
+        unassigned_details: str_field(unassigned, "details"),
+        last_allocation_status: str_field(unassigned, "last_allocation_status"),
+        can_allocate: str_field(response, "can_allocate"),
+        allocate_explanation: str_field(response, "allocate_explanation"),
+        can_remain_on_current_node: str_field(response, "can_remain_on_current_node"),
+        can_rebalance_cluster: str_field(response, "can_rebalance_cluster"),
+        rebalance_explanation: str_field(response, "rebalance_explanation"),
+        node_decisions,
+    }
+}
+
+/// Body pro explain konkrétní kopie shardu
+fn build_explain_body(index: &str, shard: u64, primary: bool, node: &str) -> Value {
+    let mut body = json!({
+        "index": index,
+        "shard": shard,
+        "primary": primary,
+    });
+    // `_cat/shards` u relokace vrací "source -> target", explain chce zdrojový node
+    let node = node.split(" -> ").next().unwrap_or("").trim();
+    if !node.is_empty() && node != "UNASSIGNED" {
+        body["current_node"] = json!(node);
+    }
+    body
+}
+
+/// Přičte příčinu do seznamu (zachovává pořadí prvního výskytu)
+fn add_cause(causes: &mut Vec<AllocationCause>, name: &str, example: Option<String>) {
+    match causes.iter_mut().find(|c| c.name == name) {
+        Some(cause) => cause.count += 1,
+        None => causes.push(AllocationCause { name: name.to_string(), count: 1, example }),
+    }
+}
+
+/// Seskupí nepřiřazené shardy podle reason kódu a podle blokujících deciderů
+fn summarize_causes(shards: &[UnassignedShard]) -> (Vec<AllocationCause>, Vec<AllocationCause>) {
+    let mut reasons = Vec::new();
+    let mut deciders = Vec::new();
+
+    for shard in shards {
+        add_cause(&mut reasons, &shard.reason, None);
+
+        let Some(explain) = &shard.explain else { continue };
+        let blocking = explain.blocking_deciders();
+        if blocking.is_empty() {
+            // Bez NO decideru: no_valid_shard_copy, allocation_delayed, throttled...
+            if let Some(can_allocate) = &explain.can_allocate {
+                add_cause(&mut deciders, can_allocate, explain.allocate_explanation.clone());
+            }
+            continue;
+        }
+        for decider in blocking {
+            let example = explain.node_decisions.iter()
+                .flat_map(|n| n.deciders.iter())
+                .find(|d| d.decider == decider && d.decision == "NO")
+                .map(|d| d.explanation.clone());
+            add_cause(&mut deciders, &decider, example);
+        }
+    }
+
+    reasons.sort_by_key(|c| std::cmp::Reverse(c.count));
+    deciders.sort_by_key(|c| std::cmp::Reverse(c.count));
+    (reasons, deciders)
+}
+
+/// Zavolá explain; chybu ES (např. shard neexistuje) vrací jako Err(text)
+async fn explain_shard(client: &EsClient, body: Value) -> anyhow::Result<Result<AllocationExplain, String>> {
+    let (status, response) = client.post_raw("/_cluster/allocation/explain", body).await?;
+    if status >= 400 {
+        return Ok(Err(extract_error_reason(&response)));
+    }
+    let value: Value = serde_json::from_str(&response)?;
+    Ok(Ok(parse_explain(&value)))
+}
+
+/// GET /shards/explain - Vysvětlení alokace jedné kopie shardu (partial pro modal)
+pub async fn explain_allocation(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<ExplainQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let body = build_explain_body(&query.index, query.shard, query.primary, &query.node);
+
+    let (data, error) = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        async {
+            let client = create_es_client(&state, endpoint, true).await?;
+            explain_shard(&client, body).await
+        }
+    ).await {
+        Ok(Ok(Ok(explain))) => (Some(explain), None),
+        Ok(Ok(Err(reason))) => (None, Some(reason)),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to explain shard allocation: {}", e);
+            (None, Some(e.to_string()))
+        }
+        Err(_) => {
+            tracing::error!("Timeout explaining shard allocation");
+            (None, Some("Timeout explaining shard allocation".to_string()))
+        }
+    };
+
+    let template = AllocationExplainTemplate { data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /shards/unassigned - Souhrn příčin nepřiřazených shardů ("proč je cluster yellow")
+pub async fn unassigned_summary_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    // Explain se volá pro každý shard zvlášť, proto delší timeout
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_unassigned_summary(&state, endpoint)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load unassigned shards: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading unassigned shards");
+            error = Some("Timeout loading unassigned shards".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = UnassignedShardsTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_unassigned_summary(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+) -> anyhow::Result<UnassignedSummaryData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let health = client.cluster_health().await?;
+    let response: Value = client
+        .get("/_cat/shards?format=json&h=index,shard,prirep,state,unassigned.reason")
+        .await?;
+
+    let mut shards: Vec<UnassignedShard> = response.as_array()
+        .map(|arr| arr.iter()
+            .filter(|item| item["state"].as_str() == Some("UNASSIGNED"))
+            .map(|item| UnassignedShard {
+                index: item["index"].as_str().unwrap_or("").to_string(),
+                shard: item["shard"].as_str().unwrap_or("").to_string(),
+                primary: item["prirep"].as_str() == Some("p"),
+                reason: item["unassigned.reason"].as_str().unwrap_or("UNKNOWN").to_string(),
+                explain: None,
+                error: None,
+            })
+            .collect())
+        .unwrap_or_default();
+
+    // Primární shardy (red) první
+    shards.sort_by(|a, b| b.primary.cmp(&a.primary)
+        .then(a.index.cmp(&b.index))
+        .then(a.shard.cmp(&b.shard)));
+
+    let explained = shards.len().min(MAX_EXPLAINED_SHARDS);
+    for shard in shards.iter_mut().take(explained) {
+        let body = build_explain_body(&shard.index, shard.shard.parse().unwrap_or(0), shard.primary, "");
+        match explain_shard(&client, body).await? {
+            Ok(explain) => shard.explain = Some(explain),
+            Err(reason) => shard.error = Some(reason),
+        }
+    }
+
+    let (reasons, deciders) = summarize_causes(&shards);
+
+    Ok(UnassignedSummaryData {
+        cluster_status: health.status,
+        total_unassigned: shards.len(),
+        unassigned_primaries: shards.iter().filter(|s| s.primary).count(),
+        explained,
+        reasons,
+        deciders,
+        shards,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unassigned_replica() -> Value {
+        json!({
+            "index": "logs",
+            "shard": 0,
+            "primary": false,
+            "current_state": "unassigned",
+            "unassigned_info": {
+                "reason": "NODE_LEFT",
+                "at": "2026-10-18T08:00:00.000Z",
+                "details": "node_left [abc]",
+                "last_allocation_status": "no_attempt"
+            },
+            "can_allocate": "no",
+            "allocate_explanation": "cannot allocate because allocation is not permitted to any of the nodes",
+            "node_allocation_decisions": [{
+                "node_id": "abc",
+                "node_name": "node-1",
+                "node_decision": "no",
+                "weight_ranking": 1,
+                "store": { "in_sync": true },
+                "deciders": [{
+                    "decider": "same_shard",
+                    "decision": "NO",
+                    "explanation": "a copy of this shard is already allocated to this node"
+                }]
+            }]
+        })
+    }
+
+    #[test]
+    fn test_parse_explain_unassigned() {
+        let explain = parse_explain(&unassigned_replica());
+        assert_eq!(explain.index, "logs");
+        assert!(!explain.primary);
+        assert_eq!(explain.unassigned_reason.as_deref(), Some("NODE_LEFT"));
+        assert_eq!(explain.can_allocate_color(), "red");
+        assert_eq!(explain.node_decisions.len(), 1);
+        assert_eq!(explain.node_decisions[0].store.as_deref(), Some("in-sync copy"));
+        assert_eq!(explain.blocking_deciders(), vec!["same_shard".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_explain_assigned() {
+        let explain = parse_explain(&json!({
+            "index": "logs",
+            "shard": 1,
+            "primary": true,
+            "current_state": "started",
+            "current_node": { "id": "abc", "name": "node-1" },
+            "can_remain_on_current_node": "yes",
+            "can_rebalance_cluster": "yes",
+            "rebalance_explanation": "cannot rebalance as no target node exists that can both allocate this shard and improve the cluster balance"
+        }));
+        assert_eq!(explain.current_node.as_deref(), Some("node-1"));
+        assert_eq!(explain.can_remain_on_current_node.as_deref(), Some("yes"));
+        assert!(explain.can_allocate.is_none());
+        assert!(explain.node_decisions.is_empty());
+    }
+
+    #[test]
+    fn test_build_explain_body() {
+        let body = build_explain_body("logs", 2, true, "");
+        assert!(body.get("current_node").is_none());
+
+        let body = build_explain_body("logs", 2, false, "node-1 -> 10.0.0.2 xyz node-2");
+        assert_eq!(body["current_node"], "node-1");
+    }
+
+    #[test]
+    fn test_summarize_causes() {
+        let mut delayed = parse_explain(&unassigned_replica());
+        delayed.node_decisions.clear();
+        delayed.can_allocate = Some("allocation_delayed".to_string());
+
+        let shard = |explain: Option<AllocationExplain>| UnassignedShard {
+            index: "logs".to_string(),
+            shard: "0".to_string(),
+            primary: false,
+            reason: "NODE_LEFT".to_string(),
+            explain,
+            error: None,
+        };
+        let shards = vec![
+            shard(Some(parse_explain(&unassigned_replica()))),
+            shard(Some(parse_explain(&unassigned_replica()))),
+            shard(Some(delayed)),
+            shard(None),
+        ];
+
+        let (reasons, deciders) = summarize_causes(&shards);
+        assert_eq!(reasons.len(), 1);
+        assert_eq!(reasons[0].count, 4);
+        assert_eq!(deciders[0].name, "same_shard");
+        assert_eq!(deciders[0].count, 2);
+        assert!(deciders[0].example.is_some());
+        assert_eq!(deciders[1].name, "allocation_delayed");
+    }
+}
@@ -0,0 +1,436 @@
+use axum::{
+    extract::{Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::handlers::mappings::parse_mapping_fields;
+use crate::templates::{AnalyzeTokensTemplate, AnalyzerTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{AnalyzeForm, AnalyzeResult, AnalyzeToken, AnalyzerPageData};
+
+/// Typy fieldů, které mají analyzer nebo normalizer
+const ANALYZED_FIELD_TYPES: &[&str] = &["text", "match_only_text", "search_as_you_type", "keyword", "completion"];
+
+#[derive(Debug, Deserialize)]
+pub struct AnalyzerQuery {
+    #[serde(default)]
+    pub index: String,
+    #[serde(default)]
+    pub field: String,
+    #[serde(default)]
+    pub analyzer: String,
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub compare: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexInfoQuery {
+    pub index: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnalyzeRunRequest {
+    pub text: String,
+    pub left: AnalyzeForm,
+    #[serde(default)]
+    pub right: Option<AnalyzeForm>,
+}
+
+#[derive(Serialize)]
+pub struct AnalyzedField {
+    pub path: String,
+    pub label: String,                  // `message (text, analyzer: english)`
+}
+
+#[derive(Serialize)]
+pub struct IndexAnalysisInfo {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub fields: Vec<AnalyzedField>,
+    pub analyzers: Vec<String>,         // custom analyzery a normalizery z nastavení indexu
+}
+
+/// Data fragmentu s tokeny (jedna nebo dvě strany)
+pub struct AnalyzeCompareData {
+    pub left: AnalyzeResult,
+    pub right: Option<AnalyzeResult>,
+}
+
+impl AnalyzeCompareData {
+    /// Token chybí na druhé straně (zvýraznění rozdílů v režimu porovnání)
+    pub fn only_left(&self, token: &str) -> bool {
+        self.right.as_ref().is_some_and(|r| !r.tokens.iter().any(|t| t.token == token))
+    }
+
+    pub fn only_right(&self, token: &str) -> bool {
+        !self.left.tokens.iter().any(|t| t.token == token)
+    }
+}
+
+/// Filtr je název (`lowercase`) nebo inline definice v JSON (`{"type": "stop", ...}`)
+fn parse_component(label: &str, raw: &str) -> Result<Value, String> {
+    let raw = raw.trim();
+    if raw.starts_with('{') {
+        let value: Value = serde_json::from_str(raw)
+            .map_err(|e| format!("{} '{}' is not valid JSON: {}", label, raw, e))?;
+        if value["type"].as_str().is_none() {
+            return Err(format!("Inline {} must have a \"type\"", label));
+        }
+        Ok(value)
+    } else if raw.contains([' ', ',', '"']) {
+        Err(format!("Invalid {} name '{}'", label, raw))
+    } else {
+        Ok(json!(raw))
+    }
+}
+
+fn parse_component_lines(label: &str, raw: &str) -> Result<Vec<Value>, String> {
+    raw.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_component(label, line))
+        .collect()
+}
+
+/// Sestaví cestu a body `_analyze` pro jednu stranu playgroundu, vrací i popisek konfigurace
+fn build_analyze_request(form: &AnalyzeForm, text: &str) -> Result<(String, Value, String), String> {
+    let index = form.index.trim();
+    let path = if index.is_empty() { "/_analyze".to_string() } else { format!("/{}/_analyze", index) };
+    let in_index = |label: String| if index.is_empty() { label } else { format!("{}: {}", index, label) };
+
+    match form.mode.as_str() {
+        "field" => {
+            let field = form.field.trim();
+            if index.is_empty() || field.is_empty() {
+                return Err("Field mode needs an index and a field".to_string());
+            }
+            Ok((path, json!({ "field": field, "text": text }), format!("{}: {}", index, field)))
+        }
+        "custom" => {
+            let tokenizer = match form.tokenizer.trim() {
+                "" => return Err("Custom chain needs a tokenizer".to_string()),
+                raw => parse_component("tokenizer", raw)?,
+            };
+            let filters = parse_component_lines("filter", &form.filters)?;
+            let char_filters = parse_component_lines("char_filter", &form.char_filters)?;
+
+            let name = |v: &Value| v.as_str().map(|s| s.to_string())
+                .unwrap_or_else(|| format!("{{{}}}", v["type"].as_str().unwrap_or("?")));
+            let mut label = char_filters.iter().map(name).collect::<Vec<_>>();
+            label.push(name(&tokenizer));
+            label.extend(filters.iter().map(name));
+
+            let mut body = json!({ "tokenizer": tokenizer, "text": text });
+            if !filters.is_empty() {
+                body["filter"] = Value::Array(filters);
+            }
+            if !char_filters.is_empty() {
+                body["char_filter"] = Value::Array(char_filters);
+            }
+            Ok((path, body, in_index(label.join(" → "))))
+        }
+        _ => {
+            let analyzer = form.analyzer.trim();
+            if analyzer.is_empty() {
+                return Err("Choose an analyzer".to_string());
+            }
+            Ok((path, json!({ "analyzer": analyzer, "text": text }), in_index(analyzer.to_string())))
+        }
+    }
+}
+
+/// Parsuje odpověď `_analyze`
+fn parse_tokens(response: &Value) -> Vec<AnalyzeToken> {
+    response["tokens"].as_array()
+        .map(|items| items.iter().map(|t| AnalyzeToken {
+            token: t["token"].as_str().unwrap_or("").to_string(),
+            start_offset: t["start_offset"].as_u64().unwrap_or(0),
+            end_offset: t["end_offset"].as_u64().unwrap_or(0),
+            position: t["position"].as_u64().unwrap_or(0),
+            token_type: t["type"].as_str().unwrap_or("").to_string(),
+            position_length: t["positionLength"].as_u64().filter(|l| *l > 1),
+        }).collect())
+        .unwrap_or_default()
+}
+
+/// Analyzované fieldy z mappingu indexu (text s analyzerem, keyword s normalizerem)
+fn analyzed_fields(mappings: &Value) -> Vec<AnalyzedField> {
+    parse_mapping_fields(mappings).into_iter()
+        .filter(|f| ANALYZED_FIELD_TYPES.contains(&f.field_type.as_str()) && !f.is_runtime)
+        .map(|f| {
+            let detail = match (&f.analyzer, &f.search_analyzer, &f.normalizer) {
+                (Some(a), Some(s), _) if a != s => format!(", analyzer: {}, search: {}", a, s),
+                (Some(a), _, _) => format!(", analyzer: {}", a),
+                (None, Some(s), _) => format!(", search analyzer: {}", s),
+                (_, _, Some(n)) => format!(", normalizer: {}", n),
+                _ => String::new(),
+            };
+            AnalyzedField {
+                label: format!("{} ({}{})", f.path, f.field_type, detail),
+                path: f.path,
+            }
+        })
+        .collect()
+}
+
+/// Custom analyzery a normalizery definované v nastavení indexu
+fn index_analyzers(settings: &Value) -> Vec<String> {
+    let analysis = &settings["index"]["analysis"];
+    let mut names: Vec<String> = ["analyzer", "normalizer"].iter()
+        .filter_map(|kind| analysis[*kind].as_object())
+        .flat_map(|items| items.keys().cloned())
+        .collect();
+    names.sort();
+    names
+}
+
+/// GET /analyze - Analyzer playground (předvyplnění přes `index`, `field`, `analyzer`, `text`)
+pub async fn analyzer_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<AnalyzerQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    // Seznam indexů je jen nápověda pro výběr, bez něj playground funguje také
+    let indices = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_index_names(&state, endpoint)
+    ).await {
+        Ok(Ok(names)) => names,
+        Ok(Err(e)) => {
+            tracing::warn!("Failed to load index names: {}", e);
+            Vec::new()
+        }
+        Err(_) => {
+            tracing::warn!("Timeout loading index names");
+            Vec::new()
+        }
+    };
+
+    let index = query.index.trim().to_string();
+    let left = if !query.field.trim().is_empty() {
+        AnalyzeForm { mode: "field".to_string(), index, field: query.field.trim().to_string(), ..Default::default() }
+    } else {
+        let analyzer = if query.analyzer.trim().is_empty() { "standard" } else { query.analyzer.trim() };
+        AnalyzeForm { mode: "analyzer".to_string(), index, analyzer: analyzer.to_string(), ..Default::default() }
+    };
+    let right = AnalyzeForm {
+        mode: "custom".to_string(),
+        tokenizer: "whitespace".to_string(),
+        filters: "lowercase".to_string(),
+        ..Default::default()
+    };
+
+    let data = AnalyzerPageData {
+        text: query.text,
+        left,
+        right,
+        compare: query.compare,
+        indices,
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = AnalyzerTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_index_names(state: &AppState, endpoint: &crate::db::models::Endpoint) -> anyhow::Result<Vec<String>> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let cat: Vec<Value> = client.get("/_cat/indices?format=json&h=index").await?;
+    let mut names: Vec<String> = cat.iter()
+        .filter_map(|i| i["index"].as_str())
+        .filter(|name| !name.starts_with('.'))
+        .map(|name| name.to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// GET /analyze/index-info - Analyzované fieldy a custom analyzery indexu (pro výběr ve formuláři)
+pub async fn index_analysis_info(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<IndexInfoQuery>,
+) -> Json<IndexAnalysisInfo> {
+    let failed = |error: String| Json(IndexAnalysisInfo { success: false, error: Some(error), fields: Vec::new(), analyzers: Vec::new() });
+
+    let Some(endpoint) = get_active_endpoint(&state, &jar).await else {
+        return failed("No active endpoint selected".to_string());
+    };
+    let client = match create_es_client(&state, &endpoint, true).await {
+        Ok(c) => c,
+        Err(e) => return failed(format!("Failed to create ES client: {}", e)),
+    };
+
+    let index = query.index.trim();
+    let mappings: Value = match client.get(&format!("/{}/_mapping", index)).await {
+        Ok(m) => m,
+        Err(e) => return failed(e.to_string()),
+    };
+    let settings: Value = client.get(&format!("/{}/_settings", index)).await.unwrap_or(Value::Null);
+
+    // Index může být alias nebo pattern - bere se první konkrétní index
+    let Some((first, mapping)) = mappings.as_object().and_then(|m| m.iter().next()) else {
+        return failed(format!("Index '{}' not found", index));
+    };
+
+    Json(IndexAnalysisInfo {
+        success: true,
+        error: None,
+        fields: analyzed_fields(&mapping["mappings"]),
+        analyzers: index_analyzers(&settings[first]["settings"]),
+    })
+}
+
+/// POST /analyze/run - Spustí `_analyze` pro jednu nebo dvě konfigurace (partial)
+pub async fn run_analyze(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<AnalyzeRunRequest>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match create_es_client(&state, endpoint, true).await {
+        Ok(client) => {
+            let left = analyze(&client, &req.left, &req.text).await;
+            let right = match &req.right {
+                Some(form) => Some(analyze(&client, form, &req.text).await),
+                None => None,
+            };
+            AnalyzeCompareData { left, right }
+        }
+        Err(e) => {
+            tracing::error!("Failed to create ES client: {}", e);
+            AnalyzeCompareData {
+                left: AnalyzeResult { error: Some(e.to_string()), ..Default::default() },
+                right: None,
+            }
+        }
+    };
+
+    let template = AnalyzeTokensTemplate { data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn analyze(client: &EsClient, form: &AnalyzeForm, text: &str) -> AnalyzeResult {
+    let (path, body, label) = match build_analyze_request(form, text) {
+        Ok(request) => request,
+        Err(e) => return AnalyzeResult { error: Some(e), ..Default::default() },
+    };
+
+    match tokio::time::timeout(tokio::time::Duration::from_secs(10), client.post_raw(&path, body)).await {
+        Ok(Ok((status, response))) if status < 400 => {
+            let value: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+            AnalyzeResult { label, tokens: parse_tokens(&value), error: None }
+        }
+        Ok(Ok((_, response))) => AnalyzeResult { label, tokens: Vec::new(), error: Some(extract_error_reason(&response)) },
+        Ok(Err(e)) => AnalyzeResult { label, tokens: Vec::new(), error: Some(e.to_string()) },
+        Err(_) => AnalyzeResult { label, tokens: Vec::new(), error: Some("Timeout running _analyze".to_string()) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_analyze_request() {
+        let field = AnalyzeForm { mode: "field".to_string(), index: "logs".to_string(), field: "message".to_string(), ..Default::default() };
+        let (path, body, label) = build_analyze_request(&field, "Quick Fox").unwrap();
+        assert_eq!(path, "/logs/_analyze");
+        assert_eq!(body, json!({ "field": "message", "text": "Quick Fox" }));
+        assert_eq!(label, "logs: message");
+
+        let builtin = AnalyzeForm { mode: "analyzer".to_string(), analyzer: "english".to_string(), ..Default::default() };
+        let (path, body, _) = build_analyze_request(&builtin, "x").unwrap();
+        assert_eq!(path, "/_analyze");
+        assert_eq!(body["analyzer"], "english");
+
+        let custom = AnalyzeForm {
+            mode: "custom".to_string(),
+            tokenizer: "standard".to_string(),
+            filters: "lowercase\n\n{\"type\": \"stop\", \"stopwords\": [\"the\"]}".to_string(),
+            char_filters: "html_strip".to_string(),
+            ..Default::default()
+        };
+        let (_, body, label) = build_analyze_request(&custom, "x").unwrap();
+        assert_eq!(body["filter"][0], "lowercase");
+        assert_eq!(body["filter"][1]["type"], "stop");
+        assert_eq!(body["char_filter"], json!(["html_strip"]));
+        assert_eq!(label, "html_strip → standard → lowercase → {stop}");
+
+        assert!(build_analyze_request(&AnalyzeForm { mode: "field".to_string(), ..Default::default() }, "x").is_err());
+        let bad_filter = AnalyzeForm { mode: "custom".to_string(), tokenizer: "standard".to_string(), filters: "{\"stopwords\": []}".to_string(), ..Default::default() };
+        assert!(build_analyze_request(&bad_filter, "x").is_err());
+    }
+
+    #[test]
+    fn test_parse_tokens_and_compare() {
+        let response = json!({
+            "tokens": [
+                { "token": "quick", "start_offset": 0, "end_offset": 5, "type": "<ALPHANUM>", "position": 0 },
+                { "token": "fox", "start_offset": 6, "end_offset": 9, "type": "<ALPHANUM>", "position": 1, "positionLength": 1 },
+                { "token": "ny", "start_offset": 10, "end_offset": 18, "type": "SYNONYM", "position": 2, "positionLength": 2 }
+            ]
+        });
+        let tokens = parse_tokens(&response);
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[1].end_offset, 9);
+        assert_eq!(tokens[1].position_length, None);
+        assert_eq!(tokens[2].position_length, Some(2));
+
+        let data = AnalyzeCompareData {
+            left: AnalyzeResult { tokens, ..Default::default() },
+            right: Some(AnalyzeResult { tokens: parse_tokens(&json!({ "tokens": [{ "token": "Quick" }, { "token": "fox" }] })), ..Default::default() }),
+        };
+        assert!(data.only_left("quick"));
+        assert!(!data.only_left("fox"));
+        assert!(data.only_right("Quick"));
+    }
+
+    #[test]
+    fn test_analyzed_fields_and_index_analyzers() {
+        let mappings = json!({
+            "properties": {
+                "message": { "type": "text", "analyzer": "english", "fields": { "raw": { "type": "keyword", "normalizer": "lower" } } },
+                "count": { "type": "long" }
+            }
+        });
+        let fields = analyzed_fields(&mappings);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].label, "message (text, analyzer: english)");
+        assert_eq!(fields[1].label, "message.raw (keyword, normalizer: lower)");
+
+        let settings = json!({ "index": { "analysis": { "analyzer": { "folding": {} }, "normalizer": { "lower": {} } } } });
+        assert_eq!(index_analyzers(&settings), vec!["folding".to_string(), "lower".to_string()]);
+    }
+}
@@ -0,0 +1,402 @@
+use axum::{
+    extract::{Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::templates::{ClusterSettingsTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{ClusterSettingRow, ClusterSettingsData, CommonSetting, COMMON_SETTINGS};
+use crate::utils::parse_size_to_bytes;
+
+/// Maximální počet řádků tabulky (s `include_defaults` jich ES vrací přes tisíc)
+const MAX_SETTING_ROWS: usize = 500;
+
+const SETTINGS_PATH: &str = "/_cluster/settings?include_defaults=true&flat_settings=true";
+
+#[derive(Debug, Deserialize)]
+pub struct ClusterSettingsQuery {
+    #[serde(default)]
+    pub q: String,
+    #[serde(default)]
+    pub show: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSettingRequest {
+    pub key: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(default)]
+    pub reset: bool,                    // nastaví null = návrat k defaultu
+    #[serde(default)]
+    pub scope: String,                  // persistent | transient
+    #[serde(default)]
+    pub confirmed: bool,                // uživatel potvrdil varování
+}
+
+#[derive(Serialize)]
+pub struct ClusterSettingsActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,          // rizikové změny čekající na potvrzení
+}
+
+/// Hodnota nastavení jako text (seznamy jsou s `flat_settings` pole)
+fn setting_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter()
+            .map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string()))
+            .collect::<Vec<_>>()
+            .join(","),
+        other => other.to_string(),
+    }
+}
+
+/// Spojí persistent, transient a defaults do jednoho seznamu seřazeného podle klíče
+fn parse_settings(response: &Value) -> Vec<ClusterSettingRow> {
+    let mut rows: BTreeMap<String, ClusterSettingRow> = BTreeMap::new();
+
+    for section in ["persistent", "transient", "defaults"] {
+        let Some(settings) = response[section].as_object() else { continue };
+        for (key, value) in settings {
+            let row = rows.entry(key.clone()).or_insert_with(|| ClusterSettingRow {
+                key: key.clone(),
+                persistent: None,
+                transient: None,
+                default: None,
+            });
+            let value = Some(setting_value(value));
+            match section {
+                "persistent" => row.persistent = value,
+                "transient" => row.transient = value,
+                _ => row.default = value,
+            }
+        }
+    }
+
+    rows.into_values().collect()
+}
+
+/// Filtr podle textu (klíč i hodnota) a režimu zobrazení
+fn filter_rows(rows: &[ClusterSettingRow], query: &str, show: &str) -> Vec<ClusterSettingRow> {
+    let query = query.trim().to_lowercase();
+    rows.iter()
+        .filter(|row| match show {
+            "all" => true,
+            "explicit" => row.source() != "default",
+            _ => row.is_changed(),
+        })
+        .filter(|row| query.is_empty()
+            || row.key.to_lowercase().contains(&query)
+            || row.effective().to_lowercase().contains(&query))
+        .cloned()
+        .collect()
+}
+
+fn percent_value(value: &str) -> Option<f64> {
+    value.trim().strip_suffix('%').and_then(|p| p.trim().parse().ok())
+}
+
+/// Varování pro rizikové změny; prázdný seznam = změnu lze provést bez potvrzení
+fn assess_risk(
+    key: &str,
+    value: Option<&str>,
+    scope: &str,
+    rows: &[ClusterSettingRow],
+    transient_deprecated: bool,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if scope == "transient" && transient_deprecated {
+        warnings.push("Transient settings are deprecated since 7.16 and are lost on a full cluster restart.".to_string());
+    }
+
+    // Transient hodnota má přednost - změna persistent by neměla žádný účinek (stejně jako u drain)
+    if scope == "persistent"
+        && let Some(transient) = rows.iter().find(|r| r.key == key).and_then(|r| r.transient.as_deref())
+    {
+        warnings.push(format!(
+            "A transient value '{}' overrides the persistent setting; this change has no effect until the transient value is reset.",
+            transient
+        ));
+    }
+
+    let Some(value) = value.map(|v| v.trim()) else {
+        return warnings;
+    };
+
+    match key {
+        "cluster.routing.allocation.enable" if value != "all" => warnings.push(format!(
+            "Allocation set to '{}': affected shards stay unassigned until it is set back to 'all'.", value
+        )),
+        "cluster.routing.rebalance.enable" if value == "none" => {
+            warnings.push("Rebalancing disabled: new nodes will not receive shards.".to_string());
+        }
+        "cluster.routing.allocation.disk.threshold_enabled" if value == "false" => {
+            warnings.push("Disk-based allocation disabled: nodes can fill their disks completely.".to_string());
+        }
+        "indices.recovery.max_bytes_per_sec" if parse_size_to_bytes(value) > 1024 * 1024 * 1024 => {
+            warnings.push("Recovery throttle above 1gb/s can saturate network and disks and slow down searches.".to_string());
+        }
+        _ => {}
+    }
+
+    if key.starts_with("cluster.blocks.") && value == "true" {
+        warnings.push(format!("{} blocks writes or metadata changes across the whole cluster.", key));
+    }
+
+    if !value.is_empty() {
+        if key.starts_with("cluster.routing.allocation.exclude.") {
+            warnings.push(format!("All shards will be moved off nodes matching '{}'.", value));
+        }
+        if key.starts_with("cluster.routing.allocation.require.") || key.starts_with("cluster.routing.allocation.include.") {
+            warnings.push(format!("Shards can only be allocated to nodes matching '{}'; others will be drained.", value));
+        }
+    }
+
+    // Watermarky v procentech musí být low <= high <= flood_stage
+    let watermarks = ["low", "high", "flood_stage"];
+    if let Some(position) = watermarks.iter()
+        .position(|w| key == format!("cluster.routing.allocation.disk.watermark.{}", w))
+        && let Some(new_percent) = percent_value(value)
+    {
+        if new_percent > 95.0 {
+            warnings.push(format!("Watermark {}% leaves little headroom before disks are full.", new_percent));
+        }
+        for (other_position, other) in watermarks.iter().enumerate() {
+            let other_key = format!("cluster.routing.allocation.disk.watermark.{}", other);
+            let Some(other_percent) = rows.iter()
+                .find(|r| r.key == other_key)
+                .and_then(|r| percent_value(&r.effective()))
+            else { continue };
+            let out_of_order = (other_position < position && other_percent > new_percent)
+                || (other_position > position && other_percent < new_percent);
+            if out_of_order {
+                warnings.push(format!(
+                    "Watermark {} ({}%) would be out of order with {} ({}%).",
+                    watermarks[position], new_percent, other, other_percent
+                ));
+            }
+        }
+    }
+
+    warnings
+}
+
+fn transient_deprecated(client: &EsClient) -> bool {
+    client.version().map(|v| v.at_least(7, 16)).unwrap_or(false)
+}
+
+/// GET /cluster/settings - Persistent, transient a default cluster settings
+pub async fn cluster_settings_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<ClusterSettingsQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_cluster_settings(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load cluster settings: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading cluster settings");
+            error = Some("Timeout loading cluster settings".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = ClusterSettingsTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_cluster_settings(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &ClusterSettingsQuery,
+) -> anyhow::Result<ClusterSettingsData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    let all_rows = parse_settings(&client.get::<Value>(SETTINGS_PATH).await?);
+
+    let common = COMMON_SETTINGS.iter().map(|def| CommonSetting {
+        key: def.key.to_string(),
+        label: def.label.to_string(),
+        description: def.description.to_string(),
+        options: def.options.iter().map(|o| o.to_string()).collect(),
+        row: all_rows.iter()
+            .find(|r| r.key == def.key)
+            .cloned()
+            .unwrap_or_else(|| ClusterSettingRow {
+                key: def.key.to_string(),
+                persistent: None,
+                transient: None,
+                default: None,
+            }),
+    }).collect();
+
+    let show = match query.show.as_str() {
+        "all" | "explicit" => query.show.clone(),
+        _ => "changed".to_string(),
+    };
+    let mut rows = filter_rows(&all_rows, &query.q, &show);
+    let total = rows.len();
+    rows.truncate(MAX_SETTING_ROWS);
+
+    Ok(ClusterSettingsData {
+        common,
+        rows,
+        query: query.q.clone(),
+        show,
+        total,
+        transient_deprecated: transient_deprecated(&client),
+    })
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<ClusterSettingsActionResponse>) {
+    (status, Json(ClusterSettingsActionResponse { success: false, message: None, error: Some(error), warnings: Vec::new() }))
+}
+
+/// POST /cluster/settings/update - Změní nebo resetuje jedno nastavení (rizikové změny vyžadují potvrzení)
+pub async fn update_cluster_setting(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<UpdateSettingRequest>,
+) -> Result<Json<ClusterSettingsActionResponse>, (StatusCode, Json<ClusterSettingsActionResponse>)> {
+    let key = req.key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return Err(error_response(StatusCode::BAD_REQUEST, format!("Invalid setting key '{}'", key)));
+    }
+    let scope = match req.scope.as_str() {
+        "transient" => "transient",
+        _ => "persistent",
+    };
+    let value = if req.reset { None } else { Some(req.value.trim()) };
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    if !req.confirmed {
+        let rows = client.get::<Value>(SETTINGS_PATH).await
+            .map(|response| parse_settings(&response))
+            .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        let warnings = assess_risk(key, value, scope, &rows, transient_deprecated(&client));
+        if !warnings.is_empty() {
+            return Ok(Json(ClusterSettingsActionResponse { success: false, message: None, error: None, warnings }));
+        }
+    }
+
+    let body = json!({ scope: { key: value } });
+    let (status, response) = client.put_raw("/_cluster/settings", body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Updated cluster setting {} ({}): {:?}", key, scope, value);
+
+    let message = match value {
+        Some(v) => format!("Nastavení {} změněno na {}", key, v),
+        None => format!("Nastavení {} vráceno na výchozí hodnotu", key),
+    };
+
+    Ok(Json(ClusterSettingsActionResponse { success: true, message: Some(message), error: None, warnings: Vec::new() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_rows() -> Vec<ClusterSettingRow> {
+        parse_settings(&json!({
+            "persistent": {
+                "cluster.routing.allocation.enable": "primaries",
+                "cluster.routing.allocation.awareness.attributes": ["zone", "rack"]
+            },
+            "transient": {
+                "indices.recovery.max_bytes_per_sec": "100mb"
+            },
+            "defaults": {
+                "cluster.routing.allocation.enable": "all",
+                "indices.recovery.max_bytes_per_sec": "40mb",
+                "cluster.routing.allocation.disk.watermark.low": "85%",
+                "cluster.routing.allocation.disk.watermark.high": "90%",
+                "cluster.routing.allocation.disk.watermark.flood_stage": "95%"
+            }
+        }))
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let rows = sample_rows();
+        let enable = rows.iter().find(|r| r.key == "cluster.routing.allocation.enable").unwrap();
+        assert_eq!(enable.effective(), "primaries");
+        assert_eq!(enable.source(), "persistent");
+        assert!(enable.is_changed());
+
+        let recovery = rows.iter().find(|r| r.key == "indices.recovery.max_bytes_per_sec").unwrap();
+        assert_eq!(recovery.source(), "transient");
+
+        let awareness = rows.iter().find(|r| r.key.ends_with("awareness.attributes")).unwrap();
+        assert_eq!(awareness.effective(), "zone,rack");
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        let rows = sample_rows();
+        assert_eq!(filter_rows(&rows, "", "changed").len(), 3);
+        assert_eq!(filter_rows(&rows, "watermark", "all").len(), 3);
+        assert_eq!(filter_rows(&rows, "100MB", "changed").len(), 1);
+    }
+
+    #[test]
+    fn test_assess_risk() {
+        let rows = sample_rows();
+        assert!(assess_risk("cluster.routing.allocation.enable", Some("all"), "persistent", &rows, true).is_empty());
+        assert_eq!(assess_risk("cluster.routing.allocation.enable", Some("none"), "persistent", &rows, true).len(), 1);
+        assert_eq!(assess_risk("cluster.routing.allocation.enable", None, "transient", &rows, true).len(), 1);
+        assert!(assess_risk("cluster.routing.allocation.exclude._ip", Some(""), "persistent", &rows, false).is_empty());
+        assert_eq!(assess_risk("cluster.routing.allocation.exclude._ip", Some("10.0.0.1"), "persistent", &rows, false).len(), 1);
+
+        // high nad flood_stage
+        let warnings = assess_risk("cluster.routing.allocation.disk.watermark.high", Some("96%"), "persistent", &rows, false);
+        assert_eq!(warnings.len(), 2);
+        assert!(assess_risk("cluster.routing.allocation.disk.watermark.high", Some("92%"), "persistent", &rows, false).is_empty());
+
+        // Persistent zastíněný transient hodnotou
+        let warnings = assess_risk("indices.recovery.max_bytes_per_sec", Some("200mb"), "persistent", &rows, false);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'100mb'"));
+        assert!(assess_risk("indices.recovery.max_bytes_per_sec", Some("200mb"), "transient", &rows, false).is_empty());
+    }
+}
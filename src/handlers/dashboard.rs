@@ -112,11 +112,7 @@ async fn load_dashboard_data(
                 stats["os"]["mem"]["used_in_bytes"].as_u64(),
                 stats["os"]["mem"]["total_in_bytes"].as_u64()
             ) {
-                if total > 0 {
-                    Some(((used * 100) / total) as u8)
-                } else {
-                    None
-                }
+                (used * 100).checked_div(total).map(|v| v as u8)
             } else {
                 None
             };
@@ -126,12 +122,8 @@ async fn load_dashboard_data(
                 stats["fs"]["total"]["available_in_bytes"].as_u64(),
                 stats["fs"]["total"]["total_in_bytes"].as_u64()
             ) {
-                if total > 0 {
-                    let used = total - avail;
-                    Some(((used * 100) / total) as u8)
-                } else {
-                    None
-                }
+                let used = total.saturating_sub(avail);
+                (used * 100).checked_div(total).map(|v| v as u8)
             } else {
                 None
             };
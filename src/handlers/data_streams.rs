@@ -0,0 +1,364 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::HashMap;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{DataStreamsTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{BackingIndex, DataStreamInfo, DataStreamsData};
+
+#[derive(Debug, Deserialize)]
+pub struct DataStreamsQuery {
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub show_hidden: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDataStreamRequest {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct DataStreamActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Spojí `_data_stream`, `_data_stream/_stats` a `_cat/indices` do seznamu data streamů
+fn parse_data_streams(streams: &Value, stats: &Value, cat_indices: &[Value]) -> Vec<DataStreamInfo> {
+    let stats_by_name: HashMap<&str, &Value> = stats["data_streams"].as_array()
+        .map(|items| items.iter()
+            .filter_map(|s| s["data_stream"].as_str().map(|name| (name, s)))
+            .collect())
+        .unwrap_or_default();
+
+    let indices_by_name: HashMap<&str, &Value> = cat_indices.iter()
+        .filter_map(|i| i["index"].as_str().map(|name| (name, i)))
+        .collect();
+
+    let mut result: Vec<DataStreamInfo> = streams["data_streams"].as_array()
+        .map(|items| items.iter().map(|ds| {
+            let name = ds["name"].as_str().unwrap_or("").to_string();
+            let backing: Vec<&Value> = ds["indices"].as_array().map(|i| i.iter().collect()).unwrap_or_default();
+            let last = backing.len().saturating_sub(1);
+
+            let backing_indices = backing.iter().enumerate().map(|(pos, index)| {
+                let index_name = index["index_name"].as_str().unwrap_or("").to_string();
+                let cat = indices_by_name.get(index_name.as_str());
+                let cat_field = |key: &str| cat.and_then(|c| c[key].as_str()).unwrap_or("");
+                BackingIndex {
+                    health: cat_field("health").to_string(),
+                    docs_count: cat_field("docs.count").parse().unwrap_or(0),
+                    store_size_bytes: cat_field("store.size").parse().unwrap_or(0),
+                    ilm_policy: index["ilm_policy"].as_str().map(|s| s.to_string()),
+                    managed_by: index["managed_by"].as_str().map(|s| s.to_string()),
+                    // Poslední backing index je write index
+                    is_write_index: pos == last,
+                    name: index_name,
+                }
+            }).collect();
+
+            let stream_stats = stats_by_name.get(name.as_str());
+            let lifecycle = ds.get("lifecycle")
+                .filter(|l| l["enabled"].as_bool().unwrap_or(true))
+                .map(|l| l["data_retention"].as_str().unwrap_or("infinite").to_string());
+
+            DataStreamInfo {
+                status: ds["status"].as_str().unwrap_or("").to_string(),
+                generation: ds["generation"].as_u64().unwrap_or(0),
+                template: ds["template"].as_str().unwrap_or("").to_string(),
+                ilm_policy: ds["ilm_policy"].as_str().map(|s| s.to_string()),
+                lifecycle,
+                timestamp_field: ds["timestamp_field"]["name"].as_str().unwrap_or("@timestamp").to_string(),
+                hidden: ds["hidden"].as_bool().unwrap_or(false),
+                system: ds["system"].as_bool().unwrap_or(false),
+                backing_indices,
+                store_size_bytes: stream_stats.and_then(|s| s["store_size_bytes"].as_u64()).unwrap_or(0),
+                maximum_timestamp: stream_stats.and_then(|s| s["maximum_timestamp"].as_i64()).filter(|t| *t > 0),
+                name,
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+/// Šablony, které vytvářejí data streamy (jen takové jdou použít pro `PUT /_data_stream/{name}`)
+fn data_stream_templates(response: &Value) -> Vec<String> {
+    let mut templates: Vec<String> = response["index_templates"].as_array()
+        .map(|items| items.iter()
+            .filter(|t| t["index_template"].get("data_stream").is_some())
+            .filter_map(|t| {
+                let name = t["name"].as_str()?;
+                let patterns: Vec<&str> = t["index_template"]["index_patterns"].as_array()?
+                    .iter()
+                    .filter_map(|p| p.as_str())
+                    .collect();
+                Some(format!("{}: {}", name, patterns.join(", ")))
+            })
+            .collect())
+        .unwrap_or_default();
+    templates.sort();
+    templates
+}
+
+/// Data streamy existují od ES 7.9
+fn require_data_streams(client: &EsClient) -> anyhow::Result<()> {
+    if !client.version().map(|v| v.at_least(7, 9)).unwrap_or(true) {
+        anyhow::bail!("Data streams require Elasticsearch 7.9 or higher");
+    }
+    Ok(())
+}
+
+/// GET /data-streams - Přehled data streamů
+pub async fn data_streams_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<DataStreamsQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_data_streams_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load data streams: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading data streams");
+            error = Some("Timeout loading data streams".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = DataStreamsTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_data_streams_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &DataStreamsQuery,
+) -> anyhow::Result<DataStreamsData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    require_data_streams(&client)?;
+
+    let streams: Value = client.get("/_data_stream/*?expand_wildcards=all").await?;
+    let stats: Value = client.get("/_data_stream/*/_stats?expand_wildcards=all").await.unwrap_or(Value::Null);
+    let cat_indices: Vec<Value> = client
+        .get("/_cat/indices?format=json&bytes=b&expand_wildcards=all&h=index,health,docs.count,store.size")
+        .await
+        .unwrap_or_default();
+
+    let mut data_streams = parse_data_streams(&streams, &stats, &cat_indices);
+
+    let filter = query.filter.trim().to_string();
+    data_streams.retain(|ds| {
+        if !query.show_hidden && (ds.hidden || ds.system || ds.name.starts_with('.')) {
+            return false;
+        }
+        filter.is_empty()
+            || filter.split(',')
+                .map(|p| p.trim())
+                .filter(|p| !p.is_empty())
+                .any(|p| {
+                    let pattern = if p.contains('*') { p.to_string() } else { format!("*{}*", p) };
+                    matches_pattern(&ds.name, &pattern) || matches_pattern(&ds.template, &pattern)
+                })
+    });
+
+    let templates = match client.get::<Value>("/_index_template").await {
+        Ok(response) => data_stream_templates(&response),
+        Err(e) => {
+            tracing::warn!("Failed to load index templates: {}", e);
+            Vec::new()
+        }
+    };
+
+    Ok(DataStreamsData {
+        data_streams,
+        filter,
+        show_hidden: query.show_hidden,
+        templates,
+    })
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<DataStreamActionResponse>) {
+    (status, Json(DataStreamActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+/// POST /data-streams/create - Vytvoří data stream (musí odpovídat data stream šabloně)
+pub async fn create_data_stream(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<CreateDataStreamRequest>,
+) -> Result<Json<DataStreamActionResponse>, (StatusCode, Json<DataStreamActionResponse>)> {
+    let name = req.name.trim();
+    if name.is_empty() || name != name.to_lowercase() || name.starts_with(['-', '_', '+']) || name.contains([' ', '*', ',', '#', '/']) {
+        return Err(error_response(StatusCode::BAD_REQUEST, format!("Invalid data stream name '{}'", name)));
+    }
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.put_raw(&format!("/_data_stream/{}", name), json!({})).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Created data stream {}", name);
+
+    Ok(Json(DataStreamActionResponse {
+        success: true,
+        message: Some(format!("Data stream {} vytvořen", name)),
+        error: None,
+    }))
+}
+
+/// POST /data-streams/{name}/rollover - Ruční rollover na novou generaci
+pub async fn rollover_data_stream(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(name): Path<String>,
+) -> Result<Json<DataStreamActionResponse>, (StatusCode, Json<DataStreamActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/{}/_rollover", name), json!({})).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    let result: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+    let new_index = result["new_index"].as_str().unwrap_or("?");
+
+    tracing::info!("Rolled over data stream {} to {}", name, new_index);
+
+    Ok(Json(DataStreamActionResponse {
+        success: true,
+        message: Some(format!("Rollover {} → {}", name, new_index)),
+        error: None,
+    }))
+}
+
+/// POST /data-streams/{name}/delete - Smaže data stream včetně všech backing indexů
+pub async fn delete_data_stream(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(name): Path<String>,
+) -> Result<Json<DataStreamActionResponse>, (StatusCode, Json<DataStreamActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.delete_raw(&format!("/_data_stream/{}", name)).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Deleted data stream {}", name);
+
+    Ok(Json(DataStreamActionResponse {
+        success: true,
+        message: Some(format!("Data stream {} smazán", name)),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::backing_index_map;
+
+    fn streams() -> Value {
+        json!({
+            "data_streams": [{
+                "name": "logs-app-default",
+                "timestamp_field": { "name": "@timestamp" },
+                "indices": [
+                    { "index_name": ".ds-logs-app-default-2026.10.01-000001", "ilm_policy": "logs", "managed_by": "Index Lifecycle Management" },
+                    { "index_name": ".ds-logs-app-default-2026.10.08-000002", "ilm_policy": "logs", "managed_by": "Index Lifecycle Management" }
+                ],
+                "generation": 2,
+                "status": "GREEN",
+                "template": "logs",
+                "ilm_policy": "logs",
+                "hidden": false,
+                "system": false
+            }]
+        })
+    }
+
+    #[test]
+    fn test_parse_data_streams() {
+        let stats = json!({
+            "data_streams": [{ "data_stream": "logs-app-default", "backing_indices": 2, "store_size_bytes": 2048, "maximum_timestamp": 1760000000000_i64 }]
+        });
+        let cat = vec![
+            json!({ "index": ".ds-logs-app-default-2026.10.01-000001", "health": "green", "docs.count": "10", "store.size": "1024" }),
+            json!({ "index": ".ds-logs-app-default-2026.10.08-000002", "health": "yellow", "docs.count": "5", "store.size": "1024" }),
+        ];
+
+        let result = parse_data_streams(&streams(), &stats, &cat);
+        assert_eq!(result.len(), 1);
+        let ds = &result[0];
+        assert_eq!(ds.generation, 2);
+        assert_eq!(ds.ilm_policy.as_deref(), Some("logs"));
+        assert_eq!(ds.lifecycle, None);
+        assert_eq!(ds.store_size_bytes, 2048);
+        assert_eq!(ds.write_index(), Some(".ds-logs-app-default-2026.10.08-000002"));
+        assert_eq!(ds.backing_indices[0].docs_count, 10);
+        assert_eq!(ds.docs_count_formatted(), "15");
+        assert_eq!(ds.status_color(), "success");
+    }
+
+    #[test]
+    fn test_backing_index_map_and_templates() {
+        let map = backing_index_map(&streams());
+        assert_eq!(map.get(".ds-logs-app-default-2026.10.01-000001").map(|s| s.as_str()), Some("logs-app-default"));
+        assert_eq!(map.len(), 2);
+
+        let templates = data_stream_templates(&json!({
+            "index_templates": [
+                { "name": "logs", "index_template": { "index_patterns": ["logs-*-*"], "data_stream": {} } },
+                { "name": "plain", "index_template": { "index_patterns": ["plain-*"] } }
+            ]
+        }));
+        assert_eq!(templates, vec!["logs: logs-*-*".to_string()]);
+    }
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+
+use crate::diagnostics::collect_bundle;
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+
+/// GET /diagnostics/bundle - Stáhne diagnostický balíček clusteru (zip s manifestem)
+pub async fn diagnostics_bundle(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Response, (StatusCode, String)> {
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let client = create_es_client(&state, &endpoint, false).await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+
+    // Limit hlídá collect_bundle sám, aby se zip s manifestem vrátil i při pomalém clusteru
+    let bundle = collect_bundle(&client, &endpoint).await.map_err(|e| {
+        tracing::error!("Failed to build diagnostics bundle: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+    })?;
+
+    tracing::info!(
+        "Diagnostics bundle {} created ({} bytes, {} failed APIs)",
+        bundle.file_name, bundle.data.len(), bundle.failed
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", bundle.file_name)),
+            (header::HeaderName::from_static("x-diagnostics-failed"), bundle.failed.to_string()),
+        ],
+        bundle.data,
+    ).into_response())
+}
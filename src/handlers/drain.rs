@@ -0,0 +1,344 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::shards::{load_shards_data, matches_pattern};
+use crate::templates::NodeDrainTemplate;
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::NodeDrainStatus;
+
+const EXCLUDE_NAME: &str = "cluster.routing.allocation.exclude._name";
+
+#[derive(Debug, Deserialize)]
+pub struct DrainStatusQuery {
+    pub start: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct DrainActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shards: Option<usize>,          // počet shardů na nodu při spuštění drainu
+}
+
+/// Identita nodu pro porovnání s exclude pravidly
+struct NodeIdentity {
+    id: String,
+    name: String,
+    ip: String,
+    host: String,
+}
+
+/// Čárkou oddělený seznam ze settings (s `flat_settings` může být i pole)
+fn exclusion_list(value: &Value) -> Vec<String> {
+    let items: Vec<String> = match value {
+        Value::String(s) => s.split(',').map(|s| s.to_string()).collect(),
+        Value::Array(items) => items.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect(),
+        _ => Vec::new(),
+    };
+    items.into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Přidá node do seznamu (bez duplicit, ostatní položky zachová)
+fn add_to_list(list: &[String], name: &str) -> Vec<String> {
+    let mut list = list.to_vec();
+    if !list.iter().any(|item| item == name) {
+        list.push(name.to_string());
+    }
+    list
+}
+
+/// Odebere jen daný node, ostatní položky (i wildcardy) zachová
+fn remove_from_list(list: &[String], name: &str) -> Vec<String> {
+    list.iter().filter(|item| item.as_str() != name).cloned().collect()
+}
+
+/// Prázdný seznam = null (odstraní nastavení)
+fn list_value(list: &[String]) -> Value {
+    if list.is_empty() { Value::Null } else { json!(list.join(",")) }
+}
+
+/// Scope, jehož hodnota platí - transient přebíjí persistent úplně (stejně jako watermarky v advisoru)
+fn effective_scope(settings: &Value, key: &str) -> &'static str {
+    if settings["transient"][key].is_null() { "persistent" } else { "transient" }
+}
+
+/// Exclude pravidla (kromě přesného `_name`, které spravuje drain), která node zasahují
+fn other_exclusions(settings: &Value, node: &NodeIdentity) -> Vec<String> {
+    let mut found = Vec::new();
+    for (attribute, value) in [("_name", &node.name), ("_id", &node.id), ("_ip", &node.ip), ("_host", &node.host)] {
+        let key = format!("cluster.routing.allocation.exclude.{}", attribute);
+        let scope = effective_scope(settings, &key);
+        let list = exclusion_list(&settings[scope][&key]);
+        let matched = list.iter().any(|pattern| {
+            if attribute == "_name" && pattern == value {
+                return false;
+            }
+            pattern == value || (pattern.contains('*') && matches_pattern(value, pattern))
+        });
+        if matched && !value.is_empty() {
+            found.push(format!("exclude.{} ({})", attribute, scope));
+        }
+    }
+    found
+}
+
+/// Počet shardů na nodu a kolik z nich právě odchází (`_cat/shards` u relokace vrací "a -> ip id b")
+fn count_node_shards<'a>(nodes: impl Iterator<Item = &'a str>, node_name: &str) -> (usize, usize) {
+    let relocating_prefix = format!("{} -> ", node_name);
+    let mut on_node = 0;
+    let mut relocating = 0;
+    for node in nodes {
+        if node == node_name {
+            on_node += 1;
+        } else if node.starts_with(&relocating_prefix) {
+            on_node += 1;
+            relocating += 1;
+        }
+    }
+    (on_node, relocating)
+}
+
+async fn load_node_identity(client: &EsClient, node_id: &str) -> anyhow::Result<NodeIdentity> {
+    let response: Value = client.get(&format!("/_nodes/{}", node_id)).await?;
+    let node = &response["nodes"][node_id];
+    if node.is_null() {
+        return Err(anyhow::anyhow!("Node not found"));
+    }
+    Ok(NodeIdentity {
+        id: node_id.to_string(),
+        name: node["name"].as_str().unwrap_or("").to_string(),
+        ip: node["ip"].as_str().unwrap_or("").to_string(),
+        host: node["host"].as_str().unwrap_or("").to_string(),
+    })
+}
+
+async fn load_drain_status(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    node_id: &str,
+    start_count: Option<usize>,
+) -> anyhow::Result<NodeDrainStatus> {
+    let client = create_es_client(state, endpoint, true).await?;
+    let node = load_node_identity(&client, node_id).await?;
+    let settings: Value = client.get("/_cluster/settings?flat_settings=true").await?;
+
+    let exclude_scope = effective_scope(&settings, EXCLUDE_NAME);
+    let draining = exclusion_list(&settings[exclude_scope][EXCLUDE_NAME]).contains(&node.name);
+
+    // Stejná data jako stránka Shards
+    let shards = load_shards_data(state, endpoint, "*").await?;
+    let (shards_on_node, relocating_away) = count_node_shards(
+        shards.all_shards.iter().map(|s| s.node.as_str()),
+        &node.name,
+    );
+
+    Ok(NodeDrainStatus {
+        node_id: node.id.clone(),
+        node_name: node.name.clone(),
+        draining,
+        exclude_scope: exclude_scope.to_string(),
+        other_exclusions: other_exclusions(&settings, &node),
+        shards_on_node,
+        relocating_away,
+        start_count,
+    })
+}
+
+/// GET /nodes/{id}/drain-status - Stav drainu nodu (partial, během drainu se obnovuje)
+pub async fn drain_status(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(node_id): Path<String>,
+    Query(query): Query<DrainStatusQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_drain_status(&state, endpoint, &node_id, query.start)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load drain status: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading drain status for: {}", node_id);
+            None
+        }
+    };
+
+    let template = NodeDrainTemplate { data, node_id };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<DrainActionResponse>) {
+    (status, Json(DrainActionResponse { success: false, message: None, error: Some(error), shards: None }))
+}
+
+async fn put_settings(client: &EsClient, body: Value) -> Result<(), (StatusCode, Json<DrainActionResponse>)> {
+    let (status, response) = client.put_raw("/_cluster/settings", body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+    Ok(())
+}
+
+/// POST /nodes/{id}/drain - Přidá node do `exclude._name`, shardy se přesunou jinam
+pub async fn drain_node(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(node_id): Path<String>,
+) -> Result<Json<DrainActionResponse>, (StatusCode, Json<DrainActionResponse>)> {
+    let (client, endpoint) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let node = load_node_identity(&client, &node_id).await
+        .map_err(|e| error_response(StatusCode::NOT_FOUND, e.to_string()))?;
+    let settings: Value = client.get("/_cluster/settings?flat_settings=true").await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Node se přidá do seznamu, který skutečně platí - persistent hodnotu by transient přebil
+    let scope = effective_scope(&settings, EXCLUDE_NAME);
+    let list = add_to_list(&exclusion_list(&settings[scope][EXCLUDE_NAME]), &node.name);
+    let mut body = json!({});
+    body[scope] = json!({ EXCLUDE_NAME: list_value(&list) });
+    put_settings(&client, body).await?;
+
+    let shards = load_shards_data(&state, &endpoint, "*").await
+        .map(|data| count_node_shards(data.all_shards.iter().map(|s| s.node.as_str()), &node.name).0)
+        .ok();
+
+    tracing::info!("Draining node {} ({}), {} exclude._name = {}", node.name, node_id, scope, list.join(","));
+
+    Ok(Json(DrainActionResponse {
+        success: true,
+        message: Some(format!("Node {} se vyprazdňuje", node.name)),
+        error: None,
+        shards,
+    }))
+}
+
+/// POST /nodes/{id}/undrain - Odebere node z `exclude._name` (ostatní položky zůstanou)
+pub async fn undrain_node(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(node_id): Path<String>,
+) -> Result<Json<DrainActionResponse>, (StatusCode, Json<DrainActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let node = load_node_identity(&client, &node_id).await
+        .map_err(|e| error_response(StatusCode::NOT_FOUND, e.to_string()))?;
+    let settings: Value = client.get("/_cluster/settings?flat_settings=true").await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    // Odeber z persistent i transient, jinak by transient exclusion node dál vyprazdňovala
+    let mut body = json!({});
+    for scope in ["persistent", "transient"] {
+        let list = exclusion_list(&settings[scope][EXCLUDE_NAME]);
+        if list.contains(&node.name) {
+            body[scope] = json!({ EXCLUDE_NAME: list_value(&remove_from_list(&list, &node.name)) });
+        }
+    }
+
+    if body.as_object().is_some_and(|o| o.is_empty()) {
+        return Err(error_response(StatusCode::BAD_REQUEST, format!("Node {} is not in {}", node.name, EXCLUDE_NAME)));
+    }
+
+    put_settings(&client, body).await?;
+
+    tracing::info!("Undrained node {} ({})", node.name, node_id);
+
+    Ok(Json(DrainActionResponse {
+        success: true,
+        message: Some(format!("Node {} odebrán z exclude._name", node.name)),
+        error: None,
+        shards: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exclusion_list_updates() {
+        let list = exclusion_list(&json!("node-1, node-*,"));
+        assert_eq!(list, vec!["node-1", "node-*"]);
+        assert_eq!(exclusion_list(&json!(["a", "b"])), vec!["a", "b"]);
+
+        let added = add_to_list(&list, "node-2");
+        assert_eq!(added, vec!["node-1", "node-*", "node-2"]);
+        assert_eq!(add_to_list(&added, "node-2").len(), 3);
+
+        let removed = remove_from_list(&added, "node-1");
+        assert_eq!(removed, vec!["node-*", "node-2"]);
+        assert_eq!(list_value(&remove_from_list(&["node-1".to_string()], "node-1")), Value::Null);
+    }
+
+    #[test]
+    fn test_other_exclusions() {
+        let node = NodeIdentity {
+            id: "abc".to_string(),
+            name: "node-1".to_string(),
+            ip: "10.0.0.1".to_string(),
+            host: "host-1".to_string(),
+        };
+        let settings = json!({
+            "persistent": { EXCLUDE_NAME: "node-1" },
+            "transient": { "cluster.routing.allocation.exclude._ip": "10.0.0.1", EXCLUDE_NAME: "node-*" }
+        });
+        let found = other_exclusions(&settings, &node);
+        assert_eq!(found, vec!["exclude._name (transient)", "exclude._ip (transient)"]);
+
+        // Persistent pravidla přebitá transient hodnotou node nezasahují
+        let overridden = json!({
+            "persistent": { "cluster.routing.allocation.exclude._ip": "10.0.0.1" },
+            "transient": { "cluster.routing.allocation.exclude._ip": "10.0.0.2" }
+        });
+        assert!(other_exclusions(&overridden, &node).is_empty());
+    }
+
+    #[test]
+    fn test_effective_scope() {
+        let persistent = json!({ "persistent": { EXCLUDE_NAME: "node-1" }, "transient": {} });
+        assert_eq!(effective_scope(&persistent, EXCLUDE_NAME), "persistent");
+
+        let transient = json!({ "persistent": { EXCLUDE_NAME: "node-1" }, "transient": { EXCLUDE_NAME: "node-2" } });
+        assert_eq!(effective_scope(&transient, EXCLUDE_NAME), "transient");
+        assert!(!exclusion_list(&transient[effective_scope(&transient, EXCLUDE_NAME)][EXCLUDE_NAME]).contains(&"node-1".to_string()));
+    }
+
+    #[test]
+    fn test_count_node_shards() {
+        let nodes = ["node-1", "node-2", "node-1 -> 10.0.0.2 xyz node-2", "node-10", "UNASSIGNED"];
+        assert_eq!(count_node_shards(nodes.into_iter(), "node-1"), (2, 1));
+    }
+}
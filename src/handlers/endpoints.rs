@@ -12,9 +12,15 @@ use askama::Template;
 
 use crate::db::{Database, models::{CreateEndpoint, UpdateEndpoint}};
 use crate::templates::{EndpointsTemplate, PageContext};
+use crate::sampler::MetricsSampler;
+use crate::live::LiveHub;
+use crate::alerts::AlertEvaluator;
 
 pub struct AppState {
     pub db: Database,
+    pub sampler: MetricsSampler,
+    pub live: LiveHub,
+    pub alerts: AlertEvaluator,
 }
 
 fn escape_attr(value: &str) -> String {
@@ -304,7 +310,46 @@ pub async fn get_active_endpoint(
         .parse::<i64>()
         .ok()?;
 
-    state.db.get_endpoint(endpoint_id).await.ok()?
+    let endpoint = state.db.get_endpoint(endpoint_id).await.ok()??;
+
+    // Endpoint, se kterým uživatel pracuje, se vzorkuje na pozadí (historie metrik)
+    state.sampler.touch(endpoint.id);
+
+    Some(endpoint)
+}
+
+/// Helper funkce - ES klient pro endpoint s heslem z DB (volitelně s detekcí verze)
+pub async fn create_es_client(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    detect_version: bool,
+) -> anyhow::Result<crate::es::EsClient> {
+    let password = state.db.get_endpoint_password(endpoint).await;
+
+    let mut client = crate::es::EsClient::new(
+        endpoint.url.clone(),
+        endpoint.insecure,
+        endpoint.username.clone(),
+        password,
+    )?;
+    if detect_version {
+        client.detect_version().await?;
+    }
+    Ok(client)
+}
+
+/// Helper funkce - ES klient pro aktivní endpoint, chyby jako (status, zpráva) pro akční handlery
+pub async fn active_es_client(
+    state: &AppState,
+    jar: &CookieJar,
+    detect_version: bool,
+) -> Result<(crate::es::EsClient, crate::db::models::Endpoint), (StatusCode, String)> {
+    let endpoint = get_active_endpoint(state, jar).await
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let client = create_es_client(state, &endpoint, detect_version).await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+    Ok((client, endpoint))
 }
 
 /// POST /endpoints/:id/test - Otestuje připojení k endpointu
@@ -0,0 +1,36 @@
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint};
+use crate::live::LiveEvent;
+
+fn to_sse(event: LiveEvent) -> Result<Event, Infallible> {
+    Ok(Event::default().event(event.name).data(event.data))
+}
+
+/// GET /events - SSE stream změn aktivního endpointu (health, metrics, shards, recovery)
+pub async fn live_events(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    let Some(endpoint) = get_active_endpoint(&state, &jar).await else {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    };
+
+    let (replay, receiver) = state.live.subscribe(state.clone(), endpoint);
+
+    // Nový odběratel dostane hned poslední health a metriky, pak živé změny
+    // (zpožděný odběratel přeskočí ztracené zprávy, další tick je doplní)
+    let stream = tokio_stream::iter(replay)
+        .chain(BroadcastStream::new(receiver).filter_map(|event| event.ok()))
+        .map(to_sse);
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
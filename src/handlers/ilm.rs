@@ -0,0 +1,858 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{IlmPageTemplate, IlmPolicyEditorTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{
+    IlmExplain, IlmPhaseForm, IlmPhaseSummary, IlmPoliciesData, IlmPolicyEditorData, IlmPolicySummary,
+    ILM_PHASES,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct IlmQuery {
+    #[serde(default)]
+    pub tab: String,
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub show_managed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IlmEditorQuery {
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IlmPolicySaveRequest {
+    pub name: String,
+    #[serde(default)]
+    pub mode: String,                   // form | json
+    #[serde(default)]
+    pub phases: BTreeMap<String, IlmPhaseForm>,
+    #[serde(default)]
+    pub meta: String,
+    #[serde(default)]
+    pub raw: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IlmRetryRequest {
+    pub indices: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IlmStepKey {
+    pub phase: String,
+    #[serde(default)]
+    pub action: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IlmMoveRequest {
+    pub current_step: IlmStepKey,
+    pub next_step: IlmStepKey,
+}
+
+#[derive(Serialize)]
+pub struct IlmActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Akce povolené v jednotlivých fázích (dle dokumentace ILM)
+fn allowed_actions(phase: &str) -> &'static [&'static str] {
+    match phase {
+        "hot" => &["rollover", "set_priority", "unfollow", "readonly", "shrink", "forcemerge", "searchable_snapshot", "downsample"],
+        "warm" => &["set_priority", "unfollow", "readonly", "allocate", "migrate", "shrink", "forcemerge", "downsample"],
+        "cold" => &["set_priority", "unfollow", "readonly", "searchable_snapshot", "allocate", "migrate", "freeze", "downsample"],
+        "frozen" => &["unfollow", "searchable_snapshot"],
+        "delete" => &["wait_for_snapshot", "delete"],
+        _ => &[],
+    }
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+/// Zpracuje odpověď `GET /_ilm/policy`
+fn parse_policies(response: &Value) -> Vec<IlmPolicySummary> {
+    let mut policies: Vec<IlmPolicySummary> = response.as_object()
+        .map(|items| items.iter().map(|(name, item)| {
+            let policy = &item["policy"];
+            let phases = ILM_PHASES.iter()
+                .filter_map(|phase| {
+                    let def = policy["phases"].get(*phase)?;
+                    Some(IlmPhaseSummary {
+                        name: phase.to_string(),
+                        min_age: def["min_age"].as_str().unwrap_or("0ms").to_string(),
+                        actions: def["actions"].as_object()
+                            .map(|a| a.keys().cloned().collect())
+                            .unwrap_or_default(),
+                    })
+                })
+                .collect();
+            IlmPolicySummary {
+                name: name.clone(),
+                version: item["version"].as_i64(),
+                modified_date: item["modified_date"].as_str().unwrap_or("").to_string(),
+                phases,
+                in_use_indices: item["in_use_by"]["indices"].as_array().map(|a| a.len()),
+                in_use_data_streams: item["in_use_by"]["data_streams"].as_array().map(|a| a.len()),
+                managed: name.starts_with('.') || policy["_meta"]["managed"].as_bool().unwrap_or(false),
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    policies.sort_by(|a, b| a.name.cmp(&b.name));
+    policies
+}
+
+/// Zpracuje odpověď `GET /{index}/_ilm/explain`
+pub(crate) fn parse_explain(response: &Value) -> Vec<IlmExplain> {
+    let mut result: Vec<IlmExplain> = response["indices"].as_object()
+        .map(|items| items.iter().map(|(index, e)| {
+            let text = |key: &str| e[key].as_str().map(|s| s.to_string());
+            let step_info = e.get("step_info").filter(|i| is_non_empty(i)).map(|info| {
+                match (info["type"].as_str(), info["reason"].as_str()) {
+                    (Some(kind), Some(reason)) => format!("{}: {}", kind, reason),
+                    (None, Some(reason)) => reason.to_string(),
+                    _ => pretty(info),
+                }
+            });
+            IlmExplain {
+                index: index.clone(),
+                managed: e["managed"].as_bool().unwrap_or(false),
+                policy: text("policy"),
+                phase: text("phase"),
+                action: text("action"),
+                step: text("step"),
+                age: text("age"),
+                failed_step: text("failed_step"),
+                step_info,
+                is_auto_retryable_error: e["is_auto_retryable_error"].as_bool().unwrap_or(false),
+                failed_step_retry_count: e["failed_step_retry_count"].as_u64().unwrap_or(0),
+                phase_definition: e["phase_execution"].get("phase_definition").map(pretty),
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    result.sort_by(|a, b| a.index.cmp(&b.index));
+    result
+}
+
+fn is_non_empty(value: &Value) -> bool {
+    value.as_object().map(|o| !o.is_empty()).unwrap_or(false)
+}
+
+/// Má objekt přesně dané klíče (podmnožinu), aby šel zobrazit ve formuláři bez ztráty
+fn has_only_keys(value: &Value, keys: &[&str]) -> bool {
+    value.as_object()
+        .map(|o| o.keys().all(|k| keys.contains(&k.as_str())))
+        .unwrap_or(false)
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Rozloží fázi politiky na formulář, nepodporované akce nechá v `extra_actions`
+fn phase_to_form(def: &Value) -> IlmPhaseForm {
+    let mut form = IlmPhaseForm {
+        enabled: true,
+        min_age: def["min_age"].as_str().unwrap_or("").to_string(),
+        delete_searchable_snapshot: true,
+        ..Default::default()
+    };
+    let mut extra = serde_json::Map::new();
+
+    for (action, config) in def["actions"].as_object().into_iter().flatten() {
+        let mapped = match action.as_str() {
+            "rollover" if has_only_keys(config, &["max_age", "max_primary_shard_size", "max_docs"]) => {
+                form.rollover_max_age = value_text(&config["max_age"]);
+                form.rollover_max_primary_shard_size = value_text(&config["max_primary_shard_size"]);
+                form.rollover_max_docs = value_text(&config["max_docs"]);
+                true
+            }
+            "forcemerge" if has_only_keys(config, &["max_num_segments"]) => {
+                form.forcemerge_segments = value_text(&config["max_num_segments"]);
+                true
+            }
+            "shrink" if has_only_keys(config, &["number_of_shards"]) => {
+                form.shrink_shards = value_text(&config["number_of_shards"]);
+                true
+            }
+            "allocate" if has_only_keys(config, &["number_of_replicas"]) => {
+                form.replicas = value_text(&config["number_of_replicas"]);
+                true
+            }
+            "set_priority" if has_only_keys(config, &["priority"]) => {
+                form.priority = value_text(&config["priority"]);
+                true
+            }
+            "readonly" if has_only_keys(config, &[]) => {
+                form.readonly = true;
+                true
+            }
+            "searchable_snapshot" if has_only_keys(config, &["snapshot_repository"]) => {
+                form.searchable_snapshot_repository = value_text(&config["snapshot_repository"]);
+                true
+            }
+            "delete" if has_only_keys(config, &["delete_searchable_snapshot"]) => {
+                form.delete_index = true;
+                form.delete_searchable_snapshot = config["delete_searchable_snapshot"].as_bool().unwrap_or(true);
+                true
+            }
+            _ => false,
+        };
+        if !mapped {
+            extra.insert(action.clone(), config.clone());
+        }
+    }
+
+    if !extra.is_empty() {
+        form.extra_actions = pretty(&Value::Object(extra));
+    }
+    form
+}
+
+/// Připraví data editoru z odpovědi `GET /_ilm/policy/{name}`
+fn policy_to_editor(name: &str, policy: &Value) -> IlmPolicyEditorData {
+    let phases = ILM_PHASES.iter()
+        .filter_map(|phase| policy["phases"].get(*phase).map(|def| (phase.to_string(), phase_to_form(def))))
+        .collect();
+
+    IlmPolicyEditorData {
+        name: name.to_string(),
+        is_new: false,
+        phases,
+        meta_json: policy.get("_meta").map(pretty).unwrap_or_default(),
+        raw_json: pretty(&json!({ "policy": policy })),
+    }
+}
+
+/// Kontrola formátu doby (`30d`, `12h`, `0ms`)
+fn validate_time_value(label: &str, value: &str) -> Result<(), String> {
+    let digits = value.chars().take_while(|c| c.is_ascii_digit()).count();
+    let unit = &value[digits..];
+    if digits == 0 || !["d", "h", "m", "s", "ms", "micros", "nanos"].contains(&unit) {
+        return Err(format!("{}: '{}' is not a valid time value (e.g. 30d, 12h)", label, value));
+    }
+    Ok(())
+}
+
+fn parse_count(label: &str, value: &str) -> Result<u64, String> {
+    value.trim().parse::<u64>()
+        .map_err(|_| format!("{}: '{}' must be a whole number", label, value.trim()))
+}
+
+/// Sestaví definici jedné fáze z formuláře
+fn build_phase(phase: &str, form: &IlmPhaseForm) -> Result<Value, String> {
+    let mut actions = match form.extra_actions.trim() {
+        "" => serde_json::Map::new(),
+        raw => match serde_json::from_str::<Value>(raw) {
+            Ok(Value::Object(map)) => map,
+            Ok(_) => return Err(format!("{} phase: other actions must be a JSON object", phase)),
+            Err(e) => return Err(format!("{} phase: other actions are not valid JSON: {}", phase, e)),
+        },
+    };
+
+    let label = |field: &str| format!("{} phase {}", phase, field);
+
+    let mut rollover = serde_json::Map::new();
+    if !form.rollover_max_age.trim().is_empty() {
+        validate_time_value(&label("rollover max age"), form.rollover_max_age.trim())?;
+        rollover.insert("max_age".to_string(), json!(form.rollover_max_age.trim()));
+    }
+    if !form.rollover_max_primary_shard_size.trim().is_empty() {
+        rollover.insert("max_primary_shard_size".to_string(), json!(form.rollover_max_primary_shard_size.trim()));
+    }
+    if !form.rollover_max_docs.trim().is_empty() {
+        rollover.insert("max_docs".to_string(), json!(parse_count(&label("rollover max docs"), &form.rollover_max_docs)?));
+    }
+    if !rollover.is_empty() {
+        actions.insert("rollover".to_string(), Value::Object(rollover));
+    }
+    if !form.forcemerge_segments.trim().is_empty() {
+        let segments = parse_count(&label("force merge segments"), &form.forcemerge_segments)?;
+        actions.insert("forcemerge".to_string(), json!({ "max_num_segments": segments }));
+    }
+    if !form.shrink_shards.trim().is_empty() {
+        let shards = parse_count(&label("shrink shards"), &form.shrink_shards)?;
+        actions.insert("shrink".to_string(), json!({ "number_of_shards": shards }));
+    }
+    if !form.replicas.trim().is_empty() {
+        let replicas = parse_count(&label("replicas"), &form.replicas)?;
+        actions.insert("allocate".to_string(), json!({ "number_of_replicas": replicas }));
+    }
+    if !form.priority.trim().is_empty() {
+        let priority = parse_count(&label("priority"), &form.priority)?;
+        actions.insert("set_priority".to_string(), json!({ "priority": priority }));
+    }
+    if form.readonly {
+        actions.insert("readonly".to_string(), json!({}));
+    }
+    if !form.searchable_snapshot_repository.trim().is_empty() {
+        actions.insert("searchable_snapshot".to_string(), json!({ "snapshot_repository": form.searchable_snapshot_repository.trim() }));
+    }
+    if phase == "delete" && form.delete_index {
+        actions.insert("delete".to_string(), json!({ "delete_searchable_snapshot": form.delete_searchable_snapshot }));
+    }
+
+    let allowed = allowed_actions(phase);
+    if let Some(action) = actions.keys().find(|a| !allowed.contains(&a.as_str())) {
+        return Err(format!("Action '{}' is not allowed in the {} phase", action, phase));
+    }
+    if phase == "hot"
+        && !actions.contains_key("rollover")
+        && let Some(action) = ["forcemerge", "shrink", "searchable_snapshot"].iter().find(|a| actions.contains_key(**a))
+    {
+        return Err(format!("The {} action in the hot phase requires rollover", action));
+    }
+    if phase == "frozen" && !actions.contains_key("searchable_snapshot") {
+        return Err("The frozen phase requires a searchable snapshot repository".to_string());
+    }
+
+    let mut def = serde_json::Map::new();
+    if !form.min_age.trim().is_empty() {
+        validate_time_value(&label("min age"), form.min_age.trim())?;
+        def.insert("min_age".to_string(), json!(form.min_age.trim()));
+    }
+    def.insert("actions".to_string(), Value::Object(actions));
+    Ok(Value::Object(def))
+}
+
+/// Sestaví body pro `PUT /_ilm/policy/{name}` ze strukturovaného formuláře nebo raw JSON
+fn build_policy_body(req: &IlmPolicySaveRequest) -> Result<Value, String> {
+    let name = req.name.trim();
+    if name.is_empty() || name.contains([' ', ',', '*', '/', '\\']) || name.starts_with('_') {
+        return Err(format!("Invalid policy name '{}'", name));
+    }
+
+    if req.mode == "json" {
+        let body: Value = serde_json::from_str(&req.raw)
+            .map_err(|e| format!("Policy is not valid JSON: {}", e))?;
+        let body = if body.get("policy").is_some() { body } else { json!({ "policy": body }) };
+        if !body["policy"]["phases"].is_object() {
+            return Err("Policy must contain a \"phases\" object".to_string());
+        }
+        return Ok(body);
+    }
+
+    let mut phases = serde_json::Map::new();
+    for phase in ILM_PHASES {
+        if let Some(form) = req.phases.get(*phase).filter(|f| f.enabled) {
+            phases.insert(phase.to_string(), build_phase(phase, form)?);
+        }
+    }
+    if phases.is_empty() {
+        return Err("Enable at least one phase".to_string());
+    }
+
+    let mut policy = serde_json::Map::new();
+    policy.insert("phases".to_string(), Value::Object(phases));
+    if !req.meta.trim().is_empty() {
+        let meta: Value = serde_json::from_str(&req.meta)
+            .map_err(|e| format!("_meta is not valid JSON: {}", e))?;
+        policy.insert("_meta".to_string(), meta);
+    }
+
+    Ok(json!({ "policy": policy }))
+}
+
+/// Body pro `POST /_ilm/move/{index}` (action/name cílového kroku jsou od 7.15 volitelné)
+fn build_move_body(req: &IlmMoveRequest) -> Result<Value, String> {
+    let current = &req.current_step;
+    if current.phase.trim().is_empty() || current.action.trim().is_empty() || current.name.trim().is_empty() {
+        return Err("Current step must have phase, action and name".to_string());
+    }
+    if !ILM_PHASES.contains(&req.next_step.phase.trim()) && req.next_step.phase.trim() != "new" {
+        return Err(format!("Unknown target phase '{}'", req.next_step.phase));
+    }
+    if !req.next_step.name.trim().is_empty() && req.next_step.action.trim().is_empty() {
+        return Err("Target step name requires an action".to_string());
+    }
+
+    let mut next = serde_json::Map::new();
+    next.insert("phase".to_string(), json!(req.next_step.phase.trim()));
+    if !req.next_step.action.trim().is_empty() {
+        next.insert("action".to_string(), json!(req.next_step.action.trim()));
+    }
+    if !req.next_step.name.trim().is_empty() {
+        next.insert("name".to_string(), json!(req.next_step.name.trim()));
+    }
+
+    Ok(json!({
+        "current_step": {
+            "phase": current.phase.trim(),
+            "action": current.action.trim(),
+            "name": current.name.trim()
+        },
+        "next_step": next
+    }))
+}
+
+/// ILM existuje od ES 6.6
+fn require_ilm(client: &EsClient) -> anyhow::Result<()> {
+    if !client.version().map(|v| v.at_least(6, 6)).unwrap_or(true) {
+        anyhow::bail!("Index lifecycle management requires Elasticsearch 6.6 or higher");
+    }
+    Ok(())
+}
+
+/// GET /ilm - ILM politiky a indexy v ERROR kroku
+pub async fn ilm_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<IlmQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_ilm_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load ILM policies: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading ILM policies");
+            error = Some("Timeout loading ILM policies".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = IlmPageTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_ilm_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &IlmQuery,
+) -> anyhow::Result<IlmPoliciesData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    require_ilm(&client)?;
+
+    let response: Value = client.get("/_ilm/policy").await?;
+    let mut policies = parse_policies(&response);
+
+    let filter = query.filter.trim().to_string();
+    policies.retain(|p| {
+        (query.show_managed || !p.managed)
+            && (filter.is_empty() || filter.split(',')
+                .map(|f| f.trim())
+                .filter(|f| !f.is_empty())
+                .any(|f| {
+                    let pattern = if f.contains('*') { f.to_string() } else { format!("*{}*", f) };
+                    matches_pattern(&p.name, &pattern)
+                }))
+    });
+
+    let status: Value = client.get("/_ilm/status").await.unwrap_or(Value::Null);
+    let operation_mode = status["operation_mode"].as_str().unwrap_or("UNKNOWN").to_string();
+
+    // `only_errors` umí ES až od 7.4, starší clustery vrátí vše a chyby se vyfiltrují níže
+    let explain_path = if client.version().map(|v| v.at_least(7, 4)).unwrap_or(false) {
+        "/*/_ilm/explain?only_errors=true&expand_wildcards=all"
+    } else {
+        "/*/_ilm/explain?expand_wildcards=all"
+    };
+    let error_indices = match client.get::<Value>(explain_path).await {
+        Ok(response) => parse_explain(&response).into_iter().filter(|e| e.is_error()).collect(),
+        Err(e) => {
+            tracing::warn!("Failed to load ILM errors: {}", e);
+            Vec::new()
+        }
+    };
+
+    let tab = if query.tab == "errors" { "errors" } else { "policies" };
+
+    Ok(IlmPoliciesData {
+        policies,
+        error_indices,
+        operation_mode,
+        filter,
+        show_managed: query.show_managed,
+        tab: tab.to_string(),
+    })
+}
+
+/// GET /ilm/policy/edit - Strukturovaný editor ILM politiky (bez name = nová politika)
+pub async fn ilm_policy_editor(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<IlmEditorQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let name = query.name.trim().to_string();
+    let mut error = None;
+
+    let data = if name.is_empty() {
+        let mut phases = BTreeMap::new();
+        phases.insert("hot".to_string(), IlmPhaseForm {
+            enabled: true,
+            rollover_max_age: "30d".to_string(),
+            rollover_max_primary_shard_size: "50gb".to_string(),
+            ..Default::default()
+        });
+        phases.insert("delete".to_string(), IlmPhaseForm {
+            min_age: "90d".to_string(),
+            delete_index: true,
+            delete_searchable_snapshot: true,
+            ..Default::default()
+        });
+        Some(IlmPolicyEditorData {
+            name: String::new(),
+            is_new: true,
+            phases,
+            meta_json: String::new(),
+            raw_json: String::new(),
+        })
+    } else {
+        match tokio::time::timeout(
+            tokio::time::Duration::from_secs(10),
+            load_policy_editor_data(&state, endpoint, &name)
+        ).await {
+            Ok(Ok(d)) => Some(d),
+            Ok(Err(e)) => {
+                tracing::error!("Failed to load ILM policy {}: {}", name, e);
+                error = Some(e.to_string());
+                None
+            }
+            Err(_) => {
+                tracing::error!("Timeout loading ILM policy {}", name);
+                error = Some("Timeout loading ILM policy".to_string());
+                None
+            }
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = IlmPolicyEditorTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_policy_editor_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    name: &str,
+) -> anyhow::Result<IlmPolicyEditorData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    require_ilm(&client)?;
+
+    let response: Value = client.get(&format!("/_ilm/policy/{}", name)).await?;
+    let policy = &response[name]["policy"];
+    if policy.is_null() {
+        anyhow::bail!("ILM policy '{}' not found", name);
+    }
+
+    Ok(policy_to_editor(name, policy))
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<IlmActionResponse>) {
+    (status, Json(IlmActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+/// POST /ilm/policy/save - Vytvoří nebo přepíše ILM politiku
+pub async fn save_ilm_policy(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<IlmPolicySaveRequest>,
+) -> Result<Json<IlmActionResponse>, (StatusCode, Json<IlmActionResponse>)> {
+    let body = build_policy_body(&req)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+    let name = req.name.trim();
+
+    let (status, response) = client.put_raw(&format!("/_ilm/policy/{}", name), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Saved ILM policy {}", name);
+
+    Ok(Json(IlmActionResponse {
+        success: true,
+        message: Some(format!("Politika {} uložena", name)),
+        error: None,
+    }))
+}
+
+/// POST /ilm/policy/{name}/delete - Smaže ILM politiku
+pub async fn delete_ilm_policy(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(name): Path<String>,
+) -> Result<Json<IlmActionResponse>, (StatusCode, Json<IlmActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.delete_raw(&format!("/_ilm/policy/{}", name)).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Deleted ILM policy {}", name);
+
+    Ok(Json(IlmActionResponse {
+        success: true,
+        message: Some(format!("Politika {} smazána", name)),
+        error: None,
+    }))
+}
+
+/// POST /ilm/retry - Zopakuje neúspěšný krok (`_ilm/retry`) pro indexy v ERROR
+pub async fn retry_ilm(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<IlmRetryRequest>,
+) -> Result<Json<IlmActionResponse>, (StatusCode, Json<IlmActionResponse>)> {
+    let indices: Vec<&str> = req.indices.iter()
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+        .collect();
+    if indices.is_empty() || indices.iter().any(|i| i.contains('*')) {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Select concrete indices to retry".to_string()));
+    }
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/{}/_ilm/retry", indices.join(",")), json!({})).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Retried ILM on {} indices", indices.len());
+
+    Ok(Json(IlmActionResponse {
+        success: true,
+        message: Some(format!("ILM retry spuštěn ({} indexů)", indices.len())),
+        error: None,
+    }))
+}
+
+/// POST /ilm/move/{index} - Ručně přesune index na jiný ILM krok
+pub async fn move_to_step(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(index): Path<String>,
+    Json(req): Json<IlmMoveRequest>,
+) -> Result<Json<IlmActionResponse>, (StatusCode, Json<IlmActionResponse>)> {
+    let body = build_move_body(&req)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/_ilm/move/{}", index), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Moved {} to ILM phase {}", index, req.next_step.phase);
+
+    Ok(Json(IlmActionResponse {
+        success: true,
+        message: Some(format!("Index {} přesunut do fáze {}", index, req.next_step.phase)),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> Value {
+        json!({
+            "phases": {
+                "hot": { "min_age": "0ms", "actions": {
+                    "rollover": { "max_age": "7d", "max_primary_shard_size": "50gb" },
+                    "set_priority": { "priority": 100 }
+                } },
+                "warm": { "min_age": "30d", "actions": {
+                    "allocate": { "number_of_replicas": 1, "require": { "data": "warm" } },
+                    "forcemerge": { "max_num_segments": 1 }
+                } },
+                "delete": { "min_age": "90d", "actions": { "delete": {} } }
+            },
+            "_meta": { "owner": "ops" }
+        })
+    }
+
+    #[test]
+    fn test_parse_policies() {
+        let policies = parse_policies(&json!({
+            "logs": { "version": 3, "modified_date": "2026-10-01T00:00:00.000Z", "policy": policy(),
+                      "in_use_by": { "indices": ["a", "b"], "data_streams": ["logs-x"] } },
+            ".internal": { "policy": { "phases": {} } }
+        }));
+        assert_eq!(policies.len(), 2);
+        assert!(policies[0].managed);
+        let logs = &policies[1];
+        assert_eq!(logs.phases.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), vec!["hot", "warm", "delete"]);
+        assert_eq!(logs.phases[1].min_age, "30d");
+        assert_eq!(logs.in_use_indices, Some(2));
+    }
+
+    #[test]
+    fn test_policy_editor_round_trip() {
+        let editor = policy_to_editor("logs", &policy());
+        let hot = editor.phase("hot");
+        assert_eq!(hot.rollover_max_age, "7d");
+        assert_eq!(hot.priority, "100");
+        let warm = editor.phase("warm");
+        // allocate s require se do formuláře nevejde, zůstane v extra
+        assert!(warm.replicas.is_empty());
+        assert!(warm.extra_actions.contains("require"));
+        assert_eq!(warm.forcemerge_segments, "1");
+        assert!(editor.phase("delete").delete_index);
+        assert!(editor.phase("delete").delete_searchable_snapshot);
+        assert!(!editor.phase("cold").enabled);
+
+        let body = build_policy_body(&IlmPolicySaveRequest {
+            name: "logs".to_string(),
+            mode: "form".to_string(),
+            phases: editor.phases.clone(),
+            meta: editor.meta_json.clone(),
+            raw: String::new(),
+        }).unwrap();
+        let phases = &body["policy"]["phases"];
+        assert_eq!(phases["hot"]["actions"]["rollover"]["max_primary_shard_size"], "50gb");
+        assert_eq!(phases["warm"]["actions"]["allocate"]["require"]["data"], "warm");
+        assert_eq!(phases["delete"]["actions"]["delete"]["delete_searchable_snapshot"], true);
+        assert_eq!(body["policy"]["_meta"]["owner"], "ops");
+    }
+
+    #[test]
+    fn test_delete_phase_without_delete_action() {
+        let form = IlmPhaseForm {
+            enabled: true,
+            min_age: "30d".to_string(),
+            extra_actions: r#"{"wait_for_snapshot": {"policy": "nightly"}}"#.to_string(),
+            ..Default::default()
+        };
+        let phase = build_phase("delete", &form).unwrap();
+        assert_eq!(phase["actions"]["wait_for_snapshot"]["policy"], "nightly");
+        assert!(phase["actions"].get("delete").is_none());
+
+        let phase = build_phase("delete", &IlmPhaseForm { delete_index: true, ..form }).unwrap();
+        assert_eq!(phase["actions"]["delete"]["delete_searchable_snapshot"], false);
+    }
+
+    #[test]
+    fn test_build_policy_validation() {
+        let request = |phase: &str, form: IlmPhaseForm| IlmPolicySaveRequest {
+            name: "p".to_string(),
+            mode: "form".to_string(),
+            phases: BTreeMap::from([(phase.to_string(), IlmPhaseForm { enabled: true, ..form })]),
+            meta: String::new(),
+            raw: String::new(),
+        };
+
+        // forcemerge v hot fázi bez rollover
+        assert!(build_policy_body(&request("hot", IlmPhaseForm { forcemerge_segments: "1".to_string(), ..Default::default() })).is_err());
+        // shrink není povolen v cold fázi
+        assert!(build_policy_body(&request("cold", IlmPhaseForm { shrink_shards: "1".to_string(), ..Default::default() })).is_err());
+        // frozen vyžaduje searchable snapshot
+        assert!(build_policy_body(&request("frozen", IlmPhaseForm::default())).is_err());
+        assert!(build_policy_body(&request("warm", IlmPhaseForm { min_age: "30 days".to_string(), ..Default::default() })).is_err());
+        assert!(build_policy_body(&request("warm", IlmPhaseForm { replicas: "x".to_string(), ..Default::default() })).is_err());
+        assert!(build_policy_body(&request("warm", IlmPhaseForm { min_age: "30d".to_string(), readonly: true, ..Default::default() })).is_ok());
+
+        let raw = build_policy_body(&IlmPolicySaveRequest {
+            name: "p".to_string(),
+            mode: "json".to_string(),
+            phases: BTreeMap::new(),
+            meta: String::new(),
+            raw: r#"{"phases": {"delete": {"actions": {"delete": {}}}}}"#.to_string(),
+        }).unwrap();
+        assert!(raw["policy"]["phases"]["delete"].is_object());
+    }
+
+    #[test]
+    fn test_parse_explain_and_move() {
+        let explain = parse_explain(&json!({
+            "indices": {
+                "logs-1": {
+                    "index": "logs-1", "managed": true, "policy": "logs", "phase": "hot", "action": "rollover",
+                    "step": "ERROR", "failed_step": "check-rollover-ready",
+                    "step_info": { "type": "illegal_argument_exception", "reason": "rollover alias missing" },
+                    "is_auto_retryable_error": true, "failed_step_retry_count": 4
+                },
+                "plain": { "index": "plain", "managed": false }
+            }
+        }));
+        assert_eq!(explain.len(), 2);
+        assert!(explain[0].is_error());
+        assert_eq!(explain[0].step_info.as_deref(), Some("illegal_argument_exception: rollover alias missing"));
+        assert!(!explain[1].managed);
+
+        let body = build_move_body(&IlmMoveRequest {
+            current_step: IlmStepKey { phase: "hot".to_string(), action: "rollover".to_string(), name: "ERROR".to_string() },
+            next_step: IlmStepKey { phase: "warm".to_string(), action: String::new(), name: String::new() },
+        }).unwrap();
+        assert_eq!(body["next_step"], json!({ "phase": "warm" }));
+
+        assert!(build_move_body(&IlmMoveRequest {
+            current_step: IlmStepKey { phase: "hot".to_string(), action: String::new(), name: String::new() },
+            next_step: IlmStepKey { phase: "warm".to_string(), action: String::new(), name: String::new() },
+        }).is_err());
+    }
+}
@@ -0,0 +1,733 @@
+use axum::{
+    extract::{Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{IndexCreateTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+
+/// Nastavení indexu, která nelze přenést do nového indexu (generuje je ES)
+const INTERNAL_INDEX_SETTINGS: &[&str] = &[
+    "uuid",
+    "creation_date",
+    "creation_date_string",
+    "version",
+    "provided_name",
+    "history_uuid",
+    "resize",
+    "verified_before_close",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct IndexCreateQuery {
+    #[serde(default)]
+    pub from: String, // předvyplnit z existujícího indexu
+}
+
+#[derive(Debug, Serialize)]
+pub struct IndexCreateData {
+    pub indices: Vec<String>,
+    pub from_index: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateIndexRequest {
+    pub name: String,
+    #[serde(default)]
+    pub shards: Option<u32>,
+    #[serde(default)]
+    pub replicas: Option<u32>,
+    #[serde(default)]
+    pub settings: String, // raw JSON
+    #[serde(default)]
+    pub mappings: String, // raw JSON
+    #[serde(default)]
+    pub aliases: String,  // čárkami oddělený seznam
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IndexSourceQuery {
+    #[serde(default)]
+    pub index: String,        // zdrojový index
+    #[serde(default)]
+    pub template_for: String, // název nového indexu - najdi odpovídající template
+}
+
+#[derive(Debug, Serialize)]
+pub struct IndexSourceResponse {
+    pub source: String,
+    pub settings: Value,
+    pub mappings: Value,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidateIndexResponse {
+    pub valid: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    pub matching_templates: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+#[derive(Serialize)]
+pub struct CreateIndexResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+struct MatchedTemplate {
+    name: String,
+    composable: bool,
+    priority: i64, // priority (composable) nebo order (legacy)
+}
+
+/// Zkontroluje název indexu podle pravidel Elasticsearch
+fn validate_index_name(name: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if name.is_empty() {
+        errors.push("Index name is required".to_string());
+        return errors;
+    }
+    if name != name.to_lowercase() {
+        errors.push("Index name must be lowercase".to_string());
+    }
+    if name.starts_with('-') || name.starts_with('_') || name.starts_with('+') {
+        errors.push("Index name cannot start with -, _ or +".to_string());
+    }
+    if name == "." || name == ".." {
+        errors.push("Index name cannot be . or ..".to_string());
+    }
+    if name.chars().any(|c| matches!(c, '\\' | '/' | '*' | '?' | '"' | '<' | '>' | '|' | ' ' | ',' | '#' | ':')) {
+        errors.push("Index name cannot contain \\ / * ? \" < > | space , # :".to_string());
+    }
+    if name.len() > 255 {
+        errors.push("Index name cannot be longer than 255 bytes".to_string());
+    }
+    errors
+}
+
+/// Parsuje JSON objekt z textového pole (prázdné pole = prázdný objekt)
+fn parse_json_object(label: &str, raw: &str) -> Result<serde_json::Map<String, Value>, String> {
+    if raw.trim().is_empty() {
+        return Ok(serde_json::Map::new());
+    }
+    match serde_json::from_str::<Value>(raw) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(format!("{} must be a JSON object", label)),
+        Err(e) => Err(format!("{} is not valid JSON: {}", label, e)),
+    }
+}
+
+/// Nastaví index setting - respektuje flat (`index.x`) i vnořený (`index: { x }`) zápis
+fn set_index_setting(settings: &mut serde_json::Map<String, Value>, key: &str, value: Value) {
+    let flat_key = format!("index.{}", key);
+    if settings.contains_key(&flat_key) {
+        settings.insert(flat_key, value);
+        return;
+    }
+    if settings.contains_key(key) {
+        settings.insert(key.to_string(), value);
+        return;
+    }
+
+    let index = settings.entry("index".to_string())
+        .or_insert_with(|| json!({}));
+    if let Some(obj) = index.as_object_mut() {
+        obj.insert(key.to_string(), value);
+    }
+}
+
+/// Sestaví body pro `PUT /{index}`
+fn build_create_index_body(req: &CreateIndexRequest) -> Result<Value, Vec<String>> {
+    let mut errors = Vec::new();
+
+    let mut settings = parse_json_object("Settings", &req.settings).unwrap_or_else(|e| {
+        errors.push(e);
+        serde_json::Map::new()
+    });
+    let mappings = parse_json_object("Mappings", &req.mappings).unwrap_or_else(|e| {
+        errors.push(e);
+        serde_json::Map::new()
+    });
+
+    if let Some(shards) = req.shards {
+        if shards == 0 {
+            errors.push("Number of shards must be at least 1".to_string());
+        }
+        set_index_setting(&mut settings, "number_of_shards", json!(shards));
+    }
+    if let Some(replicas) = req.replicas {
+        set_index_setting(&mut settings, "number_of_replicas", json!(replicas));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut body = serde_json::Map::new();
+    if !settings.is_empty() {
+        body.insert("settings".to_string(), Value::Object(settings));
+    }
+    if !mappings.is_empty() {
+        body.insert("mappings".to_string(), Value::Object(mappings));
+    }
+
+    let aliases: serde_json::Map<String, Value> = req.aliases
+        .split(',')
+        .map(|a| a.trim())
+        .filter(|a| !a.is_empty())
+        .map(|a| (a.to_string(), json!({})))
+        .collect();
+    if !aliases.is_empty() {
+        body.insert("aliases".to_string(), Value::Object(aliases));
+    }
+
+    Ok(Value::Object(body))
+}
+
+/// Odstraní settings generované ES (uuid, creation_date, ...) aby šly použít pro nový index
+fn strip_internal_settings(settings: &mut Value) {
+    if let Some(index) = settings.get_mut("index").and_then(|v| v.as_object_mut()) {
+        for key in INTERNAL_INDEX_SETTINGS {
+            index.remove(*key);
+        }
+        if let Some(allocation) = index.get_mut("routing")
+            .and_then(|v| v.get_mut("allocation"))
+            .and_then(|v| v.as_object_mut()) {
+                allocation.remove("initial_recovery");
+            }
+    }
+}
+
+/// Rekurzivně sloučí `overlay` do `base` (hodnoty z overlay mají přednost)
+fn deep_merge(base: &mut Value, overlay: &Value) {
+    match (base, overlay) {
+        (Value::Object(base_map), Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                deep_merge(base_map.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (base, overlay) => {
+            *base = overlay.clone();
+        }
+    }
+}
+
+/// Přidá settings, mappings a aliasy jedné šablony (pozdější přepisují dřívější)
+fn merge_template_parts(settings: &mut Value, mappings: &mut Value, aliases: &mut Vec<String>, body: &Value) {
+    if body["settings"].is_object() {
+        deep_merge(settings, &body["settings"]);
+    }
+    if body["mappings"].is_object() {
+        deep_merge(mappings, &body["mappings"]);
+    }
+    if let Some(map) = body["aliases"].as_object() {
+        for alias in map.keys() {
+            if !aliases.contains(alias) {
+                aliases.push(alias.clone());
+            }
+        }
+    }
+}
+
+/// Najde index templates, jejichž pattern odpovídá názvu indexu
+async fn find_matching_templates(client: &EsClient, name: &str) -> Vec<MatchedTemplate> {
+    let mut matched = Vec::new();
+    let supports_composable = client.version().map(|v| v.at_least(7, 8)).unwrap_or(true);
+
+    if supports_composable
+        && let Ok(response) = client.get::<Value>("/_index_template").await
+        && let Some(templates) = response["index_templates"].as_array()
+    {
+        for template in templates {
+            let patterns = template["index_template"]["index_patterns"].as_array();
+            let is_match = patterns
+                .map(|p| p.iter().filter_map(|v| v.as_str()).any(|p| matches_pattern(name, p)))
+                .unwrap_or(false);
+            if is_match {
+                matched.push(MatchedTemplate {
+                    name: template["name"].as_str().unwrap_or("").to_string(),
+                    composable: true,
+                    priority: template["index_template"]["priority"].as_i64().unwrap_or(0),
+                });
+            }
+        }
+    }
+
+    if let Ok(response) = client.get::<Value>("/_template").await
+        && let Some(templates) = response.as_object()
+    {
+        for (template_name, template) in templates {
+            // ES 5.x používá "template", novější "index_patterns"
+            let patterns: Vec<&str> = template["index_patterns"].as_array()
+                .map(|p| p.iter().filter_map(|v| v.as_str()).collect())
+                .or_else(|| template["template"].as_str().map(|p| vec![p]))
+                .unwrap_or_default();
+            if patterns.iter().any(|p| matches_pattern(name, p)) {
+                matched.push(MatchedTemplate {
+                    name: template_name.clone(),
+                    composable: false,
+                    priority: template["order"].as_i64().unwrap_or(0),
+                });
+            }
+        }
+    }
+
+    // Composable templates mají přednost, v rámci typu rozhoduje priorita
+    matched.sort_by(|a, b| b.composable.cmp(&a.composable).then(b.priority.cmp(&a.priority)));
+    matched
+}
+
+/// GET /indices/create - Zobrazí formulář pro vytvoření indexu
+pub async fn create_index_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<IndexCreateQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_index_create_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load index create data: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading index create data");
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = IndexCreateTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_index_create_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &IndexCreateQuery,
+) -> anyhow::Result<IndexCreateData> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    // Seznam indexů pro "start from existing index"
+    let rows: Vec<Value> = client.get("/_cat/indices?format=json&h=index").await?;
+    let mut indices: Vec<String> = rows.iter()
+        .filter_map(|r| r["index"].as_str().map(|s| s.to_string()))
+        .filter(|i| !i.starts_with('.'))
+        .collect();
+    indices.sort();
+
+    Ok(IndexCreateData {
+        indices,
+        from_index: query.from.clone(),
+    })
+}
+
+/// GET /indices/create/source - Vrátí settings/mappings/aliasy z indexu nebo odpovídající template
+pub async fn index_create_source(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<IndexSourceQuery>,
+) -> Result<Json<IndexSourceResponse>, (StatusCode, String)> {
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let client = create_es_client(&state, &endpoint, true).await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let result = if !query.index.is_empty() {
+        load_source_from_index(&client, &query.index).await
+    } else if !query.template_for.is_empty() {
+        load_source_from_template(&client, &query.template_for).await
+    } else {
+        Err(anyhow::anyhow!("Missing index or template_for parameter"))
+    };
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+async fn load_source_from_index(client: &EsClient, index_name: &str) -> anyhow::Result<IndexSourceResponse> {
+    let settings_response: Value = client.get(&format!("/{}/_settings", index_name)).await?;
+    let mappings_response: Value = client.get(&format!("/{}/_mapping", index_name)).await?;
+    let aliases_response: Value = client.get(&format!("/{}/_alias", index_name)).await
+        .unwrap_or(json!({}));
+
+    let mut settings = settings_response[index_name]["settings"].clone();
+    strip_internal_settings(&mut settings);
+
+    let aliases = aliases_response[index_name]["aliases"].as_object()
+        .map(|m| m.keys().cloned().collect())
+        .unwrap_or_default();
+
+    Ok(IndexSourceResponse {
+        source: format!("index {}", index_name),
+        settings,
+        mappings: mappings_response[index_name]["mappings"].clone(),
+        aliases,
+    })
+}
+
+async fn load_source_from_template(client: &EsClient, name: &str) -> anyhow::Result<IndexSourceResponse> {
+    let matched = find_matching_templates(client, name).await;
+    if matched.is_empty() {
+        return Err(anyhow::anyhow!("No index template matches '{}'", name));
+    }
+
+    let supports_simulate = client.version().map(|v| v.at_least(7, 9)).unwrap_or(false);
+
+    if matched[0].composable {
+        if !supports_simulate {
+            // 7.8 nemá _simulate_index - šablonu složíme z component templates ručně
+            return compose_index_template(client, &matched[0].name).await;
+        }
+        // Výsledná konfigurace včetně component templates
+        let response: Value = client.post(&format!("/_index_template/_simulate_index/{}", name), json!({})).await?;
+        let template = &response["template"];
+        return Ok(IndexSourceResponse {
+            source: format!("index template {}", matched[0].name),
+            settings: template["settings"].clone(),
+            mappings: template["mappings"].clone(),
+            aliases: template["aliases"].as_object()
+                .map(|m| m.keys().cloned().collect())
+                .unwrap_or_default(),
+        });
+    }
+
+    // Legacy templates - ES je slučuje podle order (vyšší order přepisuje nižší)
+    let response: Value = client.get("/_template").await?;
+    let mut legacy: Vec<&MatchedTemplate> = matched.iter().filter(|t| !t.composable).collect();
+    legacy.sort_by_key(|t| t.priority);
+
+    let mut settings = json!({});
+    let mut mappings = json!({});
+    let mut aliases = Vec::new();
+    for template in &legacy {
+        merge_template_parts(&mut settings, &mut mappings, &mut aliases, &response[&template.name]);
+    }
+
+    Ok(IndexSourceResponse {
+        source: format!(
+            "legacy template {}",
+            legacy.iter().map(|t| t.name.as_str()).collect::<Vec<_>>().join(", ")
+        ),
+        settings,
+        mappings,
+        aliases,
+    })
+}
+
+/// Složí composable template jako ES: component templates v pořadí `composed_of`, pak vlastní `template`
+async fn compose_index_template(client: &EsClient, template_name: &str) -> anyhow::Result<IndexSourceResponse> {
+    let response: Value = client.get(&format!("/_index_template/{}", template_name)).await?;
+    let template = &response["index_templates"][0]["index_template"];
+    if template.is_null() {
+        return Err(anyhow::anyhow!("Index template '{}' not found", template_name));
+    }
+
+    let mut settings = json!({});
+    let mut mappings = json!({});
+    let mut aliases = Vec::new();
+    let components: Vec<&str> = template["composed_of"].as_array()
+        .map(|c| c.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    for component in &components {
+        let response: Value = client.get(&format!("/_component_template/{}", component)).await?;
+        let body = &response["component_templates"][0]["component_template"]["template"];
+        merge_template_parts(&mut settings, &mut mappings, &mut aliases, body);
+    }
+    merge_template_parts(&mut settings, &mut mappings, &mut aliases, &template["template"]);
+
+    let source = if components.is_empty() {
+        format!("index template {}", template_name)
+    } else {
+        format!("index template {} (composed of {})", template_name, components.join(", "))
+    };
+    Ok(IndexSourceResponse {
+        source,
+        settings,
+        mappings,
+        aliases,
+    })
+}
+
+/// POST /indices/create/validate - Zvaliduje vstup proti clusteru
+pub async fn validate_create_index(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<CreateIndexRequest>,
+) -> Result<Json<ValidateIndexResponse>, (StatusCode, String)> {
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let client = create_es_client(&state, &endpoint, true).await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let name = req.name.trim().to_string();
+    let mut errors = validate_index_name(&name);
+    let mut warnings = Vec::new();
+
+    let body = match build_create_index_body(&req) {
+        Ok(body) => Some(body),
+        Err(body_errors) => {
+            errors.extend(body_errors);
+            None
+        }
+    };
+
+    let mut matching_templates = Vec::new();
+
+    if errors.is_empty() {
+        // 1. Existuje už index / alias se stejným názvem?
+        if let Ok((status, _)) = client.get_raw(&format!("/{}", name)).await
+            && status == 200
+        {
+            errors.push(format!("Index or alias '{}' already exists", name));
+        }
+
+        // 2. Odpovídající templates
+        let matched = find_matching_templates(&client, &name).await;
+        for (i, template) in matched.iter().enumerate() {
+            let kind = if template.composable { "composable, priority" } else { "legacy, order" };
+            let applied = if template.composable && i > 0 { " (overridden)" } else { "" };
+            matching_templates.push(format!("{} ({} {}){}", template.name, kind, template.priority, applied));
+        }
+
+        // 3. Repliky vs. počet data nodů
+        if let Some(replicas) = req.replicas
+            && let Ok(health) = client.get::<Value>("/_cluster/health").await
+        {
+            let data_nodes = health["number_of_data_nodes"].as_u64().unwrap_or(0);
+            if u64::from(replicas) >= data_nodes {
+                warnings.push(format!(
+                    "{} replicas cannot be allocated on {} data node(s) - index will be yellow",
+                    replicas, data_nodes
+                ));
+            }
+        }
+
+        // 4. Validace settings/mappings clusterem (simulace template, nic se neuloží)
+        let supports_simulate = client.version().map(|v| v.at_least(7, 9)).unwrap_or(false);
+        if supports_simulate && let Some(ref body) = body {
+            let max_priority = matched.iter()
+                .filter(|t| t.composable)
+                .map(|t| t.priority)
+                .max()
+                .unwrap_or(0);
+            let mut template = body.clone();
+            if let Some(obj) = template.as_object_mut() {
+                obj.remove("aliases");
+            }
+            let simulate_body = json!({
+                "index_patterns": [name],
+                "priority": max_priority + 1,
+                "template": template,
+            });
+            match client.post_raw("/_index_template/_simulate", simulate_body).await {
+                Ok((status, response)) if status >= 400 => {
+                    errors.push(extract_error_reason(&response));
+                }
+                Ok(_) => {}
+                Err(e) => warnings.push(format!("Cluster validation skipped: {}", e)),
+            }
+        } else if !supports_simulate {
+            warnings.push("Settings and mappings are validated only on create (requires ES 7.9+ for dry run)".to_string());
+        }
+    }
+
+    Ok(Json(ValidateIndexResponse {
+        valid: errors.is_empty(),
+        errors,
+        warnings,
+        matching_templates,
+        body,
+    }))
+}
+
+/// POST /indices/create - Vytvoří index
+pub async fn create_index(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<CreateIndexRequest>,
+) -> Result<Json<CreateIndexResponse>, (StatusCode, Json<CreateIndexResponse>)> {
+    let error_response = |status: StatusCode, error: String| {
+        (status, Json(CreateIndexResponse { success: false, message: None, error: Some(error) }))
+    };
+
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or_else(|| error_response(StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    let name = req.name.trim().to_string();
+    let name_errors = validate_index_name(&name);
+    if !name_errors.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, name_errors.join(", ")));
+    }
+
+    let body = build_create_index_body(&req)
+        .map_err(|errors| error_response(StatusCode::BAD_REQUEST, errors.join(", ")))?;
+
+    let client = create_es_client(&state, &endpoint, false).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+
+    let (status, response) = client.put_raw(&format!("/{}", name), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    let shards_acknowledged = serde_json::from_str::<Value>(&response)
+        .ok()
+        .and_then(|v| v["shards_acknowledged"].as_bool())
+        .unwrap_or(false);
+
+    tracing::info!("Created index {}", name);
+
+    let message = if shards_acknowledged {
+        format!("Index {} vytvořen", name)
+    } else {
+        format!("Index {} vytvořen, shardy zatím nejsou aktivní", name)
+    };
+
+    Ok(Json(CreateIndexResponse {
+        success: true,
+        message: Some(message),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(settings: &str, mappings: &str) -> CreateIndexRequest {
+        CreateIndexRequest {
+            name: "logs-2026".to_string(),
+            shards: Some(3),
+            replicas: Some(1),
+            settings: settings.to_string(),
+            mappings: mappings.to_string(),
+            aliases: "logs, current ".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_index_name() {
+        assert!(validate_index_name("logs-2026.01").is_empty());
+        assert!(!validate_index_name("Logs").is_empty());
+        assert!(!validate_index_name("_logs").is_empty());
+        assert!(!validate_index_name("logs*").is_empty());
+        assert!(!validate_index_name("").is_empty());
+    }
+
+    #[test]
+    fn test_build_create_index_body() {
+        let body = build_create_index_body(&request(
+            r#"{"index": {"refresh_interval": "5s"}}"#,
+            r#"{"properties": {"msg": {"type": "text"}}}"#,
+        )).unwrap();
+
+        assert_eq!(body["settings"]["index"]["number_of_shards"], 3);
+        assert_eq!(body["settings"]["index"]["number_of_replicas"], 1);
+        assert_eq!(body["settings"]["index"]["refresh_interval"], "5s");
+        assert_eq!(body["mappings"]["properties"]["msg"]["type"], "text");
+        assert!(body["aliases"]["logs"].is_object());
+        assert!(body["aliases"]["current"].is_object());
+    }
+
+    #[test]
+    fn test_build_create_index_body_flat_settings() {
+        let body = build_create_index_body(&request(r#"{"index.number_of_shards": 5}"#, "")).unwrap();
+        assert_eq!(body["settings"]["index.number_of_shards"], 3);
+        assert!(body.get("mappings").is_none());
+    }
+
+    #[test]
+    fn test_build_create_index_body_invalid_json() {
+        let errors = build_create_index_body(&request("{", "[]")).unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_strip_internal_settings() {
+        let mut settings = json!({
+            "index": {
+                "uuid": "abc",
+                "creation_date": "1",
+                "provided_name": "x",
+                "version": { "created": "8110099" },
+                "number_of_shards": "1",
+                "routing": { "allocation": { "initial_recovery": { "_id": "n1" }, "include": { "_tier_preference": "data_hot" } } }
+            }
+        });
+        strip_internal_settings(&mut settings);
+        let index = settings["index"].as_object().unwrap();
+        assert!(!index.contains_key("uuid"));
+        assert!(!index.contains_key("version"));
+        assert_eq!(index["number_of_shards"], "1");
+        assert!(settings["index"]["routing"]["allocation"].get("initial_recovery").is_none());
+        assert!(settings["index"]["routing"]["allocation"].get("include").is_some());
+    }
+
+    #[test]
+    fn test_deep_merge() {
+        let mut base = json!({"index": {"number_of_shards": 1, "codec": "default"}});
+        deep_merge(&mut base, &json!({"index": {"number_of_shards": 3}}));
+        assert_eq!(base["index"]["number_of_shards"], 3);
+        assert_eq!(base["index"]["codec"], "default");
+    }
+
+    #[test]
+    fn test_merge_template_parts() {
+        let mut settings = json!({});
+        let mut mappings = json!({});
+        let mut aliases = Vec::new();
+        let component = json!({
+            "settings": {"index": {"number_of_shards": 1, "codec": "best_compression"}},
+            "mappings": {"properties": {"host": {"type": "keyword"}}},
+            "aliases": {"logs": {}}
+        });
+        let template = json!({
+            "settings": {"index": {"number_of_shards": 3}},
+            "mappings": {"properties": {"message": {"type": "text"}}},
+            "aliases": {"logs": {}, "recent": {}}
+        });
+        merge_template_parts(&mut settings, &mut mappings, &mut aliases, &component);
+        merge_template_parts(&mut settings, &mut mappings, &mut aliases, &template);
+
+        assert_eq!(settings["index"]["number_of_shards"], 3);
+        assert_eq!(settings["index"]["codec"], "best_compression");
+        assert_eq!(mappings["properties"]["host"]["type"], "keyword");
+        assert_eq!(mappings["properties"]["message"]["type"], "text");
+        assert_eq!(aliases, vec!["logs", "recent"]);
+    }
+}
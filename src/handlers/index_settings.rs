@@ -0,0 +1,473 @@
+use axum::{
+    extract::State,
+    response::Json,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, active_es_client};
+use crate::es::{EsClient, extract_error_reason};
+
+/// Dynamická nastavení indexu - lze měnit na otevřeném indexu (prefixy bez `index.`)
+const DYNAMIC_SETTING_PREFIXES: &[&str] = &[
+    "number_of_replicas",
+    "auto_expand_replicas",
+    "refresh_interval",
+    "search.idle.after",
+    "max_result_window",
+    "max_inner_result_window",
+    "max_rescore_window",
+    "max_docvalue_fields_search",
+    "max_script_fields",
+    "max_ngram_diff",
+    "max_shingle_diff",
+    "max_refresh_listeners",
+    "max_terms_count",
+    "max_regex_length",
+    "max_slices_per_scroll",
+    "analyze.max_token_count",
+    "highlight.max_analyzed_offset",
+    "query.default_field",
+    "routing.allocation.",
+    "routing.rebalance.enable",
+    "unassigned.node_left.delayed_timeout",
+    "gc_deletes",
+    "default_pipeline",
+    "final_pipeline",
+    "hidden",
+    "blocks.",
+    "priority",
+    "mapping.total_fields.limit",
+    "mapping.depth.limit",
+    "mapping.nested_fields.limit",
+    "mapping.nested_objects.limit",
+    "mapping.field_name_length.limit",
+    "merge.",
+    "translog.",
+    "lifecycle.",
+    "indexing.slowlog.",
+    "search.slowlog.",
+    "requests.cache.enable",
+    "write.wait_for_active_shards",
+    "soft_deletes.retention_lease.period",
+    "soft_deletes.retention.operations",
+];
+
+/// Nastavení, která nelze změnit vůbec (ani na zavřeném indexu)
+const FINAL_SETTING_PREFIXES: &[&str] = &[
+    "number_of_shards",
+    "number_of_routing_shards",
+    "routing_partition_size",
+    "soft_deletes.enabled",
+    "sort.",
+    "uuid",
+    "creation_date",
+    "version.",
+    "provided_name",
+    "history_uuid",
+    "resize.",
+    "format",
+];
+
+/// Interní nastavení, která se v editoru nezobrazují
+const HIDDEN_SETTING_PREFIXES: &[&str] = &[
+    "uuid",
+    "creation_date",
+    "version.",
+    "provided_name",
+    "history_uuid",
+    "resize.",
+    "verified_before_close",
+    "routing.allocation.initial_recovery.",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingKind {
+    Dynamic,
+    Static,
+    Final,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SettingChange {
+    pub key: String,
+    pub old: Option<String>,
+    pub new: Option<String>, // None = reset na default
+    pub kind: SettingKind,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SettingsUpdateRequest {
+    pub settings: String, // raw JSON
+    #[serde(default)]
+    pub reopen: bool,     // povolit close -> update -> open
+}
+
+#[derive(Debug, Serialize)]
+pub struct SettingsPreviewResponse {
+    pub changes: Vec<SettingChange>,
+    pub index_closed: bool,
+    pub requires_close: bool,
+    pub errors: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct SettingsUpdateResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub requires_close: bool,
+}
+
+/// Převede klíč na flat tvar s prefixem `index.`
+fn normalize_setting_key(key: &str) -> String {
+    if key.starts_with("index.") {
+        key.to_string()
+    } else {
+        format!("index.{}", key)
+    }
+}
+
+/// Rozbalí vnořené settings do flat mapy (`index.routing.allocation.require._name`)
+fn flatten_settings(value: &Value, prefix: &str, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_settings(value, &path, out);
+            }
+        }
+        _ => {
+            if !prefix.is_empty() {
+                out.insert(normalize_setting_key(prefix), value.clone());
+            }
+        }
+    }
+}
+
+fn matches_prefix(key: &str, prefixes: &[&str]) -> bool {
+    let key = key.strip_prefix("index.").unwrap_or(key);
+    prefixes.iter().any(|p| {
+        if p.ends_with('.') {
+            key.starts_with(p)
+        } else {
+            key == *p
+        }
+    })
+}
+
+/// Určí, zda lze setting měnit za běhu
+fn classify_setting(key: &str) -> SettingKind {
+    if matches_prefix(key, FINAL_SETTING_PREFIXES) {
+        SettingKind::Final
+    } else if matches_prefix(key, DYNAMIC_SETTING_PREFIXES) {
+        SettingKind::Dynamic
+    } else {
+        SettingKind::Static
+    }
+}
+
+/// Textová podoba hodnoty pro porovnání (ES vrací flat settings jako stringy)
+fn setting_value_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Array(items) => Some(
+            items.iter()
+                .map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string()))
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Spočítá změny mezi aktuálními a požadovanými settings (klíče, které v požadavku chybí, se nemění)
+fn diff_settings(current: &BTreeMap<String, Value>, desired: &BTreeMap<String, Value>) -> Vec<SettingChange> {
+    desired.iter()
+        .filter_map(|(key, value)| {
+            let old = current.get(key).and_then(setting_value_string);
+            let new = setting_value_string(value);
+            if old == new {
+                return None;
+            }
+            Some(SettingChange {
+                key: key.clone(),
+                old,
+                new,
+                kind: classify_setting(key),
+            })
+        })
+        .collect()
+}
+
+/// Flat settings pro editor (bez interních hodnot), pretty JSON
+pub fn editable_settings_json(settings: &Value) -> Option<String> {
+    let mut flat = BTreeMap::new();
+    flatten_settings(settings, "", &mut flat);
+    flat.retain(|key, _| !matches_prefix(key, HIDDEN_SETTING_PREFIXES));
+    serde_json::to_string_pretty(&flat).ok()
+}
+
+struct SettingsPlan {
+    changes: Vec<SettingChange>,
+    body: Value,
+    index_closed: bool,
+}
+
+/// Načte aktuální stav indexu a připraví změny
+async fn plan_settings_update(client: &EsClient, index_name: &str, raw: &str) -> anyhow::Result<SettingsPlan> {
+    let desired_value: Value = serde_json::from_str(raw)
+        .map_err(|e| anyhow::anyhow!("Settings are not valid JSON: {}", e))?;
+    if !desired_value.is_object() {
+        return Err(anyhow::anyhow!("Settings must be a JSON object"));
+    }
+    // Povolíme i obalení do {"settings": {...}} nebo {"index": {...}}
+    let desired_value = match desired_value.get("settings") {
+        Some(inner) if desired_value.as_object().map(|m| m.len() == 1).unwrap_or(false) => inner.clone(),
+        _ => desired_value,
+    };
+
+    let mut desired = BTreeMap::new();
+    flatten_settings(&desired_value, "", &mut desired);
+
+    let response: Value = client.get(&format!("/{}/_settings", index_name)).await?;
+    let settings = response.get(index_name)
+        .or_else(|| response.as_object().and_then(|m| m.values().next()))
+        .map(|v| v["settings"].clone())
+        .unwrap_or(Value::Null);
+
+    let mut current = BTreeMap::new();
+    flatten_settings(&settings, "", &mut current);
+
+    let index_closed = index_status(client, index_name).await? == "close";
+
+    let changes = diff_settings(&current, &desired);
+    let body = Value::Object(
+        changes.iter()
+            .map(|c| (c.key.clone(), desired.get(&c.key).cloned().unwrap_or(Value::Null)))
+            .collect(),
+    );
+
+    Ok(SettingsPlan { changes, body, index_closed })
+}
+
+async fn index_status(client: &EsClient, index_name: &str) -> anyhow::Result<String> {
+    let rows: Vec<Value> = client.get(&format!("/_cat/indices/{}?format=json&h=status", index_name)).await?;
+    Ok(rows.first()
+        .and_then(|r| r["status"].as_str())
+        .unwrap_or("open")
+        .to_string())
+}
+
+fn plan_errors(plan: &SettingsPlan) -> Vec<String> {
+    let mut errors = Vec::new();
+    let final_keys: Vec<&str> = plan.changes.iter()
+        .filter(|c| c.kind == SettingKind::Final)
+        .map(|c| c.key.as_str())
+        .collect();
+    if !final_keys.is_empty() {
+        errors.push(format!(
+            "{} cannot be changed on an existing index - use reindex, shrink or split instead",
+            final_keys.join(", ")
+        ));
+    }
+    errors
+}
+
+fn static_keys(plan: &SettingsPlan) -> Vec<&str> {
+    plan.changes.iter()
+        .filter(|c| c.kind == SettingKind::Static)
+        .map(|c| c.key.as_str())
+        .collect()
+}
+
+/// POST /indices/settings/{index_name}/preview - Diff požadovaných settings proti aktuálním
+pub async fn preview_index_settings(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    axum::extract::Path(index_name): axum::extract::Path<String>,
+    Json(req): Json<SettingsUpdateRequest>,
+) -> Result<Json<SettingsPreviewResponse>, (StatusCode, String)> {
+    let (client, _) = active_es_client(&state, &jar, false).await?;
+
+    let plan = plan_settings_update(&client, &index_name, &req.settings).await
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    let errors = plan_errors(&plan);
+    let requires_close = !plan.index_closed && !static_keys(&plan).is_empty();
+
+    Ok(Json(SettingsPreviewResponse {
+        index_closed: plan.index_closed,
+        requires_close,
+        errors,
+        changes: plan.changes,
+    }))
+}
+
+/// POST /indices/settings/{index_name} - Aplikuje změny přes `PUT /{index}/_settings`
+pub async fn update_index_settings(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    axum::extract::Path(index_name): axum::extract::Path<String>,
+    Json(req): Json<SettingsUpdateRequest>,
+) -> Result<Json<SettingsUpdateResponse>, (StatusCode, Json<SettingsUpdateResponse>)> {
+    let error_response = |status: StatusCode, error: String, requires_close: bool| {
+        (status, Json(SettingsUpdateResponse { success: false, message: None, error: Some(error), requires_close }))
+    };
+
+    let (client, _) = active_es_client(&state, &jar, false).await
+        .map_err(|(status, e)| error_response(status, e, false))?;
+
+    let plan = plan_settings_update(&client, &index_name, &req.settings).await
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e.to_string(), false))?;
+
+    if plan.changes.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, "No changes".to_string(), false));
+    }
+
+    let errors = plan_errors(&plan);
+    if !errors.is_empty() {
+        return Err(error_response(StatusCode::BAD_REQUEST, errors.join(", "), false));
+    }
+
+    let static_keys = static_keys(&plan);
+    let needs_reopen = !plan.index_closed && !static_keys.is_empty();
+    if needs_reopen && !req.reopen {
+        return Err(error_response(
+            StatusCode::CONFLICT,
+            format!(
+                "{} are static settings and can only be changed on a closed index",
+                static_keys.join(", ")
+            ),
+            true,
+        ));
+    }
+
+    let path = format!("/{}/_settings", index_name);
+
+    if needs_reopen {
+        tracing::info!("Closing index {} to update static settings", index_name);
+        let (status, body) = client.post_raw(&format!("/{}/_close", index_name), json!({})).await
+            .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string(), false))?;
+        if status >= 400 {
+            return Err(error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to close index: {}", extract_error_reason(&body)),
+                false,
+            ));
+        }
+    }
+
+    let update_result = client.put_raw(&path, plan.body.clone()).await;
+
+    // Index otevři i v případě, že update selhal - nesmí zůstat zavřený
+    let reopen_error = if needs_reopen {
+        match client.post_raw(&format!("/{}/_open", index_name), json!({})).await {
+            Ok((status, _)) if status < 400 => None,
+            Ok((_, body)) => Some(extract_error_reason(&body)),
+            Err(e) => Some(e.to_string()),
+        }
+    } else {
+        None
+    };
+
+    let update_error = match update_result {
+        Ok((status, body)) if status >= 400 => Some(extract_error_reason(&body)),
+        Ok(_) => None,
+        Err(e) => Some(e.to_string()),
+    };
+
+    match (update_error, reopen_error) {
+        (None, None) => {
+            tracing::info!("Updated {} settings on index {}", plan.changes.len(), index_name);
+            let message = if needs_reopen {
+                "Settings uloženy, index znovu otevřen".to_string()
+            } else {
+                "Settings uloženy".to_string()
+            };
+            Ok(Json(SettingsUpdateResponse {
+                success: true,
+                message: Some(message),
+                error: None,
+                requires_close: false,
+            }))
+        }
+        (update_error, reopen_error) => {
+            let mut parts = Vec::new();
+            if let Some(e) = update_error {
+                parts.push(format!("Update failed: {}", e));
+            }
+            if let Some(e) = reopen_error {
+                parts.push(format!("Index is still closed, reopen failed: {}", e));
+            }
+            Err(error_response(StatusCode::INTERNAL_SERVER_ERROR, parts.join(". "), false))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_settings() {
+        let mut flat = BTreeMap::new();
+        flatten_settings(&json!({
+            "index": { "number_of_replicas": "1", "routing": { "allocation": { "require": { "_name": "n1" } } } },
+            "refresh_interval": "5s"
+        }), "", &mut flat);
+
+        assert_eq!(flat["index.number_of_replicas"], "1");
+        assert_eq!(flat["index.routing.allocation.require._name"], "n1");
+        assert_eq!(flat["index.refresh_interval"], "5s");
+    }
+
+    #[test]
+    fn test_classify_setting() {
+        assert_eq!(classify_setting("index.number_of_replicas"), SettingKind::Dynamic);
+        assert_eq!(classify_setting("index.routing.allocation.exclude._name"), SettingKind::Dynamic);
+        assert_eq!(classify_setting("index.blocks.write"), SettingKind::Dynamic);
+        assert_eq!(classify_setting("index.codec"), SettingKind::Static);
+        assert_eq!(classify_setting("index.analysis.analyzer.my.type"), SettingKind::Static);
+        assert_eq!(classify_setting("index.number_of_shards"), SettingKind::Final);
+    }
+
+    #[test]
+    fn test_diff_settings() {
+        let mut current = BTreeMap::new();
+        current.insert("index.number_of_replicas".to_string(), json!("1"));
+        current.insert("index.refresh_interval".to_string(), json!("1s"));
+
+        let mut desired = BTreeMap::new();
+        desired.insert("index.number_of_replicas".to_string(), json!(1));
+        desired.insert("index.refresh_interval".to_string(), json!("30s"));
+        desired.insert("index.blocks.write".to_string(), json!(null));
+        desired.insert("index.codec".to_string(), json!("best_compression"));
+
+        let changes = diff_settings(&current, &desired);
+        let keys: Vec<&str> = changes.iter().map(|c| c.key.as_str()).collect();
+        // replicas se nemění (1 == "1"), blocks.write neexistuje a reset na null nic nemění
+        assert_eq!(keys, vec!["index.codec", "index.refresh_interval"]);
+        assert_eq!(changes[0].kind, SettingKind::Static);
+        assert_eq!(changes[1].old.as_deref(), Some("1s"));
+    }
+
+    #[test]
+    fn test_editable_settings_hides_internal() {
+        let json = editable_settings_json(&json!({
+            "index": { "uuid": "x", "creation_date": "1", "version": { "created": "8" }, "number_of_replicas": "1" }
+        })).unwrap();
+        assert!(json.contains("index.number_of_replicas"));
+        assert!(!json.contains("uuid"));
+        assert!(!json.contains("version.created"));
+    }
+}
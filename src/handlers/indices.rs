@@ -10,9 +10,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::handlers::endpoints::{AppState, get_active_endpoint};
 use crate::templates::{IndicesTemplate, IndicesTableTemplate, IndexDetailTemplate, PageContext};
-use crate::es::EsClient;
-use crate::models::{IndexInfo, IndicesListData, AliasInfo, IndexDetail};
-use crate::utils::{format_bytes, format_number, parse_size_to_bytes};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{IndexInfo, IndicesListData, AliasInfo, IndexDetail, backing_index_map};
+use crate::utils::{encode_component, format_bytes, format_number, parse_size_to_bytes};
 use std::collections::HashMap;
 
 #[derive(Debug, Deserialize)]
@@ -283,9 +283,22 @@ async fn load_indices_data(
         }
     }
 
-    // Filtruj interní indexy (začínají tečkou)
+    // Backing indexy data streamů (.ds-*) se seskupují pod svůj stream
+    if client.version().map(|v| v.at_least(7, 9)).unwrap_or(false)
+        && let Ok(streams) = client.get::<serde_json::Value>("/_data_stream/*?expand_wildcards=all").await
+    {
+        let backing = backing_index_map(&streams);
+        for idx in &mut indices {
+            idx.data_stream = backing.get(&idx.index).cloned();
+        }
+    }
+
+    // Filtruj interní indexy (začínají tečkou), backing indexy uživatelských streamů ponech
     if query.hide_internal {
-        indices.retain(|idx| !idx.index.starts_with('.'));
+        indices.retain(|idx| match &idx.data_stream {
+            Some(stream) => !stream.starts_with('.'),
+            None => !idx.index.starts_with('.'),
+        });
     }
 
     // Sortování
@@ -329,15 +342,18 @@ async fn load_indices_data(
         _ => {}
     }
 
+    let stream_counts = group_data_stream_indices(&mut indices);
+
     let total = indices.len();
     let total_pages = total.div_ceil(query.per_page);
 
     // Pagination
     let start = (query.page - 1) * query.per_page;
-    let paginated_indices = indices.into_iter()
+    let mut paginated_indices: Vec<IndexInfo> = indices.into_iter()
         .skip(start)
         .take(query.per_page)
         .collect();
+    mark_stream_headers(&mut paginated_indices, &stream_counts);
 
     Ok(IndicesListData {
         indices: paginated_indices,
@@ -352,6 +368,57 @@ async fn load_indices_data(
     })
 }
 
+/// Přesune backing indexy každého data streamu k sobě (na pozici prvního z nich)
+/// a vrátí počet backing indexů pro každý stream
+fn group_data_stream_indices(indices: &mut Vec<IndexInfo>) -> HashMap<String, usize> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for idx in indices.iter() {
+        if let Some(stream) = &idx.data_stream {
+            *counts.entry(stream.clone()).or_default() += 1;
+        }
+    }
+    if counts.is_empty() {
+        return counts;
+    }
+
+    let mut groups: HashMap<String, Vec<IndexInfo>> = HashMap::new();
+    let mut order: Vec<Result<IndexInfo, String>> = Vec::new();
+    for idx in indices.drain(..) {
+        match idx.data_stream.clone() {
+            Some(stream) => {
+                let group = groups.entry(stream.clone()).or_default();
+                if group.is_empty() {
+                    order.push(Err(stream));
+                }
+                group.push(idx);
+            }
+            None => order.push(Ok(idx)),
+        }
+    }
+
+    for item in order {
+        match item {
+            Ok(idx) => indices.push(idx),
+            Err(stream) => indices.extend(groups.remove(&stream).unwrap_or_default()),
+        }
+    }
+
+    counts
+}
+
+/// Označí první řádek každé skupiny data streamu na stránce (řádek s hlavičkou streamu)
+fn mark_stream_headers(indices: &mut [IndexInfo], counts: &HashMap<String, usize>) {
+    let mut previous: Option<String> = None;
+    for idx in indices.iter_mut() {
+        if let Some(stream) = &idx.data_stream
+            && previous.as_ref() != Some(stream)
+        {
+            idx.stream_header = counts.get(stream).copied();
+        }
+        previous = idx.data_stream.clone();
+    }
+}
+
 /// GET /indices/detail/:index_name - Vrátí detail indexu pro modální okno
 pub async fn index_detail(
     State(state): State<Arc<AppState>>,
@@ -432,16 +499,37 @@ async fn load_index_detail(
     let settings_response: serde_json::Value = client.get(&settings_path).await?;
     let settings = serde_json::to_string_pretty(&settings_response)
         .ok();
+    let settings_editable = settings_response.get(index_name)
+        .and_then(|v| v.get("settings"))
+        .and_then(crate::handlers::index_settings::editable_settings_json);
 
     // 4. Načti mappings
     let mappings_path = format!("/{}/_mapping", index_name);
     let mappings_response: serde_json::Value = client.get(&mappings_path).await?;
     let mappings = serde_json::to_string_pretty(&mappings_response)
         .ok();
+    let mapping_fields = mappings_response.get(index_name)
+        .and_then(|v| v.get("mappings"))
+        .map(crate::handlers::mappings::parse_mapping_fields)
+        .unwrap_or_default();
+
+    // 5. ILM stav indexu (ES 6.6+, chyba = ILM není dostupné)
+    let ilm = if client.version().map(|v| v.at_least(6, 6)).unwrap_or(false) {
+        client.get::<serde_json::Value>(&format!("/{}/_ilm/explain", index_name)).await
+            .ok()
+            .and_then(|response| crate::handlers::ilm::parse_explain(&response).into_iter().next())
+    } else {
+        None
+    };
 
-    // 5. Načti stats
+    // 6. Načti stats
     let stats_path = format!("/{}/_stats", index_name);
-    let stats_response: serde_json::Value = client.get(&stats_path).await?;
+    // Zavřený index nemá stats (index_closed_exception)
+    let stats_response: serde_json::Value = if index_info.status == "close" {
+        serde_json::json!({})
+    } else {
+        client.get(&stats_path).await?
+    };
     let stats = serde_json::to_string_pretty(&stats_response)
         .ok();
 
@@ -482,11 +570,7 @@ async fn load_index_detail(
     let stats_deleted_ratio = match (stats_docs_count, stats_docs_deleted) {
         (Some(count), Some(deleted)) => {
             let total = count + deleted;
-            if total > 0 {
-                Some(((deleted * 100) / total).min(100) as u8)
-            } else {
-                Some(0)
-            }
+            Some((deleted * 100).checked_div(total).unwrap_or(0).min(100) as u8)
         }
         _ => None,
     };
@@ -510,7 +594,10 @@ async fn load_index_detail(
         pri_store_size: index_info.pri_store_size.clone(),
         aliases: alias_names,
         settings,
+        settings_editable,
         mappings,
+        mapping_fields,
+        ilm,
         stats,
         stats_docs_count,
         stats_docs_deleted,
@@ -528,6 +615,17 @@ async fn load_index_detail(
     })
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct BulkOperationRequest {
+    pub indices: Vec<String>,
+    #[serde(default)]
+    pub max_num_segments: Option<u32>, // forcemerge
+    #[serde(default)]
+    pub only_expunge_deletes: bool,    // forcemerge
+    #[serde(default)]
+    pub block: Option<String>,         // add_block / remove_block
+}
+
 #[derive(Serialize)]
 pub struct BulkOperationResponse {
     pub success: bool,
@@ -535,25 +633,165 @@ pub struct BulkOperationResponse {
     pub message: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>, // dlouhé operace běží na pozadí jako ES task
+}
+
+/// Bloky, které lze nastavit přes `index.blocks.*`
+const INDEX_BLOCKS: &[&str] = &["write", "read_only", "read", "metadata", "read_only_allow_delete"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BulkMethod {
+    Post,
+    Put,
+    Delete,
+}
+
+/// Maximální délka seznamu indexů v jedné cestě (ES `http.max_initial_line_length` je 4 KB)
+const MAX_BULK_TARGET_LEN: usize = 3000;
+
+/// Připravený ES request pro bulk operaci
+#[derive(Debug, PartialEq)]
+struct BulkRequestPlan {
+    method: BulkMethod,
+    /// Jedna cesta na dávku indexů, aby request nepřekročil limit délky URL
+    paths: Vec<String>,
+    body: serde_json::Value,
+    /// Request vrací task id místo výsledku (wait_for_completion=false)
+    tracked: bool,
+    /// Zpráva při úspěchu (jednotné / množné číslo)
+    messages: (&'static str, &'static str),
+    /// Počet indexů, na které request skutečně míří
+    count: usize,
 }
 
-/// POST /indices/bulk/{action}/{index_name} - Provede bulk operaci na indexu
+/// Rozdělí zakódované názvy indexů do dávek `a,b,c` kratších než `MAX_BULK_TARGET_LEN`
+fn bulk_targets(indices: &[&str]) -> Vec<String> {
+    let mut targets: Vec<String> = Vec::new();
+    for name in indices.iter().map(|i| encode_component(i)) {
+        match targets.last_mut() {
+            Some(target) if target.len() + 1 + name.len() <= MAX_BULK_TARGET_LEN => {
+                target.push(',');
+                target.push_str(&name);
+            }
+            _ => targets.push(name),
+        }
+    }
+    targets
+}
+
+/// Sestaví ES request pro akci nad seznamem indexů (jeden request na dávku indexů)
+fn plan_bulk_operation(
+    action: &str,
+    req: &BulkOperationRequest,
+    version: Option<&crate::es::client::EsVersion>,
+) -> anyhow::Result<BulkRequestPlan> {
+    let indices: Vec<&str> = req.indices.iter()
+        .map(|i| i.trim())
+        .filter(|i| !i.is_empty())
+        .collect();
+    if indices.is_empty() {
+        return Err(anyhow::anyhow!("No indices selected"));
+    }
+    if indices.iter().any(|i| i.contains('*') || i.starts_with('_')) {
+        return Err(anyhow::anyhow!("Wildcards are not allowed in bulk operations"));
+    }
+    let targets = bulk_targets(&indices);
+    let at_least = |major, minor| version.map(|v| v.at_least(major, minor)).unwrap_or(false);
+
+    let plan = |method, suffix: String, body, messages| BulkRequestPlan {
+        method,
+        paths: targets.iter().map(|target| format!("/{}{}", target, suffix)).collect(),
+        body,
+        tracked: false,
+        messages,
+        count: indices.len(),
+    };
+
+    let result = match action {
+        "delete" => plan(BulkMethod::Delete, String::new(), serde_json::json!(null), ("Index smazán", "Indexy smazány")),
+        "close" => plan(BulkMethod::Post, "/_close".to_string(), serde_json::json!({}), ("Index zavřen", "Indexy zavřeny")),
+        "open" => plan(BulkMethod::Post, "/_open".to_string(), serde_json::json!({}), ("Index otevřen", "Indexy otevřeny")),
+        "refresh" => plan(BulkMethod::Post, "/_refresh".to_string(), serde_json::json!({}), ("Index refreshnut", "Indexy refreshnuty")),
+        "flush" => plan(BulkMethod::Post, "/_flush".to_string(), serde_json::json!({}), ("Index flushnut", "Indexy flushnuty")),
+        "clear_cache" => plan(BulkMethod::Post, "/_cache/clear".to_string(), serde_json::json!({}), ("Cache indexu vyčištěna", "Cache indexů vyčištěna")),
+        "forcemerge" => {
+            if req.only_expunge_deletes && req.max_num_segments.is_some() {
+                return Err(anyhow::anyhow!("max_num_segments and only_expunge_deletes cannot be combined"));
+            }
+            let mut params = Vec::new();
+            if let Some(segments) = req.max_num_segments {
+                if segments == 0 {
+                    return Err(anyhow::anyhow!("max_num_segments must be at least 1"));
+                }
+                params.push(format!("max_num_segments={}", segments));
+            }
+            if req.only_expunge_deletes {
+                params.push("only_expunge_deletes=true".to_string());
+            }
+            // Od 7.7 může force merge běžet na pozadí jako task
+            let tracked = at_least(7, 7);
+            if tracked {
+                params.push("wait_for_completion=false".to_string());
+            }
+            let query = if params.is_empty() { String::new() } else { format!("?{}", params.join("&")) };
+            BulkRequestPlan {
+                tracked,
+                ..plan(BulkMethod::Post, format!("/_forcemerge{}", query), serde_json::json!({}), ("Force merge dokončen", "Force merge dokončen"))
+            }
+        }
+        "add_block" | "remove_block" => {
+            let block = req.block.as_deref().unwrap_or("");
+            if !INDEX_BLOCKS.contains(&block) {
+                return Err(anyhow::anyhow!("Unknown block: {}", block));
+            }
+            if action == "add_block" && block != "read_only_allow_delete" && at_least(7, 9) {
+                // Add index block API počká na dokončení rozpracovaných zápisů
+                plan(BulkMethod::Put, format!("/_block/{}", block), serde_json::json!({}), ("Blok přidán", "Bloky přidány"))
+            } else {
+                let value = if action == "add_block" { serde_json::json!(true) } else { serde_json::json!(null) };
+                let messages = if action == "add_block" { ("Blok přidán", "Bloky přidány") } else { ("Blok odebrán", "Bloky odebrány") };
+                plan(
+                    BulkMethod::Put,
+                    "/_settings".to_string(),
+                    serde_json::json!({ format!("index.blocks.{}", block): value }),
+                    messages,
+                )
+            }
+        }
+        "freeze" => {
+            if !at_least(6, 6) || at_least(8, 0) {
+                return Err(anyhow::anyhow!("Freeze is only available on Elasticsearch 6.6 - 7.x"));
+            }
+            plan(BulkMethod::Post, "/_freeze".to_string(), serde_json::json!({}), ("Index zmrazen", "Indexy zmrazeny"))
+        }
+        "unfreeze" => {
+            if !at_least(6, 6) || at_least(9, 0) {
+                return Err(anyhow::anyhow!("Unfreeze is only available on Elasticsearch 6.6 - 8.x"));
+            }
+            plan(BulkMethod::Post, "/_unfreeze".to_string(), serde_json::json!({}), ("Index rozmrazen", "Indexy rozmrazeny"))
+        }
+        _ => return Err(anyhow::anyhow!("Unknown action: {}", action)),
+    };
+
+    Ok(result)
+}
+
+/// POST /indices/bulk/{action} - Provede operaci nad vybranými indexy (čárkou spojené dávky)
 pub async fn bulk_operation(
     State(state): State<Arc<AppState>>,
     jar: CookieJar,
-    axum::extract::Path((action, index_name)): axum::extract::Path<(String, String)>,
+    axum::extract::Path(action): axum::extract::Path<String>,
+    Json(req): Json<BulkOperationRequest>,
 ) -> Result<Json<BulkOperationResponse>, (StatusCode, Json<BulkOperationResponse>)> {
+    let error_response = |status: StatusCode, error: String| {
+        (status, Json(BulkOperationResponse { success: false, message: None, error: Some(error), task_id: None }))
+    };
+
     let active_endpoint = get_active_endpoint(&state, &jar).await;
 
     if active_endpoint.is_none() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(BulkOperationResponse {
-                success: false,
-                message: None,
-                error: Some("No active endpoint selected".to_string()),
-            }),
-        ));
+        return Err(error_response(StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
     }
 
     let endpoint = active_endpoint.as_ref().unwrap();
@@ -564,70 +802,203 @@ pub async fn bulk_operation(
         endpoint.insecure,
         endpoint.username.clone(),
         password,
-    ).map_err(|e| (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(BulkOperationResponse {
-            success: false,
-            message: None,
-            error: Some(format!("Failed to create ES client: {}", e)),
-        }),
-    ))?;
-
-    client.detect_version().await.map_err(|e| (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        Json(BulkOperationResponse {
-            success: false,
-            message: None,
-            error: Some(format!("Failed to detect ES version: {}", e)),
-        }),
-    ))?;
-
-    // Perform the action
-    let result = match action.as_str() {
-        "delete" => perform_delete_index(&client, &index_name).await,
-        "close" => perform_close_index(&client, &index_name).await,
-        "open" => perform_open_index(&client, &index_name).await,
-        "refresh" => perform_refresh_index(&client, &index_name).await,
-        _ => Err(anyhow::anyhow!("Unknown action: {}", action)),
-    };
+    ).map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+
+    client.detect_version().await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to detect ES version: {}", e)))?;
+
+    let plan = plan_bulk_operation(&action, &req, client.version())
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    let count = plan.count;
+    let batches = plan.paths.len();
+    let mut task_ids = Vec::new();
+
+    for (done, path) in plan.paths.iter().enumerate() {
+        // Chyba v pozdější dávce - předchozí dávky už proběhly
+        let batch_error = |error: String| if done == 0 {
+            error
+        } else {
+            format!("{} (after {} of {} batches succeeded)", error, done, batches)
+        };
+
+        let result = match plan.method {
+            BulkMethod::Post => client.post_raw(path, plan.body.clone()).await,
+            BulkMethod::Put => client.put_raw(path, plan.body.clone()).await,
+            BulkMethod::Delete => client.delete_raw(path).await,
+        };
+
+        let (status, body) = result
+            .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, batch_error(e.to_string())))?;
+
+        if status >= 400 {
+            return Err(error_response(StatusCode::INTERNAL_SERVER_ERROR, batch_error(extract_error_reason(&body))));
+        }
+
+        let response: serde_json::Value = serde_json::from_str(&body).unwrap_or_default();
+
+        if plan.tracked
+            && let Some(task_id) = response["task"].as_str()
+        {
+            task_ids.push(task_id.to_string());
+            continue;
+        }
+
+        // Shard failures (flush, refresh, forcemerge, cache clear)
+        let failed_shards = response["_shards"]["failed"].as_u64().unwrap_or(0);
+        if failed_shards > 0 {
+            let reason = response["_shards"]["failures"][0]["reason"]["reason"]
+                .as_str()
+                .unwrap_or("unknown reason");
+            return Err(error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                batch_error(format!("{} shard(s) failed: {}", failed_shards, reason)),
+            ));
+        }
+    }
 
-    match result {
-        Ok(message) => Ok(Json(BulkOperationResponse {
+    if !task_ids.is_empty() {
+        tracing::info!("Started {} on {} indices as task(s) {}", action, count, task_ids.join(", "));
+        // Více dávek = více tasků, odkaz na detail má smysl jen pro jeden
+        let (message, task_id) = match task_ids.as_slice() {
+            [task_id] => (format!("Task spuštěn ({})", task_id), Some(task_id.clone())),
+            _ => (format!("Spuštěno {} tasků ({})", task_ids.len(), task_ids.join(", ")), None),
+        };
+        return Ok(Json(BulkOperationResponse {
             success: true,
             message: Some(message),
             error: None,
-        })),
-        Err(e) => Err((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(BulkOperationResponse {
-                success: false,
-                message: None,
-                error: Some(e.to_string()),
-            }),
-        )),
+            task_id,
+        }));
     }
-}
 
-async fn perform_delete_index(client: &EsClient, index_name: &str) -> anyhow::Result<String> {
-    let path = format!("/{}", index_name);
-    let _response: serde_json::Value = client.delete(&path).await?;
-    Ok("Index smazán".to_string())
-}
+    let message = if count == 1 {
+        plan.messages.0.to_string()
+    } else {
+        format!("{} ({})", plan.messages.1, count)
+    };
 
-async fn perform_close_index(client: &EsClient, index_name: &str) -> anyhow::Result<String> {
-    let path = format!("/{}/_close", index_name);
-    let _response: serde_json::Value = client.post(&path, serde_json::json!({})).await?;
-    Ok("Index zavřen".to_string())
+    Ok(Json(BulkOperationResponse {
+        success: true,
+        message: Some(message),
+        error: None,
+        task_id: None,
+    }))
 }
 
-async fn perform_open_index(client: &EsClient, index_name: &str) -> anyhow::Result<String> {
-    let path = format!("/{}/_open", index_name);
-    let _response: serde_json::Value = client.post(&path, serde_json::json!({})).await?;
-    Ok("Index otevřen".to_string())
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::es::client::EsVersion;
+
+    fn request(indices: &[&str]) -> BulkOperationRequest {
+        BulkOperationRequest {
+            indices: indices.iter().map(|i| i.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_joins_indices_into_single_request() {
+        let plan = plan_bulk_operation("flush", &request(&["a", " ", "b"]), None).unwrap();
+        assert_eq!(plan.method, BulkMethod::Post);
+        assert_eq!(plan.paths, vec!["/a,b/_flush"]);
+        assert_eq!(plan.count, 2);
+    }
 
-async fn perform_refresh_index(client: &EsClient, index_name: &str) -> anyhow::Result<String> {
-    let path = format!("/{}/_refresh", index_name);
-    let _response: serde_json::Value = client.post(&path, serde_json::json!({})).await?;
-    Ok("Index refreshnut".to_string())
+    #[test]
+    fn test_plan_encodes_and_batches_indices() {
+        let plan = plan_bulk_operation("refresh", &request(&["logs#1", "a&b"]), None).unwrap();
+        assert_eq!(plan.paths, vec!["/logs%231,a%26b/_refresh"]);
+
+        // Stovky backing indexů se rozdělí do více requestů pod limitem délky řádku
+        let names: Vec<String> = (0..300).map(|i| format!(".ds-logs-app-default-2024.01.01-{:06}", i)).collect();
+        let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        let plan = plan_bulk_operation("delete", &request(&names), None).unwrap();
+        assert!(plan.paths.len() > 1);
+        assert!(plan.paths.iter().all(|p| p.len() <= MAX_BULK_TARGET_LEN + 1));
+        assert_eq!(plan.paths.iter().map(|p| p.split(',').count()).sum::<usize>(), 300);
+        assert_eq!(plan.count, 300);
+    }
+
+    #[test]
+    fn test_plan_forcemerge() {
+        let v8 = EsVersion::from_string("8.11.0").unwrap();
+        let mut req = request(&["logs"]);
+        req.max_num_segments = Some(1);
+        let plan = plan_bulk_operation("forcemerge", &req, Some(&v8)).unwrap();
+        assert_eq!(plan.paths[0], "/logs/_forcemerge?max_num_segments=1&wait_for_completion=false");
+        assert!(plan.tracked);
+
+        let v6 = EsVersion::from_string("6.8.0").unwrap();
+        let plan = plan_bulk_operation("forcemerge", &request(&["logs"]), Some(&v6)).unwrap();
+        assert_eq!(plan.paths[0], "/logs/_forcemerge");
+        assert!(!plan.tracked);
+
+        req.only_expunge_deletes = true;
+        assert!(plan_bulk_operation("forcemerge", &req, Some(&v8)).is_err());
+    }
+
+    #[test]
+    fn test_plan_blocks() {
+        let v8 = EsVersion::from_string("8.11.0").unwrap();
+        let mut req = request(&["logs"]);
+        req.block = Some("write".to_string());
+
+        let plan = plan_bulk_operation("add_block", &req, Some(&v8)).unwrap();
+        assert_eq!(plan.method, BulkMethod::Put);
+        assert_eq!(plan.paths[0], "/logs/_block/write");
+
+        let plan = plan_bulk_operation("remove_block", &req, Some(&v8)).unwrap();
+        assert_eq!(plan.paths[0], "/logs/_settings");
+        assert_eq!(plan.body, serde_json::json!({"index.blocks.write": null}));
+
+        req.block = Some("everything".to_string());
+        assert!(plan_bulk_operation("add_block", &req, Some(&v8)).is_err());
+    }
+
+    #[test]
+    fn test_plan_freeze_version_gate() {
+        let v7 = EsVersion::from_string("7.17.0").unwrap();
+        let v8 = EsVersion::from_string("8.11.0").unwrap();
+        assert!(plan_bulk_operation("freeze", &request(&["old"]), Some(&v7)).is_ok());
+        assert!(plan_bulk_operation("freeze", &request(&["old"]), Some(&v8)).is_err());
+        assert!(plan_bulk_operation("unfreeze", &request(&["old"]), Some(&v8)).is_ok());
+    }
+
+    #[test]
+    fn test_plan_rejects_wildcards() {
+        assert!(plan_bulk_operation("delete", &request(&["*"]), None).is_err());
+        assert!(plan_bulk_operation("delete", &request(&[]), None).is_err());
+    }
+
+    fn index_info(name: &str, stream: Option<&str>) -> IndexInfo {
+        let mut info: IndexInfo = serde_json::from_value(serde_json::json!({
+            "health": "green", "status": "open", "index": name, "uuid": "", "pri": "1", "rep": "0",
+            "docs.count": "0", "docs.deleted": "0", "store.size": "0", "pri.store.size": "0"
+        })).unwrap();
+        info.data_stream = stream.map(|s| s.to_string());
+        info
+    }
+
+    #[test]
+    fn test_group_data_stream_indices() {
+        let mut indices = vec![
+            index_info(".ds-logs-000001", Some("logs")),
+            index_info("app", None),
+            index_info(".ds-metrics-000001", Some("metrics")),
+            index_info(".ds-logs-000002", Some("logs")),
+        ];
+        let counts = group_data_stream_indices(&mut indices);
+        let names: Vec<&str> = indices.iter().map(|i| i.index.as_str()).collect();
+        assert_eq!(names, vec![".ds-logs-000001", ".ds-logs-000002", "app", ".ds-metrics-000001"]);
+        assert_eq!(counts.get("logs"), Some(&2));
+
+        // Skupina rozdělená mezi stránky dostane hlavičku i na další stránce
+        let mut page = indices.split_off(1);
+        mark_stream_headers(&mut page, &counts);
+        assert_eq!(page[0].stream_header, Some(2));
+        assert_eq!(page[1].stream_header, None);
+        assert_eq!(page[2].stream_header, Some(1));
+    }
 }
@@ -0,0 +1,874 @@
+use axum::{
+    extract::{Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::templates::{MappingsTemplate, PageContext};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{FieldCapsEntry, FieldCapsType, MappingExplorerData, MappingField};
+
+/// Parametry fieldu, které mají v tabulce vlastní sloupec
+const KNOWN_FIELD_PARAMS: &[&str] = &[
+    "type", "properties", "fields", "analyzer", "search_analyzer", "normalizer", "index", "doc_values",
+];
+
+/// Typy bez doc_values (default false / nepodporováno)
+const NO_DOC_VALUES_TYPES: &[&str] = &["text", "match_only_text", "annotated_text", "search_as_you_type"];
+
+#[derive(Debug, Deserialize)]
+pub struct MappingsQuery {
+    #[serde(default)]
+    pub pattern: String,
+    #[serde(default)]
+    pub conflicts_only: bool,
+}
+
+/// Vrátí objekt s `properties` / `runtime` - v ES 6.x je mapping obalený názvem typu
+fn unwrap_mapping_type(mappings: &Value) -> &Value {
+    if mappings.get("properties").is_some() || mappings.get("runtime").is_some() {
+        return mappings;
+    }
+    mappings.as_object()
+        .and_then(|m| m.values().find(|v| v.get("properties").is_some()))
+        .unwrap_or(mappings)
+}
+
+fn field_details(def: &Value) -> Vec<String> {
+    def.as_object()
+        .map(|m| {
+            m.iter()
+                .filter(|(k, _)| !KNOWN_FIELD_PARAMS.contains(&k.as_str()))
+                .map(|(k, v)| match v {
+                    Value::String(s) => format!("{}: {}", k, s),
+                    other => format!("{}: {}", k, other),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn build_field(path: &str, name: &str, depth: usize, def: &Value, is_multi_field: bool, is_runtime: bool) -> MappingField {
+    let field_type = def["type"].as_str()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| "object".to_string());
+    let container = field_type == "object" || field_type == "nested";
+
+    // Runtime fieldy se počítají ze skriptu při dotazu, doc_values nemají
+    let doc_values = if container || is_runtime {
+        None
+    } else {
+        Some(def["doc_values"].as_bool().unwrap_or(!NO_DOC_VALUES_TYPES.contains(&field_type.as_str())))
+    };
+    let index = if container || is_runtime {
+        None
+    } else {
+        Some(def["index"].as_bool().unwrap_or(true))
+    };
+
+    MappingField {
+        path: path.to_string(),
+        name: name.to_string(),
+        depth,
+        field_type,
+        analyzer: def["analyzer"].as_str().map(|s| s.to_string()),
+        search_analyzer: def["search_analyzer"].as_str().map(|s| s.to_string()),
+        normalizer: def["normalizer"].as_str().map(|s| s.to_string()),
+        index,
+        doc_values,
+        is_multi_field,
+        is_runtime,
+        details: field_details(def),
+    }
+}
+
+fn collect_properties(properties: &Value, prefix: &str, depth: usize, out: &mut Vec<MappingField>) {
+    let Some(map) = properties.as_object() else {
+        return;
+    };
+
+    let mut names: Vec<&String> = map.keys().collect();
+    names.sort();
+
+    for name in names {
+        let def = &map[name];
+        let path = if prefix.is_empty() { name.clone() } else { format!("{}.{}", prefix, name) };
+        out.push(build_field(&path, name, depth, def, false, false));
+
+        if let Some(fields) = def["fields"].as_object() {
+            let mut sub_names: Vec<&String> = fields.keys().collect();
+            sub_names.sort();
+            for sub_name in sub_names {
+                let sub_path = format!("{}.{}", path, sub_name);
+                out.push(build_field(&sub_path, sub_name, depth + 1, &fields[sub_name], true, false));
+            }
+        }
+
+        if def.get("properties").is_some() {
+            collect_properties(&def["properties"], &path, depth + 1, out);
+        }
+    }
+}
+
+/// Zploští mapping indexu do seznamu fieldů (properties, multi-fields, runtime fields)
+pub fn parse_mapping_fields(mappings: &Value) -> Vec<MappingField> {
+    let root = unwrap_mapping_type(mappings);
+    let mut fields = Vec::new();
+
+    collect_properties(&root["properties"], "", 0, &mut fields);
+
+    if let Some(runtime) = root["runtime"].as_object() {
+        let mut names: Vec<&String> = runtime.keys().collect();
+        names.sort();
+        for name in names {
+            fields.push(build_field(name, name, 0, &runtime[name], false, true));
+        }
+    }
+
+    fields
+}
+
+/// Zpracuje odpověď `_field_caps` - metadata fieldy (`_id`, ...) vynechává
+pub fn parse_field_caps(response: &Value) -> Vec<FieldCapsEntry> {
+    let Some(fields) = response["fields"].as_object() else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<FieldCapsEntry> = fields.iter()
+        .filter(|(name, _)| !name.starts_with('_'))
+        .map(|(name, types)| {
+            let mut types: Vec<FieldCapsType> = types.as_object()
+                .map(|m| {
+                    m.iter()
+                        .map(|(field_type, caps)| FieldCapsType {
+                            field_type: field_type.clone(),
+                            searchable: caps["searchable"].as_bool().unwrap_or(false),
+                            aggregatable: caps["aggregatable"].as_bool().unwrap_or(false),
+                            indices: caps["indices"].as_array()
+                                .map(|a| a.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+                                .unwrap_or_default(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            types.sort_by(|a, b| a.field_type.cmp(&b.field_type));
+            FieldCapsEntry { name: name.clone(), types }
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// GET /mappings - Porovnání mappingů napříč indexy (field caps)
+pub async fn mappings_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<MappingsQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let pattern = query.pattern.trim().to_string();
+    let mut error = None;
+
+    let data = if pattern.is_empty() {
+        None
+    } else {
+        match tokio::time::timeout(
+            tokio::time::Duration::from_secs(30),
+            load_mappings_data(&state, endpoint, &pattern, query.conflicts_only)
+        ).await {
+            Ok(Ok(d)) => Some(d),
+            Ok(Err(e)) => {
+                tracing::error!("Failed to load field caps: {}", e);
+                error = Some(e.to_string());
+                None
+            }
+            Err(_) => {
+                tracing::error!("Timeout loading field caps");
+                error = Some("Timeout loading field capabilities".to_string());
+                None
+            }
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = MappingsTemplate { ctx, data, pattern, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_mappings_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    pattern: &str,
+    conflicts_only: bool,
+) -> anyhow::Result<MappingExplorerData> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let response: Value = client.get(&format!("/{}/_field_caps?fields=*", pattern)).await?;
+    let mut fields = parse_field_caps(&response);
+
+    // `indices` v odpovědi vrací ES 7.2+, starší verze - spočítej z _cat/indices
+    let indices_count = match response["indices"].as_array() {
+        Some(indices) => indices.len(),
+        None => {
+            let rows: Vec<Value> = client.get(&format!("/_cat/indices/{}?format=json&h=index", pattern)).await
+                .unwrap_or_default();
+            rows.len()
+        }
+    };
+
+    let conflicts = fields.iter().filter(|f| f.is_conflict()).count();
+    if conflicts_only {
+        fields.retain(|f| f.is_conflict());
+    }
+
+    Ok(MappingExplorerData {
+        pattern: pattern.to_string(),
+        fields,
+        indices_count,
+        conflicts,
+        conflicts_only,
+    })
+}
+
+/// Parametry, které lze u existujícího fieldu změnit přes `PUT _mapping`
+const UPDATABLE_FIELD_PARAMS: &[&str] = &[
+    "fields", "search_analyzer", "search_quote_analyzer", "ignore_above", "ignore_malformed", "copy_to", "meta",
+];
+
+/// Typy, u kterých má smysl analyzer
+const ANALYZED_TYPES: &[&str] = &["text", "match_only_text", "search_as_you_type", "annotated_text"];
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubFieldRequest {
+    pub name: String,
+    pub field_type: String,
+    #[serde(default)]
+    pub analyzer: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MappingFieldRequest {
+    pub path: String,                   // tečkovaná cesta, např. user.email
+    pub field_type: String,
+    #[serde(default)]
+    pub analyzer: String,
+    #[serde(default)]
+    pub search_analyzer: String,
+    #[serde(default = "default_true")]
+    pub index: bool,
+    #[serde(default = "default_true")]
+    pub doc_values: bool,
+    #[serde(default)]
+    pub sub_fields: Vec<SubFieldRequest>,
+    #[serde(default)]
+    pub extra: String,                  // raw JSON s dalšími parametry
+}
+
+/// Návrh reindexu, když změnu nelze provést in-place
+#[derive(Debug, Serialize)]
+pub struct ReindexHint {
+    pub target_index: String,
+    pub create_body: String,            // `PUT /{target}` s upraveným mappingem
+    pub reindex_body: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MappingValidateResponse {
+    pub valid: bool,
+    pub reasons: Vec<String>,
+    pub warnings: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reindex: Option<ReindexHint>,
+}
+
+#[derive(Serialize)]
+pub struct MappingUpdateResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reindex: Option<ReindexHint>,
+}
+
+/// Výsledek hledání fieldu v mappingu
+#[derive(Debug)]
+enum FieldLookup<'a> {
+    Found(&'a Value),
+    Missing,
+    ParentNotObject { parent: String, parent_type: String },
+}
+
+fn field_type_of(def: &Value) -> &str {
+    def["type"].as_str().unwrap_or("object")
+}
+
+fn find_field<'a>(root: &'a Value, path: &str) -> FieldLookup<'a> {
+    let parts: Vec<&str> = path.split('.').collect();
+    let mut properties = &root["properties"];
+
+    for (i, part) in parts.iter().enumerate() {
+        let node = &properties[*part];
+        if node.is_null() {
+            return FieldLookup::Missing;
+        }
+        if i == parts.len() - 1 {
+            return FieldLookup::Found(node);
+        }
+        let node_type = field_type_of(node);
+        if node_type != "object" && node_type != "nested" {
+            return FieldLookup::ParentNotObject {
+                parent: parts[..=i].join("."),
+                parent_type: node_type.to_string(),
+            };
+        }
+        properties = &node["properties"];
+    }
+
+    FieldLookup::Missing
+}
+
+/// Sestaví definici fieldu z formuláře
+fn build_field_definition(req: &MappingFieldRequest) -> Result<Value, String> {
+    let field_type = req.field_type.trim();
+    if field_type.is_empty() {
+        return Err("Field type is required".to_string());
+    }
+    let analyzed = ANALYZED_TYPES.contains(&field_type);
+
+    let mut def = serde_json::Map::new();
+    def.insert("type".to_string(), json!(field_type));
+
+    if !req.analyzer.trim().is_empty() {
+        if !analyzed {
+            return Err(format!("Analyzer is not supported on {} fields", field_type));
+        }
+        def.insert("analyzer".to_string(), json!(req.analyzer.trim()));
+    }
+    if !req.search_analyzer.trim().is_empty() {
+        if !analyzed {
+            return Err(format!("Search analyzer is not supported on {} fields", field_type));
+        }
+        def.insert("search_analyzer".to_string(), json!(req.search_analyzer.trim()));
+    }
+    if !req.index {
+        def.insert("index".to_string(), json!(false));
+    }
+    if !req.doc_values && !analyzed && field_type != "object" && field_type != "nested" {
+        def.insert("doc_values".to_string(), json!(false));
+    }
+
+    if !req.sub_fields.is_empty() {
+        let mut fields = serde_json::Map::new();
+        for sub in &req.sub_fields {
+            let name = sub.name.trim();
+            if name.is_empty() || name.contains('.') {
+                return Err(format!("Invalid sub-field name '{}'", sub.name));
+            }
+            let mut sub_def = serde_json::Map::new();
+            sub_def.insert("type".to_string(), json!(sub.field_type.trim()));
+            if !sub.analyzer.trim().is_empty() {
+                sub_def.insert("analyzer".to_string(), json!(sub.analyzer.trim()));
+            }
+            fields.insert(name.to_string(), Value::Object(sub_def));
+        }
+        def.insert("fields".to_string(), Value::Object(fields));
+    }
+
+    if !req.extra.trim().is_empty() {
+        match serde_json::from_str::<Value>(&req.extra) {
+            Ok(Value::Object(extra)) => {
+                for (key, value) in extra {
+                    def.insert(key, value);
+                }
+            }
+            Ok(_) => return Err("Extra parameters must be a JSON object".to_string()),
+            Err(e) => return Err(format!("Extra parameters are not valid JSON: {}", e)),
+        }
+    }
+
+    Ok(Value::Object(def))
+}
+
+/// Výchozí hodnota parametru, pokud v mappingu chybí
+fn default_param_value(field_type: &str, param: &str) -> Value {
+    match param {
+        "analyzer" if ANALYZED_TYPES.contains(&field_type) => json!("standard"),
+        "index" => json!(true),
+        "doc_values" => json!(!NO_DOC_VALUES_TYPES.contains(&field_type)),
+        _ => Value::Null,
+    }
+}
+
+/// Zjistí, proč změnu nelze provést in-place (prázdný seznam = lze)
+fn check_mapping_update(root: &Value, path: &str, def: &Value) -> Vec<String> {
+    let mut reasons = Vec::new();
+
+    match find_field(root, path) {
+        FieldLookup::ParentNotObject { parent, parent_type } => {
+            reasons.push(format!(
+                "{} is a {} field and cannot contain properties - add the field as a sub-field (multi-field) of {} instead",
+                parent, parent_type, parent
+            ));
+        }
+        FieldLookup::Missing => {}
+        FieldLookup::Found(existing) => {
+            let old_type = field_type_of(existing);
+            let new_type = field_type_of(def);
+            if old_type != new_type {
+                reasons.push(format!(
+                    "{} is already mapped as {} - the type of an existing field cannot be changed to {}",
+                    path, old_type, new_type
+                ));
+                return reasons;
+            }
+
+            if let Some(params) = def.as_object() {
+                for (param, value) in params {
+                    if param == "type" || UPDATABLE_FIELD_PARAMS.contains(&param.as_str()) {
+                        continue;
+                    }
+                    let current = existing.get(param)
+                        .cloned()
+                        .unwrap_or_else(|| default_param_value(old_type, param));
+                    if &current != value {
+                        reasons.push(format!(
+                            "Parameter [{}] of {} cannot be changed from {} to {} on an existing field",
+                            param, path, current, value
+                        ));
+                    }
+                }
+            }
+
+            // Nové sub-fieldy lze přidat, existující nelze měnit
+            if let (Some(new_fields), Some(old_fields)) = (def["fields"].as_object(), existing["fields"].as_object()) {
+                for (name, sub_def) in new_fields {
+                    if let Some(old_sub) = old_fields.get(name)
+                        && field_type_of(old_sub) != field_type_of(sub_def)
+                    {
+                        reasons.push(format!(
+                            "Sub-field {}.{} already exists as {}",
+                            path, name, field_type_of(old_sub)
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    reasons
+}
+
+/// Sestaví body pro `PUT _mapping` - tečkovaná cesta jako vnořené properties
+fn build_mapping_update_body(root: &Value, path: &str, def: &Value) -> Value {
+    let parts: Vec<&str> = path.split('.').collect();
+
+    // Existující definice + nové parametry (nezadané parametry zůstávají)
+    let mut leaf = match find_field(root, path) {
+        FieldLookup::Found(existing) => {
+            let mut merged = existing.clone();
+            if let (Some(merged_map), Some(def_map)) = (merged.as_object_mut(), def.as_object()) {
+                for (key, value) in def_map {
+                    if key == "fields" {
+                        let fields = merged_map.entry("fields".to_string()).or_insert_with(|| json!({}));
+                        if let (Some(target), Some(new_fields)) = (fields.as_object_mut(), value.as_object()) {
+                            for (name, sub) in new_fields {
+                                target.insert(name.clone(), sub.clone());
+                            }
+                        }
+                    } else {
+                        merged_map.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+            merged
+        }
+        _ => def.clone(),
+    };
+
+    // Od listu ke kořeni; nested rodiče musí zopakovat svůj typ
+    let mut properties = &root["properties"];
+    let mut parent_types = Vec::new();
+    for part in &parts[..parts.len() - 1] {
+        let node = &properties[*part];
+        parent_types.push(node["type"].as_str().map(|s| s.to_string()));
+        properties = &node["properties"];
+    }
+
+    for (i, part) in parts.iter().enumerate().rev() {
+        let mut wrapper = serde_json::Map::new();
+        wrapper.insert(part.to_string(), leaf);
+        let mut node = serde_json::Map::new();
+        node.insert("properties".to_string(), Value::Object(wrapper));
+        if i > 0
+            && let Some(Some(parent_type)) = parent_types.get(i - 1)
+            && parent_type == "nested"
+        {
+            node.insert("type".to_string(), json!("nested"));
+        }
+        leaf = Value::Object(node);
+    }
+
+    leaf
+}
+
+/// Navrhne název cílového indexu pro reindex (logs-v1 -> logs-v2, logs -> logs-v2)
+fn suggest_reindex_target(index_name: &str) -> String {
+    if let Some((base, version)) = index_name.rsplit_once("-v")
+        && let Ok(n) = version.parse::<u32>()
+    {
+        return format!("{}-v{}", base, n + 1);
+    }
+    format!("{}-v2", index_name)
+}
+
+/// Celý mapping zdrojového indexu s fieldem nahrazeným novou definicí (pro nový cílový index)
+fn build_reindex_mapping(root: &Value, path: &str, def: &Value) -> Value {
+    let mut mapping = root.clone();
+    let parts: Vec<&str> = path.split('.').collect();
+    replace_field(&mut mapping, &parts, def);
+    mapping
+}
+
+/// Vloží definici fieldu do `properties`, chybějící objektové rodiče založí
+fn replace_field(node: &mut Value, parts: &[&str], def: &Value) {
+    let Some(properties) = node.as_object_mut()
+        .map(|m| m.entry("properties".to_string()).or_insert_with(|| json!({})))
+        .and_then(|p| p.as_object_mut())
+    else {
+        return;
+    };
+    match parts {
+        [name] => {
+            properties.insert(name.to_string(), def.clone());
+        }
+        [name, rest @ ..] => {
+            replace_field(properties.entry(name.to_string()).or_insert_with(|| json!({})), rest, def);
+        }
+        [] => {}
+    }
+}
+
+fn reindex_hint(index_name: &str, create_body: &Value) -> ReindexHint {
+    let target_index = suggest_reindex_target(index_name);
+    let body = json!({
+        "source": { "index": index_name },
+        "dest": { "index": target_index },
+    });
+    ReindexHint {
+        create_body: serde_json::to_string_pretty(create_body).unwrap_or_default(),
+        reindex_body: serde_json::to_string_pretty(&body).unwrap_or_default(),
+        target_index,
+    }
+}
+
+struct MappingUpdatePlan {
+    path: String,
+    body: Value,
+    /// Body pro vytvoření cílového indexu reindexu s novým mappingem
+    create_body: Value,
+    reasons: Vec<String>,
+    warnings: Vec<String>,
+}
+
+async fn plan_mapping_update(
+    client: &EsClient,
+    index_name: &str,
+    req: &MappingFieldRequest,
+) -> anyhow::Result<MappingUpdatePlan> {
+    let field_path = req.path.trim();
+    if field_path.is_empty() || field_path.starts_with('.') || field_path.ends_with('.') || field_path.contains("..") {
+        return Err(anyhow::anyhow!("Invalid field path '{}'", req.path));
+    }
+
+    let def = build_field_definition(req).map_err(|e| anyhow::anyhow!(e))?;
+
+    let response: Value = client.get(&format!("/{}/_mapping", index_name)).await?;
+    let mappings = response.get(index_name)
+        .or_else(|| response.as_object().and_then(|m| m.values().next()))
+        .map(|v| v["mappings"].clone())
+        .unwrap_or_else(|| json!({}));
+
+    // ES 6.x - mapping je pod názvem typu
+    let type_name = mappings.as_object()
+        .filter(|m| m.get("properties").is_none())
+        .and_then(|m| m.iter().find(|(_, v)| v.get("properties").is_some()).map(|(k, _)| k.clone()));
+    let root = unwrap_mapping_type(&mappings);
+
+    let reasons = check_mapping_update(root, field_path, &def);
+    let mut warnings = Vec::new();
+
+    if let FieldLookup::Found(_) = find_field(root, field_path)
+        && reasons.is_empty()
+    {
+        warnings.push(format!("{} already exists - new sub-fields and updatable parameters are merged into its definition", field_path));
+        if def.get("fields").is_some() {
+            warnings.push("New sub-fields are only populated for newly indexed documents - run update_by_query to backfill".to_string());
+        }
+    }
+    if root["runtime"].get(field_path).is_some() {
+        warnings.push(format!("A runtime field named {} exists and shadows the mapped field in searches", field_path));
+    }
+
+    let reindex_mapping = build_reindex_mapping(root, field_path, &def);
+    let (path, create_body) = match type_name {
+        Some(type_name) => (
+            format!("/{}/_mapping/{}", index_name, type_name),
+            json!({ "mappings": { type_name: reindex_mapping } }),
+        ),
+        None => (format!("/{}/_mapping", index_name), json!({ "mappings": reindex_mapping })),
+    };
+
+    Ok(MappingUpdatePlan {
+        path,
+        create_body,
+        body: build_mapping_update_body(root, field_path, &def),
+        reasons,
+        warnings,
+    })
+}
+
+/// POST /indices/mapping/{index_name}/validate - Zkontroluje nový field proti existujícímu mappingu
+pub async fn validate_mapping_update(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    axum::extract::Path(index_name): axum::extract::Path<String>,
+    Json(req): Json<MappingFieldRequest>,
+) -> Result<Json<MappingValidateResponse>, (StatusCode, String)> {
+    let (client, _) = active_es_client(&state, &jar, false).await?;
+
+    match plan_mapping_update(&client, &index_name, &req).await {
+        Ok(plan) => {
+            let valid = plan.reasons.is_empty();
+            Ok(Json(MappingValidateResponse {
+                valid,
+                reindex: if valid { None } else { Some(reindex_hint(&index_name, &plan.create_body)) },
+                reasons: plan.reasons,
+                warnings: plan.warnings,
+                body: Some(plan.body),
+            }))
+        }
+        Err(e) => Ok(Json(MappingValidateResponse {
+            valid: false,
+            reasons: vec![e.to_string()],
+            warnings: Vec::new(),
+            body: None,
+            reindex: None,
+        })),
+    }
+}
+
+/// POST /indices/mapping/{index_name} - Přidá / rozšíří field přes `PUT /{index}/_mapping`
+pub async fn update_mapping(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    axum::extract::Path(index_name): axum::extract::Path<String>,
+    Json(req): Json<MappingFieldRequest>,
+) -> Result<Json<MappingUpdateResponse>, (StatusCode, Json<MappingUpdateResponse>)> {
+    let error_response = |status: StatusCode, error: String, reindex: Option<ReindexHint>| {
+        (status, Json(MappingUpdateResponse { success: false, message: None, error: Some(error), reindex }))
+    };
+
+    let (client, _) = active_es_client(&state, &jar, false).await
+        .map_err(|(status, e)| error_response(status, e, None))?;
+
+    let plan = plan_mapping_update(&client, &index_name, &req).await
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e.to_string(), None))?;
+
+    if !plan.reasons.is_empty() {
+        return Err(error_response(StatusCode::CONFLICT, plan.reasons.join(". "), Some(reindex_hint(&index_name, &plan.create_body))));
+    }
+
+    let (status, body) = client.put_raw(&plan.path, plan.body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string(), None))?;
+
+    if status >= 400 {
+        // ES změnu odmítl - typicky konflikt s existujícím mappingem
+        return Err(error_response(
+            StatusCode::CONFLICT,
+            extract_error_reason(&body),
+            Some(reindex_hint(&index_name, &plan.create_body)),
+        ));
+    }
+
+    tracing::info!("Updated mapping of {} (field {})", index_name, req.path);
+
+    Ok(Json(MappingUpdateResponse {
+        success: true,
+        message: Some(format!("Field {} uložen", req.path.trim())),
+        error: None,
+        reindex: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mapping_fields() {
+        let fields = parse_mapping_fields(&json!({
+            "properties": {
+                "message": { "type": "text", "analyzer": "english", "fields": { "raw": { "type": "keyword", "ignore_above": 256 } } },
+                "user": { "properties": { "id": { "type": "long", "doc_values": false } } }
+            },
+            "runtime": { "day": { "type": "keyword", "script": "emit('x')" } }
+        }));
+
+        let paths: Vec<&str> = fields.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["message", "message.raw", "user", "user.id", "day"]);
+
+        assert_eq!(fields[0].analyzer.as_deref(), Some("english"));
+        assert_eq!(fields[0].doc_values, Some(false));
+        assert!(fields[1].is_multi_field);
+        assert_eq!(fields[1].details, vec!["ignore_above: 256"]);
+        assert!(fields[2].is_container());
+        assert_eq!(fields[3].depth, 1);
+        assert_eq!(fields[3].doc_values, Some(false));
+        assert!(fields[4].is_runtime);
+        assert_eq!(fields[4].doc_values, None);
+        assert_eq!(fields[4].index, None);
+    }
+
+    #[test]
+    fn test_parse_mapping_fields_with_type_name() {
+        let fields = parse_mapping_fields(&json!({ "_doc": { "properties": { "a": { "type": "keyword" } } } }));
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field_type, "keyword");
+    }
+
+    #[test]
+    fn test_parse_field_caps_conflicts() {
+        let entries = parse_field_caps(&json!({
+            "indices": ["logs-1", "logs-2"],
+            "fields": {
+                "_id": { "_id": { "type": "_id", "searchable": true, "aggregatable": false } },
+                "status": {
+                    "keyword": { "type": "keyword", "searchable": true, "aggregatable": true, "indices": ["logs-1"] },
+                    "long": { "type": "long", "searchable": true, "aggregatable": true, "indices": ["logs-2"] }
+                },
+                "host": { "keyword": { "type": "keyword", "searchable": true, "aggregatable": true } }
+            }
+        }));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "host");
+        assert!(!entries[0].is_conflict());
+        assert!(entries[1].is_conflict());
+        assert_eq!(entries[1].types_label(), "keyword / long");
+        assert_eq!(entries[1].types[0].indices, vec!["logs-1"]);
+    }
+
+    #[test]
+    fn test_check_mapping_update() {
+        let root = json!({
+            "properties": {
+                "message": { "type": "text", "analyzer": "english" },
+                "status": { "type": "keyword" },
+                "user": { "properties": { "id": { "type": "long" } } }
+            }
+        });
+
+        // Nový field i do existujícího objektu
+        assert!(check_mapping_update(&root, "user.email", &json!({"type": "keyword"})).is_empty());
+        // Změna typu
+        assert_eq!(check_mapping_update(&root, "status", &json!({"type": "long"})).len(), 1);
+        // Změna analyzeru
+        assert_eq!(check_mapping_update(&root, "message", &json!({"type": "text", "analyzer": "standard"})).len(), 1);
+        // Přidání multi-fieldu je povolené
+        assert!(check_mapping_update(&root, "message", &json!({"type": "text", "fields": {"raw": {"type": "keyword"}}})).is_empty());
+        // Field pod keyword fieldem
+        assert_eq!(check_mapping_update(&root, "status.code", &json!({"type": "keyword"})).len(), 1);
+    }
+
+    #[test]
+    fn test_build_mapping_update_body() {
+        let root = json!({
+            "properties": {
+                "events": { "type": "nested", "properties": { "name": { "type": "keyword" } } },
+                "message": { "type": "text", "analyzer": "english" }
+            }
+        });
+
+        let body = build_mapping_update_body(&root, "events.code", &json!({"type": "long"}));
+        assert_eq!(body, json!({
+            "properties": { "events": { "type": "nested", "properties": { "code": { "type": "long" } } } }
+        }));
+
+        let body = build_mapping_update_body(&root, "message", &json!({"type": "text", "fields": {"raw": {"type": "keyword"}}}));
+        assert_eq!(body["properties"]["message"]["analyzer"], "english");
+        assert_eq!(body["properties"]["message"]["fields"]["raw"]["type"], "keyword");
+    }
+
+    #[test]
+    fn test_build_field_definition() {
+        let req = MappingFieldRequest {
+            path: "title".to_string(),
+            field_type: "keyword".to_string(),
+            analyzer: "english".to_string(),
+            search_analyzer: String::new(),
+            index: true,
+            doc_values: false,
+            sub_fields: Vec::new(),
+            extra: String::new(),
+        };
+        assert!(build_field_definition(&req).is_err());
+
+        let req = MappingFieldRequest { analyzer: String::new(), extra: r#"{"ignore_above": 256}"#.to_string(), ..req };
+        let def = build_field_definition(&req).unwrap();
+        assert_eq!(def, json!({"type": "keyword", "doc_values": false, "ignore_above": 256}));
+    }
+
+    #[test]
+    fn test_build_reindex_mapping() {
+        let root = json!({
+            "dynamic": "strict",
+            "properties": {
+                "status": { "type": "text" },
+                "user": { "type": "nested", "properties": { "id": { "type": "long" } } }
+            }
+        });
+        let mapping = build_reindex_mapping(&root, "status", &json!({ "type": "keyword" }));
+        assert_eq!(mapping["properties"]["status"], json!({ "type": "keyword" }));
+        assert_eq!(mapping["dynamic"], "strict");
+        assert_eq!(mapping["properties"]["user"]["properties"]["id"]["type"], "long");
+
+        let mapping = build_reindex_mapping(&root, "user.name", &json!({ "type": "keyword" }));
+        assert_eq!(mapping["properties"]["user"]["type"], "nested");
+        assert_eq!(mapping["properties"]["user"]["properties"]["name"]["type"], "keyword");
+
+        let hint = reindex_hint("logs", &json!({ "mappings": mapping }));
+        assert_eq!(hint.target_index, "logs-v2");
+        assert!(hint.create_body.contains("\"mappings\""));
+    }
+
+    #[test]
+    fn test_suggest_reindex_target() {
+        assert_eq!(suggest_reindex_target("logs"), "logs-v2");
+        assert_eq!(suggest_reindex_target("logs-v3"), "logs-v4");
+    }
+}
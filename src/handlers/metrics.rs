@@ -0,0 +1,104 @@
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint};
+use crate::db::models::MetricPoint;
+use crate::sampler::{CLUSTER_NODE_ID, TIERS};
+
+#[derive(Debug, Deserialize)]
+pub struct MetricsHistoryQuery {
+    pub node: Option<String>,
+    pub window: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricsHistory {
+    pub node: String,
+    pub window: String,
+    pub resolution: i64,
+    pub points: Vec<MetricPoint>,
+}
+
+/// Délka okna a rozlišení, ze kterého se čte (15m, 1h, 24h, 7d)
+fn window_spec(window: &str) -> Option<(i64, i64)> {
+    match window {
+        "15m" => Some((15 * 60, 30)),
+        "1h" => Some((3600, 30)),
+        "24h" => Some((86400, 300)),
+        "7d" => Some((7 * 86400, 3600)),
+        _ => None,
+    }
+}
+
+/// Rozlišení od zvoleného po nejjemnější - konec okna se doplní z jemnějších úrovní,
+/// které ještě nebyly agregovány
+fn resolutions_from(resolution: i64) -> Vec<i64> {
+    TIERS.iter()
+        .map(|(r, _)| *r)
+        .filter(|r| *r <= resolution)
+        .rev()
+        .collect()
+}
+
+/// GET /metrics/history - Historie metrik nodu nebo clusteru (`node=_cluster`) jako JSON
+pub async fn metrics_history(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<MetricsHistoryQuery>,
+) -> Result<Json<MetricsHistory>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let node = query.node.unwrap_or_else(|| CLUSTER_NODE_ID.to_string());
+    let window = query.window.unwrap_or_else(|| "1h".to_string());
+
+    let Some((duration, resolution)) = window_spec(&window) else {
+        return Err((StatusCode::BAD_REQUEST, format!("Unknown window: {}", window)));
+    };
+
+    let mut cursor = chrono::Utc::now().timestamp() - duration;
+    let mut points: Vec<MetricPoint> = Vec::new();
+
+    for tier in resolutions_from(resolution) {
+        let tier_points = state.db.get_metric_points(endpoint.id, &node, tier, cursor).await
+            .map_err(|e| {
+                tracing::error!("Failed to load metrics history: {}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+            })?;
+
+        if let Some(last) = tier_points.last() {
+            cursor = last.ts + tier;
+        }
+        points.extend(tier_points);
+    }
+
+    Ok(Json(MetricsHistory { node, window, resolution, points }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maps_windows_to_resolutions() {
+        assert_eq!(window_spec("15m"), Some((900, 30)));
+        assert_eq!(window_spec("7d"), Some((604800, 3600)));
+        assert_eq!(window_spec("2w"), None);
+    }
+
+    #[test]
+    fn test_fills_tail_from_finer_tiers() {
+        assert_eq!(resolutions_from(3600), vec![3600, 300, 30]);
+        assert_eq!(resolutions_from(30), vec![30]);
+    }
+}
@@ -1,10 +1,32 @@
 pub mod dashboard;
+pub mod diagnostics;
+pub mod alerts;
+pub mod advisor;
 pub mod endpoints;
+pub mod events;
 pub mod nodes;
+pub mod drain;
+pub mod node_diagnostics;
+pub mod metrics;
 pub mod indices;
+pub mod index_create;
+pub mod index_settings;
 pub mod search;
 pub mod shards;
+pub mod allocation;
+pub mod reroute;
+pub mod recovery;
+pub mod cluster_settings;
+pub mod mappings;
+pub mod aliases;
+pub mod templates;
+pub mod data_streams;
+pub mod ilm;
+pub mod snapshots;
+pub mod pipelines;
+pub mod analyze;
 pub mod console;
+pub mod tasks;
 
 use axum::{
     response::{IntoResponse, Redirect},
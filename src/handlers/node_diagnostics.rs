@@ -0,0 +1,334 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Html,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::templates::{NodeDiagnosticsTemplate, NodeHotThreadsTemplate};
+use crate::es::extract_error_reason;
+use crate::models::{BreakerRow, GcCollector, HotThread, HotThreadsReport, NodeDiagnostics, ThreadPoolRow};
+
+/// Povolené hodnoty pro picker v UI
+const HOT_THREADS_INTERVALS: [&str; 4] = ["500ms", "1s", "3s", "5s"];
+const HOT_THREADS_TYPES: [&str; 3] = ["cpu", "wait", "block"];
+const HOT_THREADS_MAX: u32 = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct HotThreadsQuery {
+    pub interval: Option<String>,
+    pub threads: Option<u32>,
+    #[serde(rename = "type")]
+    pub thread_type: Option<String>,
+    pub ignore_idle: Option<bool>,
+}
+
+impl HotThreadsQuery {
+    /// Parametry pro `_nodes/{id}/hot_threads` (neznámé hodnoty nahradí výchozími)
+    fn to_query_string(&self) -> String {
+        let interval = self.interval.as_deref()
+            .filter(|i| HOT_THREADS_INTERVALS.contains(i))
+            .unwrap_or("500ms");
+        let thread_type = self.thread_type.as_deref()
+            .filter(|t| HOT_THREADS_TYPES.contains(t))
+            .unwrap_or("cpu");
+        let threads = self.threads.unwrap_or(3).clamp(1, HOT_THREADS_MAX);
+        let ignore_idle = self.ignore_idle.unwrap_or(true);
+        format!(
+            "interval={}&threads={}&type={}&ignore_idle_threads={}",
+            interval, threads, thread_type, ignore_idle
+        )
+    }
+}
+
+/// Hodnota z `_cat` API (čísla vrací jako stringy, u scaling poolů může chybět)
+fn cat_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn thread_pools_for_node(rows: &[Value], node_id: &str) -> Vec<ThreadPoolRow> {
+    let mut pools: Vec<ThreadPoolRow> = rows.iter()
+        .filter(|row| row["node_id"].as_str() == Some(node_id))
+        .map(|row| ThreadPoolRow {
+            name: row["name"].as_str().unwrap_or("-").to_string(),
+            pool_type: row["type"].as_str().unwrap_or("-").to_string(),
+            size: cat_u64(&row["size"]),
+            active: cat_u64(&row["active"]).unwrap_or(0),
+            queue: cat_u64(&row["queue"]).unwrap_or(0),
+            // "-1" se nenaparsuje jako u64 = neomezená fronta
+            queue_size: cat_u64(&row["queue_size"]),
+            rejected: cat_u64(&row["rejected"]).unwrap_or(0),
+            completed: cat_u64(&row["completed"]).unwrap_or(0),
+        })
+        .collect();
+    pools.sort_by(|a, b| a.name.cmp(&b.name));
+    pools
+}
+
+fn breakers_from_stats(stats: &Value) -> Vec<BreakerRow> {
+    let mut breakers: Vec<BreakerRow> = stats["breakers"].as_object()
+        .map(|breakers| breakers.iter().map(|(name, b)| BreakerRow {
+            name: name.clone(),
+            limit_bytes: b["limit_size_in_bytes"].as_u64().unwrap_or(0),
+            estimated_bytes: b["estimated_size_in_bytes"].as_u64().unwrap_or(0),
+            overhead: b["overhead"].as_f64().unwrap_or(1.0),
+            tripped: b["tripped"].as_u64().unwrap_or(0),
+        }).collect())
+        .unwrap_or_default();
+    // Parent breaker zastřešuje ostatní, zobrazí se první
+    breakers.sort_by(|a, b| (a.name != "parent", &a.name).cmp(&(b.name != "parent", &b.name)));
+    breakers
+}
+
+fn gc_from_stats(stats: &Value) -> Vec<GcCollector> {
+    let mut collectors: Vec<GcCollector> = stats["jvm"]["gc"]["collectors"].as_object()
+        .map(|collectors| collectors.iter().map(|(name, c)| GcCollector {
+            name: name.clone(),
+            collection_count: c["collection_count"].as_u64().unwrap_or(0),
+            collection_time_ms: c["collection_time_in_millis"].as_u64().unwrap_or(0),
+        }).collect())
+        .unwrap_or_default();
+    collectors.sort_by_key(|c| c.name != "young");
+    collectors
+}
+
+/// Rozdělí textový výstup hot threads na hlavičku a jednotlivá vlákna se stack tracem
+pub fn parse_hot_threads(raw: &str) -> HotThreadsReport {
+    let mut header = Vec::new();
+    let mut threads: Vec<HotThread> = Vec::new();
+
+    for line in raw.lines() {
+        let trimmed = line.trim();
+        if let Some(pos) = trimmed.find("usage by thread '") {
+            let name_start = pos + "usage by thread '".len();
+            let thread_name = trimmed[name_start..].trim_end_matches('\'').to_string();
+            let percent = trimmed.split('%').next()
+                .and_then(|p| p.trim().parse().ok())
+                .unwrap_or(0.0);
+            threads.push(HotThread {
+                percent,
+                summary: trimmed.to_string(),
+                thread_name,
+                stack: Vec::new(),
+            });
+        } else if let Some(thread) = threads.last_mut() {
+            thread.stack.push(line.trim_end().to_string());
+        } else if !trimmed.is_empty() {
+            header.push(trimmed.to_string());
+        }
+    }
+
+    // Odsazení stack trace zarovná k levému okraji, prázdné řádky na konci zahodí
+    for thread in threads.iter_mut() {
+        while thread.stack.last().is_some_and(|l| l.is_empty()) {
+            thread.stack.pop();
+        }
+        let indent = thread.stack.iter()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.len() - l.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        for line in thread.stack.iter_mut() {
+            *line = line.get(indent..).unwrap_or("").to_string();
+        }
+    }
+
+    HotThreadsReport {
+        header,
+        threads,
+        raw: raw.to_string(),
+    }
+}
+
+async fn load_node_diagnostics(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    node_id: &str,
+) -> anyhow::Result<NodeDiagnostics> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let stats_response: Value = client.get(&format!("/_nodes/{}/stats/jvm,breaker", node_id)).await?;
+    let stats = &stats_response["nodes"][node_id];
+    if stats.is_null() {
+        return Err(anyhow::anyhow!("Node not found"));
+    }
+
+    let pools: Vec<Value> = client.get(
+        "/_cat/thread_pool?format=json&h=node_id,name,type,size,active,queue,queue_size,rejected,completed"
+    ).await?;
+
+    Ok(NodeDiagnostics {
+        node_id: node_id.to_string(),
+        thread_pools: thread_pools_for_node(&pools, node_id),
+        breakers: breakers_from_stats(stats),
+        gc_collectors: gc_from_stats(stats),
+        jvm_uptime_ms: stats["jvm"]["uptime_in_millis"].as_u64().unwrap_or(0),
+    })
+}
+
+/// GET /nodes/{id}/diagnostics - Thread pooly, circuit breakery a GC nodu (partial)
+pub async fn node_diagnostics(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(node_id): Path<String>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let (data, error) = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_node_diagnostics(&state, endpoint, &node_id)
+    ).await {
+        Ok(Ok(d)) => (Some(d), None),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load node diagnostics: {}", e);
+            (None, Some(e.to_string()))
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading node diagnostics for: {}", node_id);
+            (None, Some("Timeout loading node diagnostics".to_string()))
+        }
+    };
+
+    let template = NodeDiagnosticsTemplate { data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_hot_threads(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    node_id: &str,
+    query: &HotThreadsQuery,
+) -> anyhow::Result<HotThreadsReport> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let path = format!("/_nodes/{}/hot_threads?{}", node_id, query.to_query_string());
+    let (status, body) = client.get_raw(&path).await?;
+    if status >= 400 {
+        return Err(anyhow::anyhow!(extract_error_reason(&body)));
+    }
+
+    Ok(parse_hot_threads(&body))
+}
+
+/// GET /nodes/{id}/hot-threads - Zachytí hot threads nodu (partial)
+pub async fn node_hot_threads(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(node_id): Path<String>,
+    Query(query): Query<HotThreadsQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let (report, error) = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_hot_threads(&state, endpoint, &node_id, &query)
+    ).await {
+        Ok(Ok(r)) => (Some(r), None),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load hot threads: {}", e);
+            (None, Some(e.to_string()))
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading hot threads for: {}", node_id);
+            (None, Some("Timeout loading hot threads".to_string()))
+        }
+    };
+
+    let template = NodeHotThreadsTemplate { report, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const HOT_THREADS: &str = "::: {node-1}{abc}{xyz}{127.0.0.1}{127.0.0.1:9300}
+   Hot threads at 2024-05-01T10:00:00.000Z, interval=500ms, busiestThreads=3, ignoreIdleThreads=true:
+
+   85.2% [cpu=85.2%, other=0.0%] (426ms out of 500ms) cpu usage by thread 'elasticsearch[node-1][search][T#3]'
+     2/10 snapshots sharing following 3 elements
+       org.apache.lucene.search.Weight.bulkScorer(Weight.java:100)
+       java.lang.Thread.run(Thread.java:833)
+
+    4.1% (20.5ms out of 500ms) cpu usage by thread 'elasticsearch[node-1][write][T#1]'
+     unique snapshot
+       java.lang.Thread.run(Thread.java:833)
+";
+
+    #[test]
+    fn test_parse_hot_threads() {
+        let report = parse_hot_threads(HOT_THREADS);
+        assert_eq!(report.header.len(), 2);
+        assert_eq!(report.threads.len(), 2);
+
+        let first = &report.threads[0];
+        assert_eq!(first.percent, 85.2);
+        assert_eq!(first.thread_name, "elasticsearch[node-1][search][T#3]");
+        assert_eq!(first.stack[0], "2/10 snapshots sharing following 3 elements");
+        assert_eq!(first.stack[1], "  org.apache.lucene.search.Weight.bulkScorer(Weight.java:100)");
+        assert_eq!(first.stack.len(), 3);
+
+        // Starší formát bez [cpu=..., other=...]
+        let second = &report.threads[1];
+        assert_eq!(second.percent, 4.1);
+        assert_eq!(second.thread_name, "elasticsearch[node-1][write][T#1]");
+        assert_eq!(second.stack, vec!["unique snapshot", "  java.lang.Thread.run(Thread.java:833)"]);
+    }
+
+    #[test]
+    fn test_hot_threads_query_sanitized() {
+        let query = HotThreadsQuery {
+            interval: Some("10m".to_string()),
+            threads: Some(500),
+            thread_type: Some("wait".to_string()),
+            ignore_idle: None,
+        };
+        assert_eq!(query.to_query_string(), "interval=500ms&threads=20&type=wait&ignore_idle_threads=true");
+    }
+
+    #[test]
+    fn test_thread_pools_for_node() {
+        let rows = vec![
+            json!({"node_id": "a", "name": "write", "type": "fixed", "size": "8", "active": "2", "queue": "5", "queue_size": "10000", "rejected": "3", "completed": "100"}),
+            json!({"node_id": "b", "name": "write", "type": "fixed", "size": "8", "active": "0", "queue": "0", "queue_size": "10000", "rejected": "0", "completed": "1"}),
+            json!({"node_id": "a", "name": "generic", "type": "scaling", "size": "", "active": "0", "queue": "0", "queue_size": "-1", "rejected": "0", "completed": "7"}),
+        ];
+        let pools = thread_pools_for_node(&rows, "a");
+        assert_eq!(pools.len(), 2);
+        assert_eq!(pools[0].name, "generic");
+        assert_eq!(pools[0].size, None);
+        assert_eq!(pools[0].queue_limit(), "∞");
+        assert!(!pools[0].is_busy());
+        assert_eq!(pools[1].queue, 5);
+        assert_eq!(pools[1].rejected, 3);
+        assert!(pools[1].is_busy());
+    }
+}
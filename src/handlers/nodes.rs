@@ -7,11 +7,12 @@ use axum_extra::extract::CookieJar;
 use std::sync::Arc;
 use askama::Template;
 use serde::Serialize;
+use serde_json::Value;
 
 use crate::handlers::endpoints::{AppState, get_active_endpoint};
 use crate::templates::{NodeDetailTemplate, PageContext};
 use crate::es::EsClient;
-use crate::models::NodeDetail;
+use crate::models::{DataPathUsage, NodeCacheStats, NodeDetail, NodeExtendedStats};
 
 #[derive(Debug, Serialize)]
 pub struct NodeMetrics {
@@ -115,21 +116,13 @@ async fn load_node_detail(
     // RAM
     let ram_used = stats["os"]["mem"]["used_in_bytes"].as_u64().unwrap_or(0);
     let ram_total = stats["os"]["mem"]["total_in_bytes"].as_u64().unwrap_or(0);
-    let ram_percent = if ram_total > 0 {
-        Some(((ram_used * 100) / ram_total) as u8)
-    } else {
-        None
-    };
+    let ram_percent = (ram_used * 100).checked_div(ram_total).map(|v| v as u8);
 
     // Disk
     let disk_available = stats["fs"]["total"]["available_in_bytes"].as_u64().unwrap_or(0);
     let disk_total = stats["fs"]["total"]["total_in_bytes"].as_u64().unwrap_or(0);
-    let disk_used = disk_total - disk_available;
-    let disk_percent = if disk_total > 0 {
-        Some(((disk_used * 100) / disk_total) as u8)
-    } else {
-        None
-    };
+    let disk_used = disk_total.saturating_sub(disk_available);
+    let disk_percent = (disk_used * 100).checked_div(disk_total).map(|v| v as u8);
 
     // Documents
     let docs_count = stats["indices"]["docs"]["count"].as_u64().unwrap_or(0);
@@ -160,9 +153,58 @@ async fn load_node_detail(
         docs_count,
         docs_deleted,
         store_size_bytes: store_size,
+        extended: parse_extended_stats(stats),
     })
 }
 
+fn cache_stats(name: &str, cache: &Value) -> NodeCacheStats {
+    NodeCacheStats {
+        name: name.to_string(),
+        memory_bytes: cache["memory_size_in_bytes"].as_u64().unwrap_or(0),
+        evictions: cache["evictions"].as_u64().unwrap_or(0),
+        hit_count: cache["hit_count"].as_u64(),
+        miss_count: cache["miss_count"].as_u64(),
+    }
+}
+
+/// Segmenty, cache, file deskriptory, síť a datové cesty z `_nodes/{id}/stats`
+fn parse_extended_stats(stats: &Value) -> NodeExtendedStats {
+    let indices = &stats["indices"];
+
+    let data_paths = stats["fs"]["data"].as_array()
+        .map(|paths| paths.iter().map(|p| DataPathUsage {
+            path: p["path"].as_str().unwrap_or("-").to_string(),
+            mount: p["mount"].as_str().unwrap_or("-").to_string(),
+            fs_type: p["type"].as_str().unwrap_or("-").to_string(),
+            total_bytes: p["total_in_bytes"].as_u64().unwrap_or(0),
+            available_bytes: p["available_in_bytes"].as_u64().unwrap_or(0),
+        }).collect())
+        .unwrap_or_default();
+
+    NodeExtendedStats {
+        index_total: indices["indexing"]["index_total"].as_u64().unwrap_or(0),
+        query_total: indices["search"]["query_total"].as_u64().unwrap_or(0),
+        query_time_ms: indices["search"]["query_time_in_millis"].as_u64().unwrap_or(0),
+        segments_count: indices["segments"]["count"].as_u64().unwrap_or(0),
+        segments_memory_bytes: indices["segments"]["memory_in_bytes"].as_u64(),
+        caches: vec![
+            cache_stats("fielddata", &indices["fielddata"]),
+            cache_stats("query_cache", &indices["query_cache"]),
+            cache_stats("request_cache", &indices["request_cache"]),
+        ],
+        open_file_descriptors: stats["process"]["open_file_descriptors"].as_u64(),
+        max_file_descriptors: stats["process"]["max_file_descriptors"].as_u64(),
+        http_current_open: stats["http"]["current_open"].as_u64().unwrap_or(0),
+        http_total_opened: stats["http"]["total_opened"].as_u64().unwrap_or(0),
+        transport_server_open: stats["transport"]["server_open"].as_u64().unwrap_or(0),
+        transport_rx_bytes: stats["transport"]["rx_size_in_bytes"].as_u64().unwrap_or(0),
+        transport_tx_bytes: stats["transport"]["tx_size_in_bytes"].as_u64().unwrap_or(0),
+        transport_rx_count: stats["transport"]["rx_count"].as_u64().unwrap_or(0),
+        transport_tx_count: stats["transport"]["tx_count"].as_u64().unwrap_or(0),
+        data_paths,
+    }
+}
+
 /// GET /nodes/{id}/metrics - Vrátí aktuální metriky nodu jako JSON
 pub async fn node_metrics(
     State(state): State<Arc<AppState>>,
@@ -232,21 +274,13 @@ async fn load_node_metrics(
     // RAM
     let ram_used = stats["os"]["mem"]["used_in_bytes"].as_u64().unwrap_or(0);
     let ram_total = stats["os"]["mem"]["total_in_bytes"].as_u64().unwrap_or(0);
-    let ram_percent = if ram_total > 0 {
-        Some(((ram_used * 100) / ram_total) as u8)
-    } else {
-        None
-    };
+    let ram_percent = (ram_used * 100).checked_div(ram_total).map(|v| v as u8);
 
     // Disk
     let disk_available = stats["fs"]["total"]["available_in_bytes"].as_u64().unwrap_or(0);
     let disk_total = stats["fs"]["total"]["total_in_bytes"].as_u64().unwrap_or(0);
-    let disk_used = disk_total - disk_available;
-    let disk_percent = if disk_total > 0 {
-        Some(((disk_used * 100) / disk_total) as u8)
-    } else {
-        None
-    };
+    let disk_used = disk_total.saturating_sub(disk_available);
+    let disk_percent = (disk_used * 100).checked_div(disk_total).map(|v| v as u8);
 
     Ok(NodeMetrics {
         cpu_percent,
@@ -255,3 +289,37 @@ async fn load_node_metrics(
         disk_percent,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_extended_stats() {
+        let stats = json!({
+            "indices": {
+                "indexing": { "index_total": 500 },
+                "search": { "query_total": 40, "query_time_in_millis": 200 },
+                "segments": { "count": 12, "memory_in_bytes": 0 },
+                "fielddata": { "memory_size_in_bytes": 1024, "evictions": 2 },
+                "query_cache": { "memory_size_in_bytes": 2048, "evictions": 0, "hit_count": 30, "miss_count": 10 },
+                "request_cache": { "memory_size_in_bytes": 0, "evictions": 0, "hit_count": 0, "miss_count": 0 }
+            },
+            "process": { "open_file_descriptors": 250, "max_file_descriptors": 1000 },
+            "http": { "current_open": 3, "total_opened": 90 },
+            "transport": { "server_open": 13, "rx_size_in_bytes": 100, "tx_size_in_bytes": 200 },
+            "fs": { "data": [{ "path": "/data/nodes/0", "mount": "/data (/dev/sda1)", "type": "ext4", "total_in_bytes": 400, "available_in_bytes": 100 }] }
+        });
+
+        let extended = parse_extended_stats(&stats);
+        assert_eq!(extended.avg_query_latency_ms(), Some(5));
+        assert_eq!(extended.segments_memory_formatted(), "-");
+        assert_eq!(extended.file_descriptors_percent(), Some(25));
+        assert_eq!(extended.caches[0].hit_ratio(), None);
+        assert_eq!(extended.caches[1].hit_ratio(), Some(75));
+        assert_eq!(extended.caches[2].hit_ratio(), None);
+        assert_eq!(extended.data_paths[0].used_percent(), 75);
+        assert_eq!(extended.transport_tx_bytes, 200);
+    }
+}
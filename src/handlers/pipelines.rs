@@ -0,0 +1,711 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{PageContext, PipelineEditorTemplate, PipelineSimulateTemplate, PipelinesTemplate};
+use crate::es::extract_error_reason;
+use crate::models::{
+    PipelineEditorData, PipelineSimulateData, PipelineSummary, PipelinesData, SimulateStep, SimulatedDoc,
+};
+
+/// Maximální počet vzorových dokumentů načtených z vyhledávání
+const MAX_SAMPLE_DOCS: usize = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct PipelinesQuery {
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub show_managed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PipelineEditorQuery {
+    #[serde(default)]
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SampleDocsQuery {
+    #[serde(default = "default_sample_size")]
+    pub size: usize,
+}
+
+fn default_sample_size() -> usize {
+    5
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct PipelineSaveRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub mode: String,                   // processors | json
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub processors: Vec<String>,
+    #[serde(default)]
+    pub on_failure: String,
+    #[serde(default)]
+    pub extra: String,
+    #[serde(default)]
+    pub raw: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PipelineSimulateRequest {
+    #[serde(flatten)]
+    pub pipeline: PipelineSaveRequest,
+    #[serde(default)]
+    pub docs: String,
+}
+
+#[derive(Serialize)]
+pub struct PipelineActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct SampleDocsResponse {
+    pub success: bool,
+    pub docs: String,                   // pretty JSON pole dokumentů
+    pub message: String,
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+/// Typ procesoru je jediný klíč objektu `{"set": {...}}`
+fn processor_type(processor: &Value) -> String {
+    processor.as_object()
+        .and_then(|p| p.keys().next().cloned())
+        .unwrap_or_else(|| "?".to_string())
+}
+
+/// Parsuje `GET /_ingest/pipeline`
+fn parse_pipelines(response: &Value) -> Vec<PipelineSummary> {
+    let mut pipelines: Vec<PipelineSummary> = response.as_object()
+        .map(|items| items.iter().map(|(id, p)| PipelineSummary {
+            id: id.clone(),
+            description: p["description"].as_str().unwrap_or("").to_string(),
+            processor_types: p["processors"].as_array()
+                .map(|items| items.iter().map(processor_type).collect())
+                .unwrap_or_default(),
+            on_failure: p["on_failure"].as_array().is_some_and(|items| !items.is_empty()),
+            version: p["version"].as_i64(),
+            managed: p["_meta"]["managed"].as_bool().unwrap_or(false),
+            deprecated: p["deprecated"].as_bool().unwrap_or(false),
+        }).collect())
+        .unwrap_or_default();
+    pipelines.sort_by(|a, b| a.id.cmp(&b.id));
+    pipelines
+}
+
+/// Převede definici pipeline do dat editoru
+fn pipeline_to_editor(id: &str, pipeline: &Value) -> PipelineEditorData {
+    let mut extra = pipeline.as_object().cloned().unwrap_or_default();
+    for key in ["description", "processors", "on_failure"] {
+        extra.remove(key);
+    }
+
+    PipelineEditorData {
+        id: id.to_string(),
+        is_new: false,
+        description: pipeline["description"].as_str().unwrap_or("").to_string(),
+        processors: pipeline["processors"].as_array()
+            .map(|items| items.iter().map(pretty).collect())
+            .unwrap_or_default(),
+        on_failure_json: pipeline.get("on_failure").map(pretty).unwrap_or_default(),
+        extra_json: if extra.is_empty() { String::new() } else { pretty(&Value::Object(extra)) },
+        raw_json: pretty(pipeline),
+    }
+}
+
+fn validate_pipeline_id(id: &str) -> Result<(), String> {
+    if id.is_empty() || id.starts_with('_') || id.contains([' ', ',', '*', '/', '\\', '"']) {
+        return Err(format!("Invalid pipeline id '{}'", id));
+    }
+    Ok(())
+}
+
+/// Procesor musí být objekt s právě jedním klíčem (typem procesoru)
+fn parse_processor(label: &str, raw: &str) -> Result<Value, String> {
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|e| format!("{} is not valid JSON: {}", label, e))?;
+    match value.as_object() {
+        Some(map) if map.len() == 1 && map.values().all(|v| v.is_object()) => Ok(value),
+        _ => Err(format!("{} must be an object with a single processor type, e.g. {{\"set\": {{...}}}}", label)),
+    }
+}
+
+fn parse_processor_list(label: &str, raw: &str) -> Result<Vec<Value>, String> {
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|e| format!("{} is not valid JSON: {}", label, e))?;
+    let items = value.as_array()
+        .ok_or_else(|| format!("{} must be a JSON array of processors", label))?;
+    items.iter().enumerate()
+        .map(|(i, p)| parse_processor(&format!("{} #{}", label, i + 1), &p.to_string()))
+        .collect()
+}
+
+/// Sestaví definici pipeline z editoru (seznam procesorů nebo raw JSON)
+fn build_pipeline_body(req: &PipelineSaveRequest) -> Result<Value, String> {
+    if req.mode == "json" {
+        let body: Value = serde_json::from_str(&req.raw)
+            .map_err(|e| format!("Pipeline is not valid JSON: {}", e))?;
+        let processors = body["processors"].as_array()
+            .ok_or_else(|| "Pipeline must contain a \"processors\" array".to_string())?;
+        for (i, p) in processors.iter().enumerate() {
+            parse_processor(&format!("Processor #{}", i + 1), &p.to_string())?;
+        }
+        return Ok(body);
+    }
+
+    let mut body = match req.extra.trim() {
+        "" => Map::new(),
+        raw => match serde_json::from_str::<Value>(raw) {
+            Ok(Value::Object(map)) => map,
+            Ok(_) => return Err("Other fields must be a JSON object".to_string()),
+            Err(e) => return Err(format!("Other fields are not valid JSON: {}", e)),
+        },
+    };
+
+    if !req.description.trim().is_empty() {
+        body.insert("description".to_string(), json!(req.description.trim()));
+    }
+
+    let processors = req.processors.iter()
+        .filter(|p| !p.trim().is_empty())
+        .enumerate()
+        .map(|(i, p)| parse_processor(&format!("Processor #{}", i + 1), p))
+        .collect::<Result<Vec<_>, _>>()?;
+    body.insert("processors".to_string(), Value::Array(processors));
+
+    if !req.on_failure.trim().is_empty() {
+        let on_failure = parse_processor_list("on_failure", &req.on_failure)?;
+        body.insert("on_failure".to_string(), Value::Array(on_failure));
+    }
+
+    Ok(Value::Object(body))
+}
+
+/// Vzorové dokumenty: pole hitů (`_index`, `_id`, `_source`) nebo přímo pole zdrojových dokumentů
+fn normalize_docs(raw: &str) -> Result<Vec<Value>, String> {
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|e| format!("Sample documents are not valid JSON: {}", e))?;
+    let items = match value {
+        Value::Array(items) => items,
+        Value::Object(_) => vec![value],
+        _ => return Err("Sample documents must be a JSON array of documents".to_string()),
+    };
+    if items.is_empty() {
+        return Err("Add at least one sample document".to_string());
+    }
+
+    items.into_iter().enumerate().map(|(i, item)| {
+        if !item.is_object() {
+            return Err(format!("Sample document #{} must be a JSON object", i + 1));
+        }
+        if item.get("_source").is_some() {
+            let mut doc = Map::new();
+            for key in ["_index", "_id", "_routing", "_source"] {
+                if let Some(v) = item.get(key) {
+                    doc.insert(key.to_string(), v.clone());
+                }
+            }
+            Ok(Value::Object(doc))
+        } else {
+            Ok(json!({ "_source": item }))
+        }
+    }).collect()
+}
+
+fn error_text(error: &Value) -> String {
+    let root = &error["root_cause"][0];
+    let source = if root.is_object() { root } else { error };
+    match (source["type"].as_str(), source["reason"].as_str()) {
+        (Some(t), Some(r)) => format!("{}: {}", t, r),
+        (None, Some(r)) => r.to_string(),
+        _ => error.to_string(),
+    }
+}
+
+/// Parsuje odpověď `_ingest/pipeline/_simulate?verbose=true`
+fn parse_simulate(response: &Value, docs: &[Value]) -> Vec<SimulatedDoc> {
+    response["docs"].as_array()
+        .map(|items| items.iter().enumerate().map(|(i, doc)| {
+            let input = docs.get(i);
+            let label = match (input.and_then(|d| d["_index"].as_str()), input.and_then(|d| d["_id"].as_str())) {
+                (Some(index), Some(id)) => format!("{}/{}", index, id),
+                _ => format!("Document #{}", i + 1),
+            };
+
+            let steps: Vec<SimulateStep> = doc["processor_results"].as_array()
+                .map(|results| results.iter().map(|r| {
+                    let error = r.get("error")
+                        .or_else(|| r["ignored_error"].get("error"))
+                        .map(error_text);
+                    SimulateStep {
+                        processor_type: r["processor_type"].as_str().unwrap_or("?").to_string(),
+                        tag: r["tag"].as_str().map(|t| t.to_string()),
+                        status: r["status"].as_str().unwrap_or("success").to_string(),
+                        condition: r.get("if").map(|c| format!(
+                            "{} → {}",
+                            c["condition"].as_str().unwrap_or(""),
+                            c["result"].as_bool().unwrap_or(false),
+                        )),
+                        error,
+                        doc_json: r["doc"].get("_source").map(pretty),
+                    }
+                }).collect())
+                .unwrap_or_default();
+
+            // Výsledek: non-verbose `doc` nebo poslední krok s dokumentem
+            let result_json = doc["doc"].get("_source").map(pretty)
+                .or_else(|| steps.iter().rev().find_map(|s| s.doc_json.clone()));
+            let error = doc.get("error").map(error_text)
+                .or_else(|| steps.iter().find(|s| s.status == "error").and_then(|s| s.error.clone()));
+
+            SimulatedDoc { label, steps, result_json, error }
+        }).collect())
+        .unwrap_or_default()
+}
+
+/// GET /pipelines - Seznam ingest pipelines
+pub async fn pipelines_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<PipelinesQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_pipelines_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load ingest pipelines: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading ingest pipelines");
+            error = Some("Timeout loading ingest pipelines".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = PipelinesTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_pipelines_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &PipelinesQuery,
+) -> anyhow::Result<PipelinesData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let response: Value = client.get("/_ingest/pipeline").await?;
+    let mut pipelines = parse_pipelines(&response);
+
+    let filter = query.filter.trim().to_string();
+    pipelines.retain(|p| {
+        if !query.show_managed && p.managed {
+            return false;
+        }
+        filter.is_empty()
+            || filter.split(',')
+                .map(|f| f.trim())
+                .filter(|f| !f.is_empty())
+                .any(|f| {
+                    let pattern = if f.contains('*') { f.to_string() } else { format!("*{}*", f) };
+                    matches_pattern(&p.id, &pattern)
+                })
+    });
+
+    Ok(PipelinesData {
+        pipelines,
+        filter,
+        show_managed: query.show_managed,
+    })
+}
+
+/// GET /pipelines/edit - Editor pipeline se simulací (bez `id` nová pipeline)
+pub async fn pipeline_editor_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<PipelineEditorQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let id = query.id.trim().to_string();
+    let mut error = None;
+
+    let data = if id.is_empty() {
+        Some(PipelineEditorData {
+            id: String::new(),
+            is_new: true,
+            description: String::new(),
+            processors: vec![pretty(&json!({ "set": { "field": "ingested_at", "value": "{{_ingest.timestamp}}" } }))],
+            on_failure_json: String::new(),
+            extra_json: String::new(),
+            raw_json: String::new(),
+        })
+    } else {
+        match tokio::time::timeout(
+            tokio::time::Duration::from_secs(10),
+            load_pipeline_editor_data(&state, endpoint, &id)
+        ).await {
+            Ok(Ok(d)) => Some(d),
+            Ok(Err(e)) => {
+                tracing::error!("Failed to load pipeline {}: {}", id, e);
+                error = Some(e.to_string());
+                None
+            }
+            Err(_) => {
+                tracing::error!("Timeout loading pipeline {}", id);
+                error = Some("Timeout loading pipeline".to_string());
+                None
+            }
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = PipelineEditorTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_pipeline_editor_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    id: &str,
+) -> anyhow::Result<PipelineEditorData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let (status, response) = client.get_raw(&format!("/_ingest/pipeline/{}", id)).await?;
+    if status == 404 {
+        anyhow::bail!("Pipeline '{}' not found", id);
+    }
+    if status >= 400 {
+        anyhow::bail!(extract_error_reason(&response));
+    }
+
+    let value: Value = serde_json::from_str(&response)?;
+    Ok(pipeline_to_editor(id, &value[id]))
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<PipelineActionResponse>) {
+    (status, Json(PipelineActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+/// POST /pipelines/save - Vytvoří nebo přepíše pipeline
+pub async fn save_pipeline(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<PipelineSaveRequest>,
+) -> Result<Json<PipelineActionResponse>, (StatusCode, Json<PipelineActionResponse>)> {
+    let id = req.id.trim();
+    validate_pipeline_id(id).map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+    let body = build_pipeline_body(&req).map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.put_raw(&format!("/_ingest/pipeline/{}", id), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Saved ingest pipeline {}", id);
+
+    Ok(Json(PipelineActionResponse {
+        success: true,
+        message: Some(format!("Pipeline {} uložena", id)),
+        error: None,
+    }))
+}
+
+/// POST /pipelines/{id}/delete - Smaže pipeline
+pub async fn delete_pipeline(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(id): Path<String>,
+) -> Result<Json<PipelineActionResponse>, (StatusCode, Json<PipelineActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.delete_raw(&format!("/_ingest/pipeline/{}", id)).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Deleted ingest pipeline {}", id);
+
+    Ok(Json(PipelineActionResponse {
+        success: true,
+        message: Some(format!("Pipeline {} smazána", id)),
+        error: None,
+    }))
+}
+
+/// GET /pipelines/sample-docs - Vzorové dokumenty z posledního vyhledávání na stránce Search
+pub async fn sample_docs(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<SampleDocsQuery>,
+) -> Result<Json<SampleDocsResponse>, (StatusCode, Json<PipelineActionResponse>)> {
+    let endpoint = get_active_endpoint(&state, &jar).await
+        .ok_or_else(|| error_response(StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()))?;
+
+    // Stejné cookies, do kterých si stránka Search ukládá poslední dotaz
+    let index_pattern = jar.get(&format!("search_index_pattern_{}", endpoint.id))
+        .map(|c| c.value().to_string())
+        .unwrap_or_default();
+    let search_query = jar.get(&format!("search_query_{}", endpoint.id))
+        .map(|c| c.value().to_string())
+        .filter(|q| !q.trim().is_empty())
+        .unwrap_or_else(|| "*".to_string());
+
+    if index_pattern.is_empty() || index_pattern == "*" {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Run a search on the Search page first".to_string()));
+    }
+
+    let client = create_es_client(&state, &endpoint, true).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to create ES client: {}", e)))?;
+
+    let body = json!({
+        "size": query.size.clamp(1, MAX_SAMPLE_DOCS),
+        "query": {
+            "query_string": {
+                "query": search_query,
+                "default_operator": "AND"
+            }
+        }
+    });
+
+    let (status, response) = client.post_raw(&format!("/{}/_search", index_pattern), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    let result: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+    let docs: Vec<Value> = result["hits"]["hits"].as_array()
+        .map(|hits| hits.iter().map(|h| json!({
+            "_index": h["_index"],
+            "_id": h["_id"],
+            "_source": h["_source"],
+        })).collect())
+        .unwrap_or_default();
+
+    Ok(Json(SampleDocsResponse {
+        success: true,
+        message: format!("{} dokumentů z {} (query: {})", docs.len(), index_pattern, search_query),
+        docs: pretty(&Value::Array(docs)),
+    }))
+}
+
+/// POST /pipelines/simulate - Spustí `_simulate?verbose` s neuloženou definicí z editoru (partial)
+pub async fn simulate_pipeline(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<PipelineSimulateRequest>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        run_simulation(&state, endpoint, &req)
+    ).await {
+        Ok(Ok(d)) => d,
+        Ok(Err(e)) => {
+            tracing::error!("Pipeline simulation failed: {}", e);
+            PipelineSimulateData { docs: Vec::new(), error: Some(e.to_string()) }
+        }
+        Err(_) => {
+            tracing::error!("Timeout simulating pipeline");
+            PipelineSimulateData { docs: Vec::new(), error: Some("Timeout simulating pipeline".to_string()) }
+        }
+    };
+
+    let template = PipelineSimulateTemplate { data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn run_simulation(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    req: &PipelineSimulateRequest,
+) -> anyhow::Result<PipelineSimulateData> {
+    let pipeline = build_pipeline_body(&req.pipeline).map_err(anyhow::Error::msg)?;
+    let docs = normalize_docs(&req.docs).map_err(anyhow::Error::msg)?;
+
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let body = json!({ "pipeline": pipeline, "docs": docs });
+    let (status, response) = client.post_raw("/_ingest/pipeline/_simulate?verbose=true", body).await?;
+
+    if status >= 400 {
+        return Ok(PipelineSimulateData { docs: Vec::new(), error: Some(extract_error_reason(&response)) });
+    }
+
+    let value: Value = serde_json::from_str(&response)?;
+    Ok(PipelineSimulateData { docs: parse_simulate(&value, &docs), error: None })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pipelines_and_editor() {
+        let response = json!({
+            "logs": {
+                "description": "Parse logs",
+                "processors": [{ "grok": { "field": "message", "patterns": ["%{COMMONAPACHELOG}"] } }, { "remove": { "field": "message" } }],
+                "on_failure": [{ "set": { "field": "error", "value": "{{ _ingest.on_failure_message }}" } }],
+                "version": 3
+            },
+            "xpack_monitoring_7": { "processors": [], "_meta": { "managed": true } }
+        });
+
+        let pipelines = parse_pipelines(&response);
+        assert_eq!(pipelines.len(), 2);
+        assert_eq!(pipelines[0].id, "logs");
+        assert_eq!(pipelines[0].processor_types, vec!["grok".to_string(), "remove".to_string()]);
+        assert!(pipelines[0].on_failure);
+        assert!(pipelines[1].managed);
+
+        let editor = pipeline_to_editor("logs", &response["logs"]);
+        assert_eq!(editor.processors.len(), 2);
+        assert!(editor.extra_json.contains("\"version\": 3"));
+        assert!(!editor.on_failure_json.is_empty());
+    }
+
+    #[test]
+    fn test_build_pipeline_body() {
+        let req = PipelineSaveRequest {
+            id: "logs".to_string(),
+            mode: "processors".to_string(),
+            description: "Parse".to_string(),
+            processors: vec![r#"{"lowercase": {"field": "level"}}"#.to_string(), "  ".to_string()],
+            extra: r#"{"version": 2}"#.to_string(),
+            ..Default::default()
+        };
+        let body = build_pipeline_body(&req).unwrap();
+        assert_eq!(body["description"], "Parse");
+        assert_eq!(body["processors"].as_array().unwrap().len(), 1);
+        assert_eq!(body["version"], 2);
+
+        let two_types = PipelineSaveRequest {
+            processors: vec![r#"{"set": {}, "remove": {}}"#.to_string()],
+            ..Default::default()
+        };
+        assert!(build_pipeline_body(&two_types).is_err());
+
+        let bad_on_failure = PipelineSaveRequest { on_failure: r#"{"set": {}}"#.to_string(), ..Default::default() };
+        assert!(build_pipeline_body(&bad_on_failure).is_err());
+
+        let json_mode = PipelineSaveRequest {
+            mode: "json".to_string(),
+            raw: r#"{"processors": [{"trim": {"field": "a"}}]}"#.to_string(),
+            ..Default::default()
+        };
+        assert!(build_pipeline_body(&json_mode).is_ok());
+        assert!(validate_pipeline_id("my pipeline").is_err());
+    }
+
+    #[test]
+    fn test_normalize_docs() {
+        let docs = normalize_docs(r#"[{"_index": "logs", "_id": "1", "_source": {"a": 1}, "_score": 2.0}, {"b": 2}]"#).unwrap();
+        assert_eq!(docs[0], json!({ "_index": "logs", "_id": "1", "_source": { "a": 1 } }));
+        assert_eq!(docs[1], json!({ "_source": { "b": 2 } }));
+
+        assert!(normalize_docs("[]").is_err());
+        assert!(normalize_docs("[1]").is_err());
+        assert_eq!(normalize_docs(r#"{"c": 3}"#).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_simulate_verbose() {
+        let docs = vec![json!({ "_index": "logs", "_id": "1", "_source": {} }), json!({ "_source": {} })];
+        let response = json!({
+            "docs": [
+                {
+                    "processor_results": [
+                        { "processor_type": "set", "status": "success", "doc": { "_source": { "level": "INFO" } } },
+                        { "processor_type": "lowercase", "status": "success", "if": { "condition": "ctx.level != null", "result": true }, "doc": { "_source": { "level": "info" } } }
+                    ]
+                },
+                {
+                    "processor_results": [
+                        { "processor_type": "grok", "status": "error_ignored", "ignored_error": { "error": { "root_cause": [{ "type": "illegal_argument_exception", "reason": "no match" }] } }, "doc": { "_source": {} } },
+                        { "processor_type": "date", "status": "error", "error": { "type": "illegal_argument_exception", "reason": "unable to parse date" } }
+                    ]
+                }
+            ]
+        });
+
+        let result = parse_simulate(&response, &docs);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].label, "logs/1");
+        assert_eq!(result[0].steps[1].condition.as_deref(), Some("ctx.level != null → true"));
+        assert!(result[0].result_json.as_deref().unwrap().contains("\"info\""));
+        assert!(result[0].error.is_none());
+
+        assert_eq!(result[1].label, "Document #2");
+        assert_eq!(result[1].steps[0].error.as_deref(), Some("illegal_argument_exception: no match"));
+        assert_eq!(result[1].steps[0].status_color(), "warning");
+        assert_eq!(result[1].error.as_deref(), Some("illegal_argument_exception: unable to parse date"));
+    }
+}
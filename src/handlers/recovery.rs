@@ -0,0 +1,217 @@
+use axum::{
+    extract::State,
+    response::Html,
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde_json::Value;
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::templates::{PageContext, RecoveryTableTemplate, RecoveryTemplate};
+use crate::es::EsClient;
+use crate::es::api::ClusterHealth;
+use crate::models::{RecoveryMonitorData, ShardRecovery};
+
+/// Indexy s aktivní recovery z `_cat/recovery?active_only` (bez duplicit)
+fn active_recovery_indices(response: &Value) -> Vec<String> {
+    let mut indices: Vec<String> = response.as_array()
+        .map(|rows| rows.iter()
+            .filter_map(|row| row["index"].as_str().map(|s| s.to_string()))
+            .collect())
+        .unwrap_or_default();
+    indices.sort();
+    indices.dedup();
+    indices
+}
+
+/// Parsuje `GET /_recovery` do seznamu shardů (index, shard)
+pub(crate) fn parse_recovery(response: &Value) -> Vec<ShardRecovery> {
+    let mut shards = Vec::new();
+    let Some(indices) = response.as_object() else {
+        return shards;
+    };
+
+    for (index, entry) in indices {
+        for s in entry["shards"].as_array().into_iter().flatten() {
+            let recovery_type = s["type"].as_str().unwrap_or("").to_string();
+            let source = if recovery_type == "SNAPSHOT" {
+                format!(
+                    "{}/{}",
+                    s["source"]["repository"].as_str().unwrap_or("?"),
+                    s["source"]["snapshot"].as_str().unwrap_or("?"),
+                )
+            } else {
+                s["source"]["name"].as_str().unwrap_or("").to_string()
+            };
+            let size = &s["index"]["size"];
+            let files = &s["index"]["files"];
+            let throttle = s["index"]["source_throttle_time_in_millis"].as_u64().unwrap_or(0)
+                + s["index"]["target_throttle_time_in_millis"].as_u64().unwrap_or(0);
+            let translog = s["translog"]["percent"].as_str()
+                .and_then(|p| p.trim_end_matches('%').parse().ok())
+                .unwrap_or(0.0);
+
+            shards.push(ShardRecovery {
+                index: index.clone(),
+                shard: s["id"].as_u64().unwrap_or(0),
+                primary: s["primary"].as_bool().unwrap_or(false),
+                recovery_type,
+                stage: s["stage"].as_str().unwrap_or("").to_string(),
+                source,
+                target_node: s["target"]["name"].as_str().unwrap_or("").to_string(),
+                bytes_total: size["total_in_bytes"].as_u64().unwrap_or(0),
+                bytes_recovered: size["recovered_in_bytes"].as_u64().unwrap_or(0),
+                files_total: files["total"].as_u64().unwrap_or(0),
+                files_recovered: files["recovered"].as_u64().unwrap_or(0),
+                translog_percent: translog,
+                total_time_ms: s["total_time_in_millis"].as_u64().unwrap_or(0),
+                throttle_time_ms: throttle,
+            });
+        }
+    }
+
+    shards.sort_by(|a, b| a.index.cmp(&b.index).then(a.shard.cmp(&b.shard)).then(b.primary.cmp(&a.primary)));
+    shards
+}
+
+/// GET /recovery - Monitor běžících recovery a relokací shardů
+pub async fn recovery_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    // Data načítá tabulka přes HTMX, během relokací ji obnovuje SSE událost `recovery`
+    let template = RecoveryTemplate { ctx: PageContext::new(active_endpoint) };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /recovery/table - Tabulka aktivních recovery (partial pro HTMX)
+pub async fn recovery_table(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_recovery_data(&state, endpoint)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load recovery: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading recovery");
+            None
+        }
+    };
+
+    let template = RecoveryTableTemplate { data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_recovery_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+) -> anyhow::Result<RecoveryMonitorData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    let health = client.cluster_health().await?;
+    load_recovery(&client, &health).await
+}
+
+/// Aktivní recovery pro tabulku i live kanál (`/events` je posílá během relokací)
+pub(crate) async fn load_recovery(client: &EsClient, health: &ClusterHealth) -> anyhow::Result<RecoveryMonitorData> {
+    // Levný `_cat` dotaz najde indexy s běžící recovery, detail (časy, throttling) jen pro ně
+    let active: Value = client.get("/_cat/recovery?active_only=true&format=json&h=index").await?;
+    let indices = active_recovery_indices(&active);
+
+    let shards = if indices.is_empty() {
+        Vec::new()
+    } else {
+        let path = format!("/{}/_recovery?active_only=true&expand_wildcards=all", indices.join(","));
+        let mut shards = parse_recovery(&client.get::<Value>(&path).await?);
+        shards.retain(|s| !s.is_done());
+        shards
+    };
+
+    Ok(RecoveryMonitorData {
+        shards,
+        initializing: health.initializing_shards,
+        relocating: health.relocating_shards,
+        unassigned: health.unassigned_shards,
+    })
+}
+
+/// Vyrenderovaná tabulka recovery pro SSE událost `recovery`
+pub(crate) fn render_recovery_table(data: RecoveryMonitorData) -> anyhow::Result<String> {
+    Ok(RecoveryTableTemplate { data: Some(data) }.render()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_active_recovery_indices() {
+        let response = json!([
+            { "index": "logs-b" },
+            { "index": "logs-a" },
+            { "index": "logs-b" }
+        ]);
+        assert_eq!(active_recovery_indices(&response), vec!["logs-a", "logs-b"]);
+        assert!(active_recovery_indices(&json!([])).is_empty());
+    }
+
+    #[test]
+    fn test_recovery_eta() {
+        let response = json!({
+            "logs": { "shards": [{
+                "id": 0,
+                "type": "PEER",
+                "stage": "INDEX",
+                "primary": false,
+                "total_time_in_millis": 10_000,
+                "source": { "name": "node-1" },
+                "target": { "name": "node-2" },
+                "index": {
+                    "size": { "total_in_bytes": 3_000_000, "recovered_in_bytes": 1_000_000 },
+                    "files": { "total": 10, "recovered": 5 },
+                    "source_throttle_time_in_millis": 100,
+                    "target_throttle_time_in_millis": 50
+                },
+                "translog": { "percent": "0.0%" }
+            }] }
+        });
+        let shards = parse_recovery(&response);
+        assert_eq!(shards[0].source, "node-1");
+        assert_eq!(shards[0].throttle_time_ms, 150);
+        assert_eq!(shards[0].throughput() as u64, 100_000);
+        assert_eq!(shards[0].eta_ms(), Some(20_000));
+        assert_eq!(shards[0].files_percent_formatted(), "50.0");
+
+        let data = RecoveryMonitorData { shards, initializing: 1, relocating: 2, unassigned: 0 };
+        assert_eq!(data.eta_ms(), Some(20_000));
+        assert_eq!(data.queued(), 2);
+    }
+}
@@ -0,0 +1,376 @@
+use axum::{
+    extract::State,
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::templates::ReroutePreviewTemplate;
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{AllocationDecider, ReroutePreviewData, RerouteExplanation, RoutingEntry};
+
+/// Jeden příkaz pro `_cluster/reroute`
+#[derive(Debug, Deserialize, Clone)]
+pub struct RerouteCommand {
+    pub action: String,                 // move | cancel | allocate_replica
+    pub index: String,
+    pub shard: u64,
+    #[serde(default)]
+    pub from_node: String,
+    #[serde(default)]
+    pub to_node: String,
+    #[serde(default)]
+    pub node: String,
+    #[serde(default)]
+    pub allow_primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RerouteRequest {
+    #[serde(default)]
+    pub commands: Vec<RerouteCommand>,
+    #[serde(default)]
+    pub retry_failed: bool,
+}
+
+#[derive(Serialize)]
+pub struct RerouteActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn required<'a>(value: &'a str, name: &str) -> Result<&'a str, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{} is required", name));
+    }
+    Ok(value)
+}
+
+/// Převede příkazy z UI na reroute commands; `legacy` = ES < 5.0 (jen `allocate`)
+fn build_reroute_commands(commands: &[RerouteCommand], legacy: bool) -> Result<Vec<Value>, String> {
+    commands.iter().map(|c| {
+        let index = required(&c.index, "Index")?;
+        match c.action.as_str() {
+            "move" => {
+                let from_node = required(&c.from_node, "Source node")?;
+                let to_node = required(&c.to_node, "Target node")?;
+                if from_node == to_node {
+                    return Err(format!("Shard {}[{}] is already on {}", index, c.shard, to_node));
+                }
+                Ok(json!({ "move": {
+                    "index": index, "shard": c.shard, "from_node": from_node, "to_node": to_node,
+                }}))
+            }
+            "cancel" => {
+                let node = required(&c.node, "Node")?;
+                Ok(json!({ "cancel": {
+                    "index": index, "shard": c.shard, "node": node, "allow_primary": c.allow_primary,
+                }}))
+            }
+            "allocate_replica" => {
+                let node = required(&c.node, "Node")?;
+                let command = if legacy { "allocate" } else { "allocate_replica" };
+                Ok(json!({ command: { "index": index, "shard": c.shard, "node": node } }))
+            }
+            other => Err(format!("Unknown reroute action '{}'", other)),
+        }
+    }).collect()
+}
+
+fn reroute_path(retry_failed: bool, dry_run: bool) -> String {
+    let mut params = Vec::new();
+    if retry_failed {
+        params.push("retry_failed=true");
+    }
+    if dry_run {
+        params.push("dry_run=true");
+        params.push("explain=true");
+        params.push("metric=routing_table,nodes");
+    }
+    if params.is_empty() {
+        "/_cluster/reroute".to_string()
+    } else {
+        format!("/_cluster/reroute?{}", params.join("&"))
+    }
+}
+
+/// Parsuje routing tabulku z cluster state (id nodů překládá na názvy)
+fn parse_routing_table(state: &Value) -> Vec<RoutingEntry> {
+    let node_names: HashMap<&str, &str> = state["nodes"].as_object()
+        .map(|nodes| nodes.iter()
+            .filter_map(|(id, node)| node["name"].as_str().map(|name| (id.as_str(), name)))
+            .collect())
+        .unwrap_or_default();
+    let node_name = |v: &Value| v.as_str().map(|id| node_names.get(id).copied().unwrap_or(id).to_string());
+
+    let mut entries = Vec::new();
+    if let Some(indices) = state["routing_table"]["indices"].as_object() {
+        for (index, table) in indices {
+            let Some(shards) = table["shards"].as_object() else { continue };
+            for copies in shards.values() {
+                for copy in copies.as_array().into_iter().flatten() {
+                    entries.push(RoutingEntry {
+                        index: index.clone(),
+                        shard: copy["shard"].as_u64().unwrap_or(0),
+                        primary: copy["primary"].as_bool().unwrap_or(false),
+                        state: copy["state"].as_str().unwrap_or("").to_string(),
+                        node: node_name(&copy["node"]),
+                        relocating_node: node_name(&copy["relocating_node"]),
+                        changed: false,
+                    });
+                }
+            }
+        }
+    }
+
+    entries.sort_by(|a, b| a.index.cmp(&b.index)
+        .then(a.shard.cmp(&b.shard))
+        .then(b.primary.cmp(&a.primary))
+        .then(a.node.cmp(&b.node)));
+    entries
+}
+
+/// Klíč kopie shardu pro porovnání routing tabulek
+fn routing_key(entry: &RoutingEntry) -> (&str, u64, bool, &str, Option<&str>, Option<&str>) {
+    (
+        entry.index.as_str(),
+        entry.shard,
+        entry.primary,
+        entry.state.as_str(),
+        entry.node.as_deref(),
+        entry.relocating_node.as_deref(),
+    )
+}
+
+/// Označí kopie, které v aktuálním stavu neexistují; vrací jejich počet
+fn mark_changes(after: &mut [RoutingEntry], before: &[RoutingEntry]) -> usize {
+    // Bez filtru indexů (jen retry_failed) jde o celou routing tabulku clusteru
+    let current: HashSet<_> = before.iter().map(routing_key).collect();
+
+    for entry in after.iter_mut() {
+        entry.changed = !current.contains(&routing_key(entry));
+    }
+    after.iter().filter(|e| e.changed).count()
+}
+
+fn parse_explanations(response: &Value) -> Vec<RerouteExplanation> {
+    response["explanations"].as_array()
+        .map(|items| items.iter().map(|e| RerouteExplanation {
+            command: e["command"].as_str().unwrap_or("").to_string(),
+            parameters: e["parameters"].as_object()
+                .map(|p| p.iter()
+                    .map(|(k, v)| format!("{}={}", k, v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string())))
+                    .collect::<Vec<_>>()
+                    .join(", "))
+                .unwrap_or_default(),
+            decisions: e["decisions"].as_array()
+                .map(|decisions| decisions.iter().map(|d| AllocationDecider {
+                    decider: d["decider"].as_str().unwrap_or("").to_string(),
+                    decision: d["decision"].as_str().unwrap_or("").to_string(),
+                    explanation: d["explanation"].as_str().unwrap_or("").to_string(),
+                }).collect())
+                .unwrap_or_default(),
+        }).collect())
+        .unwrap_or_default()
+}
+
+fn is_legacy(client: &EsClient) -> bool {
+    client.version().map(|v| !v.at_least(5, 0)).unwrap_or(false)
+}
+
+/// POST /shards/reroute/preview - Dry run reroute a výsledná routing tabulka (partial)
+pub async fn preview_reroute(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<RerouteRequest>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        run_preview(&state, endpoint, &req)
+    ).await {
+        Ok(Ok(d)) => d,
+        Ok(Err(e)) => {
+            tracing::error!("Reroute dry run failed: {}", e);
+            ReroutePreviewData { error: Some(e.to_string()), ..Default::default() }
+        }
+        Err(_) => {
+            tracing::error!("Timeout running reroute dry run");
+            ReroutePreviewData { error: Some("Timeout running reroute dry run".to_string()), ..Default::default() }
+        }
+    };
+
+    let template = ReroutePreviewTemplate { data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn run_preview(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    req: &RerouteRequest,
+) -> anyhow::Result<ReroutePreviewData> {
+    if req.commands.is_empty() && !req.retry_failed {
+        anyhow::bail!("Nothing to reroute");
+    }
+
+    let client = create_es_client(state, endpoint, true).await?;
+    let commands = build_reroute_commands(&req.commands, is_legacy(&client)).map_err(anyhow::Error::msg)?;
+
+    // Aktuální routing jen dotčených indexů; u samotného retry_failed celý cluster
+    let mut indices: Vec<&str> = req.commands.iter().map(|c| c.index.trim()).collect();
+    indices.sort();
+    indices.dedup();
+    let state_path = if indices.is_empty() {
+        "/_cluster/state/routing_table,nodes".to_string()
+    } else {
+        format!("/_cluster/state/routing_table,nodes/{}", indices.join(","))
+    };
+    let before = parse_routing_table(&client.get::<Value>(&state_path).await?);
+
+    let (status, response) = client
+        .post_raw(&reroute_path(req.retry_failed, true), json!({ "commands": commands }))
+        .await?;
+    if status >= 400 {
+        return Ok(ReroutePreviewData { error: Some(extract_error_reason(&response)), ..Default::default() });
+    }
+
+    let value: Value = serde_json::from_str(&response)?;
+    let mut routing = parse_routing_table(&value["state"]);
+    if !indices.is_empty() {
+        routing.retain(|e| indices.contains(&e.index.as_str()));
+    }
+    let changed = mark_changes(&mut routing, &before);
+    if indices.is_empty() {
+        routing.retain(|e| e.changed);
+    }
+
+    Ok(ReroutePreviewData {
+        explanations: parse_explanations(&value),
+        routing,
+        changed,
+        error: None,
+    })
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<RerouteActionResponse>) {
+    (status, Json(RerouteActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+/// POST /shards/reroute - Provede reroute příkazy (move, cancel, allocate replica, retry failed)
+pub async fn execute_reroute(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Json(req): Json<RerouteRequest>,
+) -> Result<Json<RerouteActionResponse>, (StatusCode, Json<RerouteActionResponse>)> {
+    if req.commands.is_empty() && !req.retry_failed {
+        return Err(error_response(StatusCode::BAD_REQUEST, "Nothing to reroute".to_string()));
+    }
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let commands = build_reroute_commands(&req.commands, is_legacy(&client))
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (status, response) = client
+        .post_raw(&reroute_path(req.retry_failed, false), json!({ "commands": commands }))
+        .await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Executed reroute: {} commands, retry_failed={}", commands.len(), req.retry_failed);
+
+    let message = match (commands.len(), req.retry_failed) {
+        (0, _) => "Opakování selhaných alokací spuštěno".to_string(),
+        (n, false) => format!("Reroute proveden ({} příkazů)", n),
+        (n, true) => format!("Reroute proveden ({} příkazů) včetně opakování selhaných alokací", n),
+    };
+
+    Ok(Json(RerouteActionResponse {
+        success: true,
+        message: Some(message),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(action: &str) -> RerouteCommand {
+        RerouteCommand {
+            action: action.to_string(),
+            index: "logs".to_string(),
+            shard: 0,
+            from_node: "node-1".to_string(),
+            to_node: "node-2".to_string(),
+            node: "node-2".to_string(),
+            allow_primary: false,
+        }
+    }
+
+    #[test]
+    fn test_build_reroute_commands() {
+        let commands = build_reroute_commands(&[command("move"), command("cancel")], false).unwrap();
+        assert_eq!(commands[0]["move"]["to_node"], "node-2");
+        assert_eq!(commands[1]["cancel"]["allow_primary"], false);
+
+        let commands = build_reroute_commands(&[command("allocate_replica")], false).unwrap();
+        assert!(commands[0].get("allocate_replica").is_some());
+        let commands = build_reroute_commands(&[command("allocate_replica")], true).unwrap();
+        assert!(commands[0].get("allocate").is_some());
+
+        let mut same_node = command("move");
+        same_node.to_node = "node-1".to_string();
+        assert!(build_reroute_commands(&[same_node], false).is_err());
+        assert!(build_reroute_commands(&[command("explode")], false).is_err());
+    }
+
+    #[test]
+    fn test_reroute_path() {
+        assert_eq!(reroute_path(false, false), "/_cluster/reroute");
+        assert_eq!(reroute_path(true, false), "/_cluster/reroute?retry_failed=true");
+        assert!(reroute_path(false, true).contains("dry_run=true"));
+    }
+
+    #[test]
+    fn test_routing_table_changes() {
+        let state = |node: &str, relocating: Value, shard_state: &str| json!({
+            "nodes": { "id1": { "name": "node-1" }, "id2": { "name": "node-2" } },
+            "routing_table": { "indices": { "logs": { "shards": { "0": [
+                { "index": "logs", "shard": 0, "primary": true, "state": shard_state, "node": node, "relocating_node": relocating }
+            ] } } } }
+        });
+
+        let before = parse_routing_table(&state("id1", Value::Null, "STARTED"));
+        assert_eq!(before[0].node.as_deref(), Some("node-1"));
+
+        let mut after = parse_routing_table(&state("id1", json!("id2"), "RELOCATING"));
+        assert_eq!(after[0].relocating_node.as_deref(), Some("node-2"));
+        assert_eq!(mark_changes(&mut after, &before), 1);
+        assert!(after[0].changed);
+    }
+}
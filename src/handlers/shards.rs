@@ -53,6 +53,7 @@ pub struct ShardStats {
     pub replica: usize,
     pub started: usize,
     pub relocating: usize,
+    pub initializing: usize,
     pub unassigned: usize,
 }
 
@@ -72,6 +73,20 @@ impl ShardsData {
         get_text_color_for_background(bg_color)
     }
 
+    /// Názvy skutečných nodů (bez UNASSIGNED a relokačních "a -> b" skupin) pro výběr cíle reroute
+    pub fn node_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.nodes.iter()
+            .map(|n| n.node_name.clone())
+            .filter(|n| n != "UNASSIGNED" && !n.contains(" -> "))
+            .collect();
+        names.sort();
+        names
+    }
+
+    pub fn node_names_json(&self) -> String {
+        serde_json::to_string(&self.node_names()).unwrap_or_else(|_| "[]".to_string())
+    }
+
     /// Vrací všechny shardy jako JSON string pro JavaScript
     pub fn all_shards_json(&self) -> String {
         serde_json::to_string(&self.all_shards).unwrap_or_else(|_| "[]".to_string())
@@ -79,7 +94,7 @@ impl ShardsData {
 }
 
 /// Porovná index name s pattern (podporuje * wildcard)
-fn matches_pattern(index_name: &str, pattern: &str) -> bool {
+pub(crate) fn matches_pattern(index_name: &str, pattern: &str) -> bool {
     // Jednoduchá wildcard matching funkce
     // Podporuje * jako libovolný počet znaků
 
@@ -180,7 +195,7 @@ pub async fn shards_page(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
-async fn load_shards_data(
+pub(crate) async fn load_shards_data(
     state: &AppState,
     endpoint: &crate::db::models::Endpoint,
     pattern: &str,
@@ -251,6 +266,7 @@ async fn load_shards_data(
         replica: shards.iter().filter(|s| s.prirep == "r").count(),
         started: shards.iter().filter(|s| s.state == "STARTED").count(),
         relocating: shards.iter().filter(|s| s.state == "RELOCATING").count(),
+        initializing: shards.iter().filter(|s| s.state == "INITIALIZING").count(),
         unassigned: shards.iter().filter(|s| s.state == "UNASSIGNED").count(),
     };
 
@@ -0,0 +1,745 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::recovery::parse_recovery;
+use crate::templates::{PageContext, SnapshotRestoreTemplate, SnapshotRecoveryTableTemplate, SnapshotsTemplate};
+use crate::es::extract_error_reason;
+use crate::models::{
+    RestoreProgressData, ShardRecovery, SlmPolicyInfo, SnapshotInfo, SnapshotRepository, SnapshotsData,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SnapshotsQuery {
+    #[serde(default)]
+    pub repository: String,
+    #[serde(default)]
+    pub snapshot_indices: String,
+    #[serde(default)]
+    pub tab: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSnapshotRequest {
+    pub snapshot: String,
+    #[serde(default)]
+    pub indices: String,
+    #[serde(default)]
+    pub include_global_state: bool,
+    #[serde(default)]
+    pub partial: bool,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct RestoreSnapshotRequest {
+    #[serde(default)]
+    pub indices: String,
+    #[serde(default)]
+    pub rename_pattern: String,
+    #[serde(default)]
+    pub rename_replacement: String,
+    #[serde(default)]
+    pub index_settings: String,
+    #[serde(default)]
+    pub ignore_index_settings: String,
+    #[serde(default)]
+    pub include_global_state: bool,
+    #[serde(default)]
+    pub include_aliases: bool,
+    #[serde(default)]
+    pub partial: bool,
+}
+
+#[derive(Serialize)]
+pub struct SnapshotActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Čárkou oddělený seznam → neprázdné položky
+fn split_list(value: &str) -> Vec<String> {
+    value.split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn format_millis(millis: Option<i64>) -> Option<String> {
+    millis
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+/// Parsuje `GET /_snapshot/_all` do seznamu repository
+fn parse_repositories(response: &Value) -> Vec<SnapshotRepository> {
+    let mut repositories: Vec<SnapshotRepository> = response.as_object()
+        .map(|items| items.iter().map(|(name, repo)| {
+            let settings = &repo["settings"];
+            let location = ["location", "bucket", "container", "url", "path"].iter()
+                .filter_map(|key| settings[*key].as_str())
+                .collect::<Vec<_>>()
+                .join(" / ");
+            SnapshotRepository {
+                name: name.clone(),
+                repo_type: repo["type"].as_str().unwrap_or("").to_string(),
+                location,
+                settings: serde_json::to_string_pretty(settings).unwrap_or_default(),
+            }
+        }).collect())
+        .unwrap_or_default();
+    repositories.sort_by(|a, b| a.name.cmp(&b.name));
+    repositories
+}
+
+/// Parsuje `GET /_snapshot/{repo}/_all`, nejnovější snapshoty první
+fn parse_snapshots(response: &Value, repository: &str) -> Vec<SnapshotInfo> {
+    let strings = |value: &Value| -> Vec<String> {
+        value.as_array()
+            .map(|items| items.iter().filter_map(|i| i.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default()
+    };
+
+    let mut snapshots: Vec<SnapshotInfo> = response["snapshots"].as_array()
+        .map(|items| items.iter().map(|s| {
+            let start = s["start_time_in_millis"].as_i64().unwrap_or(0);
+            // U běžících snapshotů chybí end_time, duration je pak 0
+            let duration = s["duration_in_millis"].as_i64()
+                .or_else(|| s["end_time_in_millis"].as_i64().map(|end| end - start))
+                .unwrap_or(0)
+                .max(0);
+            let mut indices = strings(&s["indices"]);
+            indices.sort();
+
+            SnapshotInfo {
+                repository: s["repository"].as_str().unwrap_or(repository).to_string(),
+                snapshot: s["snapshot"].as_str().unwrap_or("").to_string(),
+                state: s["state"].as_str().unwrap_or("UNKNOWN").to_string(),
+                start_time_ms: start,
+                duration_ms: duration,
+                indices,
+                data_streams: strings(&s["data_streams"]),
+                shards_total: s["shards"]["total"].as_u64().unwrap_or(0),
+                shards_failed: s["shards"]["failed"].as_u64().unwrap_or(0),
+                failures: s["failures"].as_array()
+                    .map(|items| items.iter().map(|f| format!(
+                        "{}[{}]: {}",
+                        f["index"].as_str().unwrap_or("?"),
+                        f["shard_id"].as_u64().unwrap_or(0),
+                        f["reason"].as_str().unwrap_or(""),
+                    )).collect())
+                    .unwrap_or_default(),
+                version: s["version"].as_str().unwrap_or("").to_string(),
+                slm_policy: s["metadata"]["policy"].as_str().map(|p| p.to_string()),
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    snapshots.sort_by(|a, b| b.start_time_ms.cmp(&a.start_time_ms).then_with(|| b.snapshot.cmp(&a.snapshot)));
+    snapshots
+}
+
+/// Parsuje `GET /_slm/policy`
+fn parse_slm_policies(response: &Value) -> Vec<SlmPolicyInfo> {
+    let mut policies: Vec<SlmPolicyInfo> = response.as_object()
+        .map(|items| items.iter().map(|(id, p)| {
+            let policy = &p["policy"];
+            let indices = match &policy["config"]["indices"] {
+                Value::Array(items) => items.iter().filter_map(|i| i.as_str()).collect::<Vec<_>>().join(", "),
+                Value::String(s) => s.clone(),
+                _ => "*".to_string(),
+            };
+
+            let retention = &policy["retention"];
+            let retention = [
+                retention["expire_after"].as_str().map(|v| format!("expire after {}", v)),
+                retention["min_count"].as_u64().map(|v| format!("min {}", v)),
+                retention["max_count"].as_u64().map(|v| format!("max {}", v)),
+            ].into_iter().flatten().collect::<Vec<_>>().join(", ");
+
+            let run = |key: &str| {
+                let entry = &p[key];
+                let name = entry["snapshot_name"].as_str()?;
+                let time = format_millis(entry["time"].as_i64().or_else(|| entry["start_time"].as_i64()))
+                    .unwrap_or_default();
+                Some(format!("{} @ {}", name, time))
+            };
+
+            SlmPolicyInfo {
+                id: id.clone(),
+                snapshot_name: policy["name"].as_str().unwrap_or("").to_string(),
+                schedule: policy["schedule"].as_str().unwrap_or("").to_string(),
+                repository: policy["repository"].as_str().unwrap_or("").to_string(),
+                indices,
+                retention,
+                last_success: run("last_success"),
+                last_failure: run("last_failure"),
+                next_execution: format_millis(p["next_execution_millis"].as_i64()).unwrap_or_else(|| "-".to_string()),
+            }
+        }).collect())
+        .unwrap_or_default();
+    policies.sort_by(|a, b| a.id.cmp(&b.id));
+    policies
+}
+
+/// Název snapshotu musí být lowercase bez speciálních znaků (date math `<...>` nepodporujeme)
+fn validate_snapshot_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Snapshot name is required".to_string());
+    }
+    if name != name.to_lowercase()
+        || name.starts_with(['_', '-'])
+        || name.contains([' ', ',', '"', '*', '\\', '/', '<', '>', '|', '?', '#'])
+    {
+        return Err(format!("Invalid snapshot name '{}'", name));
+    }
+    Ok(())
+}
+
+/// Sestaví body pro `PUT /_snapshot/{repo}/{snapshot}`
+fn build_snapshot_body(req: &CreateSnapshotRequest) -> Value {
+    let mut body = json!({
+        "include_global_state": req.include_global_state,
+        "partial": req.partial,
+    });
+    let indices = split_list(&req.indices);
+    if !indices.is_empty() {
+        body["indices"] = json!(indices.join(","));
+    }
+    body
+}
+
+/// Sestaví body pro `POST /_snapshot/{repo}/{snapshot}/_restore`
+fn build_restore_body(req: &RestoreSnapshotRequest) -> Result<Value, String> {
+    let mut body = Map::new();
+
+    let indices = split_list(&req.indices);
+    if !indices.is_empty() {
+        body.insert("indices".to_string(), json!(indices.join(",")));
+    }
+
+    let pattern = req.rename_pattern.trim();
+    let replacement = req.rename_replacement.trim();
+    match (pattern.is_empty(), replacement.is_empty()) {
+        (false, false) => {
+            body.insert("rename_pattern".to_string(), json!(pattern));
+            body.insert("rename_replacement".to_string(), json!(replacement));
+        }
+        (true, true) => {}
+        _ => return Err("Rename pattern and rename replacement must be set together".to_string()),
+    }
+
+    let settings = req.index_settings.trim();
+    if !settings.is_empty() {
+        let value: Value = serde_json::from_str(settings)
+            .map_err(|e| format!("Invalid index settings JSON: {}", e))?;
+        if !value.is_object() {
+            return Err("Index settings must be a JSON object".to_string());
+        }
+        body.insert("index_settings".to_string(), value);
+    }
+
+    let ignore = split_list(&req.ignore_index_settings);
+    if !ignore.is_empty() {
+        body.insert("ignore_index_settings".to_string(), json!(ignore));
+    }
+
+    body.insert("include_global_state".to_string(), json!(req.include_global_state));
+    body.insert("include_aliases".to_string(), json!(req.include_aliases));
+    body.insert("partial".to_string(), json!(req.partial));
+
+    Ok(Value::Object(body))
+}
+
+/// GET /snapshots - Repository, snapshoty vybraného repository a SLM politiky
+pub async fn snapshots_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<SnapshotsQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    // Výpis snapshotů velkého repository (S3, GCS) může trvat déle
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(30),
+        load_snapshots_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load snapshots: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading snapshots");
+            error = Some("Timeout loading snapshots".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = SnapshotsTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_snapshots_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &SnapshotsQuery,
+) -> anyhow::Result<SnapshotsData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let repositories = parse_repositories(&client.get::<Value>("/_snapshot/_all").await?);
+
+    let repository = repositories.iter()
+        .find(|r| r.name == query.repository)
+        .or_else(|| repositories.first())
+        .map(|r| r.name.clone())
+        .unwrap_or_default();
+
+    let mut snapshots = Vec::new();
+    let mut snapshots_error = None;
+    if !repository.is_empty() {
+        let (status, response) = client.get_raw(&format!("/_snapshot/{}/_all", repository)).await?;
+        if status >= 400 {
+            snapshots_error = Some(extract_error_reason(&response));
+        } else {
+            let value: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+            snapshots = parse_snapshots(&value, &repository);
+        }
+    }
+
+    // SLM je dostupné od ES 7.4
+    let slm_supported = client.version().map(|v| v.at_least(7, 4)).unwrap_or(true);
+    let slm_policies = if slm_supported {
+        match client.get::<Value>("/_slm/policy").await {
+            Ok(response) => parse_slm_policies(&response),
+            Err(e) => {
+                tracing::warn!("Failed to load SLM policies: {}", e);
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let tab = match query.tab.as_str() {
+        "repositories" | "slm" => query.tab.clone(),
+        _ => "snapshots".to_string(),
+    };
+
+    Ok(SnapshotsData {
+        repositories,
+        repository,
+        snapshots,
+        snapshots_error,
+        slm_policies,
+        slm_supported,
+        snapshot_indices: query.snapshot_indices.clone(),
+        tab,
+    })
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<SnapshotActionResponse>) {
+    (status, Json(SnapshotActionResponse { success: false, message: None, error: Some(error) }))
+}
+
+/// POST /snapshots/repository/{name}/verify - Ověří, že repository je dostupné ze všech nodů
+pub async fn verify_repository(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(name): Path<String>,
+) -> Result<Json<SnapshotActionResponse>, (StatusCode, Json<SnapshotActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/_snapshot/{}/_verify", name), json!({})).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    let result: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+    let mut nodes: Vec<&str> = result["nodes"].as_object()
+        .map(|n| n.values().filter_map(|node| node["name"].as_str()).collect())
+        .unwrap_or_default();
+    nodes.sort();
+
+    Ok(Json(SnapshotActionResponse {
+        success: true,
+        message: Some(format!("Repository {} ověřeno na {} nodech: {}", name, nodes.len(), nodes.join(", "))),
+        error: None,
+    }))
+}
+
+/// POST /snapshots/{repository}/create - Spustí snapshot na pozadí
+pub async fn create_snapshot(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(repository): Path<String>,
+    Json(req): Json<CreateSnapshotRequest>,
+) -> Result<Json<SnapshotActionResponse>, (StatusCode, Json<SnapshotActionResponse>)> {
+    let name = req.snapshot.trim();
+    validate_snapshot_name(name).map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.put_raw(
+        &format!("/_snapshot/{}/{}?wait_for_completion=false", repository, name),
+        build_snapshot_body(&req),
+    ).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Started snapshot {}/{}", repository, name);
+
+    Ok(Json(SnapshotActionResponse {
+        success: true,
+        message: Some(format!("Snapshot {} spuštěn", name)),
+        error: None,
+    }))
+}
+
+/// POST /snapshots/{repository}/{snapshot}/restore - Obnoví indexy ze snapshotu (na pozadí)
+pub async fn restore_snapshot(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path((repository, snapshot)): Path<(String, String)>,
+    Json(req): Json<RestoreSnapshotRequest>,
+) -> Result<Json<SnapshotActionResponse>, (StatusCode, Json<SnapshotActionResponse>)> {
+    let body = build_restore_body(&req).map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/_snapshot/{}/{}/_restore", repository, snapshot), body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Started restore of snapshot {}/{}", repository, snapshot);
+
+    Ok(Json(SnapshotActionResponse {
+        success: true,
+        message: Some(format!("Obnova snapshotu {} spuštěna", snapshot)),
+        error: None,
+    }))
+}
+
+/// POST /snapshots/{repository}/{snapshot}/delete - Smaže snapshot (běžící snapshot tím zruší)
+pub async fn delete_snapshot(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path((repository, snapshot)): Path<(String, String)>,
+) -> Result<Json<SnapshotActionResponse>, (StatusCode, Json<SnapshotActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.delete_raw(&format!("/_snapshot/{}/{}", repository, snapshot)).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Deleted snapshot {}/{}", repository, snapshot);
+
+    Ok(Json(SnapshotActionResponse {
+        success: true,
+        message: Some(format!("Snapshot {} smazán", snapshot)),
+        error: None,
+    }))
+}
+
+/// POST /snapshots/slm/{policy}/execute - Okamžitě spustí SLM politiku
+pub async fn execute_slm_policy(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(policy): Path<String>,
+) -> Result<Json<SnapshotActionResponse>, (StatusCode, Json<SnapshotActionResponse>)> {
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.post_raw(&format!("/_slm/policy/{}/_execute", policy), json!({})).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    let result: Value = serde_json::from_str(&response).unwrap_or(Value::Null);
+    let snapshot = result["snapshot_name"].as_str().unwrap_or("?");
+
+    tracing::info!("Executed SLM policy {} -> {}", policy, snapshot);
+
+    Ok(Json(SnapshotActionResponse {
+        success: true,
+        message: Some(format!("SLM politika {} spuštěna, snapshot {}", policy, snapshot)),
+        error: None,
+    }))
+}
+
+/// GET /snapshots/{repository}/{snapshot}/restore-progress - Stránka s průběhem obnovy
+pub async fn restore_progress_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path((repository, snapshot)): Path<(String, String)>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = SnapshotRestoreTemplate { ctx, repository, snapshot };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /snapshots/{repository}/{snapshot}/restore-progress/table - Shardy obnovované z daného snapshotu (partial pro HTMX)
+pub async fn restore_progress_table(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path((repository, snapshot)): Path<(String, String)>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let source = format!("{}/{}", repository, snapshot);
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_restore_progress(&state, endpoint, &source)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load restore progress: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading restore progress");
+            None
+        }
+    };
+
+    let template = SnapshotRecoveryTableTemplate { data, repository, snapshot };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_restore_progress(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    source: &str,
+) -> anyhow::Result<RestoreProgressData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    // `_recovery` drží poslední recovery každého shardu, takže dokončená obnova zůstane vidět
+    let response: Value = client.get("/_recovery?expand_wildcards=all").await?;
+    let shards: Vec<ShardRecovery> = parse_recovery(&response)
+        .into_iter()
+        .filter(|s| s.recovery_type == "SNAPSHOT" && s.source == source)
+        .collect();
+    let done = shards.iter().filter(|s| s.is_done()).count();
+
+    Ok(RestoreProgressData {
+        indices: {
+            let mut indices: Vec<&str> = shards.iter().map(|s| s.index.as_str()).collect();
+            indices.dedup();
+            indices.join(",")
+        },
+        shards,
+        done,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_snapshots() {
+        let response = json!({
+            "snapshots": [
+                {
+                    "snapshot": "nightly-1", "uuid": "a", "version": "8.15.0",
+                    "indices": ["logs-b", "logs-a"], "data_streams": [],
+                    "state": "SUCCESS",
+                    "start_time_in_millis": 1760000000000_i64, "end_time_in_millis": 1760000075000_i64,
+                    "duration_in_millis": 75000,
+                    "failures": [], "shards": { "total": 4, "failed": 0, "successful": 4 },
+                    "metadata": { "policy": "nightly" }
+                },
+                {
+                    "snapshot": "manual-2", "uuid": "b", "version": "8.15.0",
+                    "indices": ["orders"], "state": "PARTIAL",
+                    "start_time_in_millis": 1760100000000_i64, "end_time_in_millis": 1760100001500_i64,
+                    "failures": [{ "index": "orders", "shard_id": 1, "reason": "node left" }],
+                    "shards": { "total": 2, "failed": 1, "successful": 1 }
+                }
+            ]
+        });
+
+        let snapshots = parse_snapshots(&response, "backups");
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].snapshot, "manual-2");
+        assert_eq!(snapshots[0].repository, "backups");
+        assert_eq!(snapshots[0].duration_ms, 1500);
+        assert_eq!(snapshots[0].duration_formatted(), "1.5s");
+        assert_eq!(snapshots[0].failures, vec!["orders[1]: node left".to_string()]);
+        assert_eq!(snapshots[0].state_color(), "warning");
+        assert_eq!(snapshots[1].indices, vec!["logs-a".to_string(), "logs-b".to_string()]);
+        assert_eq!(snapshots[1].duration_formatted(), "1m 15s");
+        assert_eq!(snapshots[1].slm_policy.as_deref(), Some("nightly"));
+    }
+
+    #[test]
+    fn test_parse_repositories_and_slm() {
+        let repositories = parse_repositories(&json!({
+            "s3-backups": { "type": "s3", "settings": { "bucket": "es-backups", "base_path": "prod" } },
+            "local": { "type": "fs", "settings": { "location": "/mnt/backups" } }
+        }));
+        assert_eq!(repositories[0].name, "local");
+        assert_eq!(repositories[0].location, "/mnt/backups");
+        assert_eq!(repositories[1].location, "es-backups");
+
+        let policies = parse_slm_policies(&json!({
+            "nightly": {
+                "version": 1,
+                "policy": {
+                    "name": "<nightly-{now/d}>", "schedule": "0 30 1 * * ?", "repository": "s3-backups",
+                    "config": { "indices": ["logs-*", "orders"] },
+                    "retention": { "expire_after": "30d", "min_count": 5 }
+                },
+                "last_success": { "snapshot_name": "nightly-2026.10.17", "time": 1760000000000_i64 },
+                "next_execution_millis": 1760086400000_i64
+            }
+        }));
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].indices, "logs-*, orders");
+        assert_eq!(policies[0].retention, "expire after 30d, min 5");
+        assert!(policies[0].last_success.as_deref().unwrap().starts_with("nightly-2026.10.17 @ "));
+        assert!(policies[0].last_failure.is_none());
+    }
+
+    #[test]
+    fn test_build_restore_body() {
+        let req = RestoreSnapshotRequest {
+            indices: "logs-a, logs-b".to_string(),
+            rename_pattern: "(.+)".to_string(),
+            rename_replacement: "restored-$1".to_string(),
+            index_settings: r#"{"index.number_of_replicas": 0}"#.to_string(),
+            ignore_index_settings: "index.refresh_interval".to_string(),
+            include_aliases: false,
+            ..Default::default()
+        };
+        let body = build_restore_body(&req).unwrap();
+        assert_eq!(body["indices"], "logs-a,logs-b");
+        assert_eq!(body["rename_replacement"], "restored-$1");
+        assert_eq!(body["index_settings"]["index.number_of_replicas"], 0);
+        assert_eq!(body["ignore_index_settings"], json!(["index.refresh_interval"]));
+        assert_eq!(body["include_aliases"], false);
+
+        let partial_rename = RestoreSnapshotRequest { rename_pattern: "(.+)".to_string(), ..Default::default() };
+        assert!(build_restore_body(&partial_rename).is_err());
+
+        let bad_settings = RestoreSnapshotRequest { index_settings: "[1]".to_string(), ..Default::default() };
+        assert!(build_restore_body(&bad_settings).is_err());
+
+        let all = build_restore_body(&RestoreSnapshotRequest::default()).unwrap();
+        assert!(all.get("indices").is_none());
+    }
+
+    #[test]
+    fn test_parse_recovery() {
+        let response = json!({
+            "restored-logs": {
+                "shards": [
+                    {
+                        "id": 1, "type": "SNAPSHOT", "stage": "INDEX", "primary": true, "total_time_in_millis": 1200,
+                        "source": { "repository": "backups", "snapshot": "nightly-1", "index": "logs" },
+                        "target": { "name": "node-1" },
+                        "index": { "size": { "total_in_bytes": 1000, "recovered_in_bytes": 250 }, "files": { "total": 10, "recovered": 2 } },
+                        "translog": { "percent": "0.0%" }
+                    },
+                    {
+                        "id": 0, "type": "SNAPSHOT", "stage": "DONE", "primary": true, "total_time_in_millis": 900,
+                        "source": { "repository": "backups", "snapshot": "nightly-1", "index": "logs" },
+                        "target": { "name": "node-2" },
+                        "index": { "size": { "total_in_bytes": 0, "recovered_in_bytes": 0 }, "files": { "total": 0, "recovered": 0 } },
+                        "translog": { "percent": "100.0%" }
+                    },
+                    {
+                        "id": 0, "type": "PEER", "stage": "DONE", "primary": false,
+                        "source": { "name": "node-2" }, "target": { "name": "node-3" },
+                        "index": { "size": { "total_in_bytes": 500, "recovered_in_bytes": 500 } }
+                    }
+                ]
+            }
+        });
+
+        let shards = parse_recovery(&response);
+        assert_eq!(shards.len(), 3);
+        assert_eq!(shards[0].shard, 0);
+        assert!(shards[0].primary);
+        assert_eq!(shards[0].source, "backups/nightly-1");
+        assert_eq!(shards[0].bytes_percent(), 100.0);
+        assert_eq!(shards[0].translog_percent, 100.0);
+        assert_eq!(shards[1].source, "node-2");
+        assert_eq!(shards[2].bytes_percent_formatted(), "25.0");
+
+        let progress = RestoreProgressData {
+            indices: "restored-logs".to_string(),
+            done: 1,
+            shards: shards.into_iter().filter(|s| s.recovery_type == "SNAPSHOT").collect(),
+        };
+        assert!(!progress.is_complete());
+        assert_eq!(progress.overall_percent(), "25.0");
+    }
+
+    #[test]
+    fn test_validate_snapshot_name() {
+        assert!(validate_snapshot_name("nightly-2026.10.18").is_ok());
+        assert!(validate_snapshot_name("").is_err());
+        assert!(validate_snapshot_name("Nightly").is_err());
+        assert!(validate_snapshot_name("a b").is_err());
+        assert!(validate_snapshot_name("_all").is_err());
+    }
+}
@@ -0,0 +1,432 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use std::collections::HashMap;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, create_es_client};
+use crate::templates::{TasksTemplate, TasksTableTemplate, TaskDetailTemplate, PageContext};
+use crate::models::{TaskInfo, TaskProgress, TasksListData, TaskDetail};
+use crate::utils::encode_component;
+
+#[derive(Debug, Deserialize)]
+pub struct TasksQuery {
+    #[serde(default)]
+    pub actions: String,
+    #[serde(default)]
+    pub nodes: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CatTaskRow {
+    pub task_id: String,
+    #[serde(default)]
+    pub node: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CatNodeRow {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct TaskActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Odstraní mezery z filtru (ES očekává čárkami oddělený seznam)
+fn normalize_filter(input: &str) -> String {
+    input
+        .split(',')
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parametry `actions` / `nodes` pro `_tasks` i `_cat/tasks` - hodnoty jsou vstup uživatele,
+/// proto se kódují (`&` nebo `#` nesmí přidat parametr ani useknout query)
+fn filter_params(query: &TasksQuery) -> Vec<String> {
+    [("actions", &query.actions), ("nodes", &query.nodes)].into_iter()
+        .map(|(name, value)| (name, normalize_filter(value)))
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(name, value)| format!("{}={}", name, encode_component(&value)))
+        .collect()
+}
+
+/// Parsuje `status` tasku - pouze tasky s `total` (reindex, *_by_query) mají průběh
+fn parse_task_progress(status: &serde_json::Value) -> Option<TaskProgress> {
+    let total = status.get("total")?.as_u64()?;
+    let get = |key: &str| status.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+
+    Some(TaskProgress {
+        total,
+        created: get("created"),
+        updated: get("updated"),
+        deleted: get("deleted"),
+        noops: get("noops"),
+        version_conflicts: get("version_conflicts"),
+        batches: get("batches"),
+    })
+}
+
+/// Parsuje jeden task z `_tasks` response (rekurzivně i jeho children) do plochého seznamu
+fn collect_tasks(
+    task_id: &str,
+    task: &serde_json::Value,
+    node_names: &HashMap<String, String>,
+    depth: usize,
+    out: &mut Vec<TaskInfo>,
+) {
+    let node_id = task["node"].as_str().unwrap_or("").to_string();
+    let node_name = node_names.get(task_id)
+        .cloned()
+        .unwrap_or_else(|| node_id.clone());
+    let children = task["children"].as_array();
+
+    out.push(TaskInfo {
+        id: task_id.to_string(),
+        node_id,
+        node_name,
+        action: task["action"].as_str().unwrap_or("").to_string(),
+        task_type: task["type"].as_str().unwrap_or("").to_string(),
+        description: task["description"].as_str().unwrap_or("").to_string(),
+        start_time_ms: task["start_time_in_millis"].as_u64().unwrap_or(0),
+        running_time_nanos: task["running_time_in_nanos"].as_u64().unwrap_or(0),
+        cancellable: task["cancellable"].as_bool().unwrap_or(false),
+        cancelled: task["cancelled"].as_bool().unwrap_or(false),
+        parent_task_id: task["parent_task_id"].as_str().map(|s| s.to_string()),
+        progress: parse_task_progress(&task["status"]),
+        depth,
+        children_count: children.map(|c| c.len()).unwrap_or(0),
+    });
+
+    if let Some(children) = children {
+        for child in children {
+            let child_id = format!(
+                "{}:{}",
+                child["node"].as_str().unwrap_or(""),
+                child["id"].as_u64().unwrap_or(0)
+            );
+            collect_tasks(&child_id, child, node_names, depth + 1, out);
+        }
+    }
+}
+
+/// GET /tasks - Zobrazí seznam běžících tasků
+pub async fn tasks_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<TasksQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    // Načti data s timeoutem
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_tasks_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load tasks: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading tasks");
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = TasksTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// GET /tasks/table - Vrátí jen tabulku tasků (partial pro HTMX)
+pub async fn tasks_table(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<TasksQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_tasks_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load tasks: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading tasks");
+            None
+        }
+    };
+
+    let template = TasksTableTemplate { data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_tasks_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &TasksQuery,
+) -> anyhow::Result<TasksListData> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let filters = filter_params(query);
+    let mut params = vec!["detailed=true".to_string(), "group_by=parents".to_string()];
+    let mut cat_params = vec!["format=json".to_string(), "detailed=true".to_string(), "h=task_id,node".to_string()];
+    params.extend(filters.iter().cloned());
+    cat_params.extend(filters);
+
+    // 1. Stromová struktura tasků (parent -> children)
+    let tasks_response: serde_json::Value = client.get(&format!("/_tasks?{}", params.join("&"))).await?;
+
+    // 2. _cat/tasks pro mapování task_id -> název nodu
+    let cat_rows: Vec<CatTaskRow> = client.get(&format!("/_cat/tasks?{}", cat_params.join("&")))
+        .await
+        .unwrap_or_default();
+    let node_names: HashMap<String, String> = cat_rows.into_iter()
+        .map(|row| (row.task_id, row.node))
+        .collect();
+
+    // 3. Seznam nodů pro filtr
+    let cat_nodes: Vec<CatNodeRow> = client.get("/_cat/nodes?format=json&h=name")
+        .await
+        .unwrap_or_default();
+    let mut nodes: Vec<String> = cat_nodes.into_iter().map(|n| n.name).collect();
+    nodes.sort();
+
+    let mut tasks = Vec::new();
+    if let Some(tasks_map) = tasks_response["tasks"].as_object() {
+        // Nejdéle běžící tasky nahoře
+        let mut roots: Vec<(&String, &serde_json::Value)> = tasks_map.iter().collect();
+        roots.sort_by(|a, b| {
+            b.1["running_time_in_nanos"].as_u64().unwrap_or(0)
+                .cmp(&a.1["running_time_in_nanos"].as_u64().unwrap_or(0))
+        });
+        for (task_id, task) in roots {
+            collect_tasks(task_id, task, &node_names, 0, &mut tasks);
+        }
+    }
+
+    let total = tasks.len();
+    let cancellable = tasks.iter().filter(|t| t.cancellable && !t.cancelled).count();
+    let with_progress = tasks.iter().filter(|t| t.progress.is_some()).count();
+
+    Ok(TasksListData {
+        tasks,
+        nodes,
+        actions: normalize_filter(&query.actions),
+        node_filter: normalize_filter(&query.nodes),
+        total,
+        cancellable,
+        with_progress,
+    })
+}
+
+/// GET /tasks/detail/{task_id} - Vrátí detail tasku pro modální okno
+pub async fn task_detail(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(task_id): Path<String>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_task_detail(&state, endpoint, &task_id)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load task detail: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading task detail");
+            None
+        }
+    };
+
+    let template = TaskDetailTemplate { data, task_id };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_task_detail(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    task_id: &str,
+) -> anyhow::Result<TaskDetail> {
+    let client = create_es_client(state, endpoint, false).await?;
+
+    let response: serde_json::Value = client.get(&format!("/_tasks/{}", task_id)).await?;
+
+    let mut parsed = Vec::new();
+    collect_tasks(task_id, &response["task"], &HashMap::new(), 0, &mut parsed);
+    let task = parsed.into_iter().next()
+        .ok_or_else(|| anyhow::anyhow!("Task not found"))?;
+
+    Ok(TaskDetail {
+        task,
+        completed: response["completed"].as_bool().unwrap_or(false),
+        raw: serde_json::to_string_pretty(&response).unwrap_or_default(),
+    })
+}
+
+/// POST /tasks/{task_id}/cancel - Zruší task
+pub async fn cancel_task(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(task_id): Path<String>,
+) -> Result<Json<TaskActionResponse>, (StatusCode, Json<TaskActionResponse>)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    let endpoint = match active_endpoint {
+        Some(ep) => ep,
+        None => return Err((
+            StatusCode::BAD_REQUEST,
+            Json(TaskActionResponse {
+                success: false,
+                message: None,
+                error: Some("No active endpoint selected".to_string()),
+            }),
+        )),
+    };
+
+    let client = create_es_client(&state, &endpoint, false).await.map_err(|e| (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(TaskActionResponse {
+            success: false,
+            message: None,
+            error: Some(format!("Failed to create ES client: {}", e)),
+        }),
+    ))?;
+
+    let path = format!("/_tasks/{}/_cancel", task_id);
+    let response: serde_json::Value = client.post(&path, serde_json::json!({})).await
+        .map_err(|e| (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(TaskActionResponse {
+                success: false,
+                message: None,
+                error: Some(e.to_string()),
+            }),
+        ))?;
+
+    // ES vrací 200 i při chybě - chyby jsou v node_failures / task_failures
+    let failure = response["node_failures"].as_array()
+        .or_else(|| response["task_failures"].as_array())
+        .and_then(|failures| failures.first())
+        .map(|f| {
+            f["caused_by"]["reason"].as_str()
+                .or_else(|| f["reason"]["reason"].as_str())
+                .unwrap_or("Cancel failed")
+                .to_string()
+        });
+
+    if let Some(error) = failure {
+        return Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(TaskActionResponse {
+                success: false,
+                message: None,
+                error: Some(error),
+            }),
+        ));
+    }
+
+    tracing::info!("Cancelled task {}", task_id);
+
+    Ok(Json(TaskActionResponse {
+        success: true,
+        message: Some("Task zrušen".to_string()),
+        error: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_tasks_flattens_children() {
+        let task = serde_json::json!({
+            "node": "n1",
+            "id": 42,
+            "type": "transport",
+            "action": "indices:data/write/reindex",
+            "description": "reindex from [a] to [b]",
+            "start_time_in_millis": 1_700_000_000_000u64,
+            "running_time_in_nanos": 5_000_000_000u64,
+            "cancellable": true,
+            "cancelled": false,
+            "status": { "total": 200, "created": 50, "updated": 30, "deleted": 0, "batches": 2 },
+            "children": [
+                { "node": "n2", "id": 7, "action": "indices:data/write/bulk", "cancellable": false }
+            ]
+        });
+
+        let mut names = HashMap::new();
+        names.insert("n1:42".to_string(), "node-1".to_string());
+
+        let mut out = Vec::new();
+        collect_tasks("n1:42", &task, &names, 0, &mut out);
+
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].node_name, "node-1");
+        assert_eq!(out[0].children_count, 1);
+        assert_eq!(out[0].progress.as_ref().unwrap().percent(), 40);
+        assert_eq!(out[1].id, "n2:7");
+        assert_eq!(out[1].depth, 1);
+        assert_eq!(out[1].node_name, "n2");
+        assert!(out[1].progress.is_none());
+    }
+
+    #[test]
+    fn test_normalize_filter() {
+        assert_eq!(normalize_filter(" *reindex , *byquery* "), "*reindex,*byquery*");
+        assert_eq!(normalize_filter(""), "");
+
+        let query = TasksQuery { actions: "*reindex&detailed=false".to_string(), nodes: "node-1#x".to_string() };
+        assert_eq!(filter_params(&query), vec!["actions=%2Areindex%26detailed%3Dfalse", "nodes=node-1%23x"]);
+    }
+}
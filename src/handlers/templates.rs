@@ -0,0 +1,916 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, Json},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use std::sync::Arc;
+use askama::Template;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::handlers::endpoints::{AppState, get_active_endpoint, active_es_client, create_es_client};
+use crate::handlers::shards::matches_pattern;
+use crate::templates::{
+    ComponentTemplatesTemplate, IndexTemplatesTemplate, PageContext, TemplateEditorTemplate,
+    TemplateSimulateTemplate,
+};
+use crate::es::{EsClient, extract_error_reason};
+use crate::models::{
+    ComponentTemplatesData, TemplateEditorData, TemplateKind, TemplateSimulateData, TemplateSummary,
+    TemplatesData,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct TemplatesQuery {
+    #[serde(default)]
+    pub tab: String,
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub show_managed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateEditorQuery {
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TemplateSimulateQuery {
+    #[serde(default)]
+    pub index: String,
+    #[serde(default)]
+    pub template: String,
+}
+
+/// Formulář editoru šablony
+#[derive(Debug, Deserialize)]
+pub struct TemplateSaveRequest {
+    pub name: String,
+    #[serde(default)]
+    pub index_patterns: String,         // čárkou oddělené
+    #[serde(default)]
+    pub priority: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub composed_of: Vec<String>,
+    #[serde(default)]
+    pub data_stream: bool,
+    #[serde(default)]
+    pub template: String,               // JSON settings / mappings / aliases
+    #[serde(default)]
+    pub extra: String,                  // JSON ostatních top-level polí
+    #[serde(default)]
+    pub create: bool,                   // nepřepisovat existující šablonu
+}
+
+#[derive(Serialize)]
+pub struct TemplateActionResponse {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Klíče, které editor zobrazuje jako samostatná pole
+const COMPOSABLE_FIELDS: &[&str] = &["index_patterns", "priority", "version", "composed_of", "template", "data_stream"];
+const LEGACY_FIELDS: &[&str] = &["index_patterns", "order", "version", "template", "settings", "mappings", "aliases"];
+const COMPONENT_FIELDS: &[&str] = &["version", "template"];
+
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect(),
+        Value::String(s) => vec![s.clone()],
+        _ => Vec::new(),
+    }
+}
+
+fn is_non_empty(value: &Value) -> bool {
+    value.as_object().map(|o| !o.is_empty()).unwrap_or(false)
+}
+
+fn is_managed(name: &str, def: &Value) -> bool {
+    name.starts_with('.') || def["_meta"]["managed"].as_bool().unwrap_or(false)
+}
+
+/// Zpracuje odpověď `GET /_index_template`
+fn parse_composable_templates(response: &Value) -> Vec<TemplateSummary> {
+    let mut templates: Vec<TemplateSummary> = response["index_templates"].as_array()
+        .map(|items| items.iter().map(|item| {
+            let name = item["name"].as_str().unwrap_or("").to_string();
+            let def = &item["index_template"];
+            TemplateSummary {
+                managed: is_managed(&name, def),
+                name,
+                kind: TemplateKind::Index,
+                index_patterns: string_list(&def["index_patterns"]),
+                priority: def["priority"].as_i64(),
+                composed_of: string_list(&def["composed_of"]),
+                version: def["version"].as_i64(),
+                has_settings: is_non_empty(&def["template"]["settings"]),
+                has_mappings: is_non_empty(&def["template"]["mappings"]),
+                has_aliases: is_non_empty(&def["template"]["aliases"]),
+                data_stream: def.get("data_stream").is_some(),
+                used_by: Vec::new(),
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Zpracuje odpověď `GET /_template` (mapa název → definice)
+fn parse_legacy_templates(response: &Value) -> Vec<TemplateSummary> {
+    let mut templates: Vec<TemplateSummary> = response.as_object()
+        .map(|items| items.iter().map(|(name, def)| {
+            // ES 5.x používá "template", novější "index_patterns"
+            let mut index_patterns = string_list(&def["index_patterns"]);
+            if index_patterns.is_empty() {
+                index_patterns = string_list(&def["template"]);
+            }
+            TemplateSummary {
+                name: name.clone(),
+                kind: TemplateKind::Legacy,
+                index_patterns,
+                priority: def["order"].as_i64(),
+                composed_of: Vec::new(),
+                version: def["version"].as_i64(),
+                has_settings: is_non_empty(&def["settings"]),
+                has_mappings: is_non_empty(&def["mappings"]),
+                has_aliases: is_non_empty(&def["aliases"]),
+                data_stream: false,
+                managed: is_managed(name, def),
+                used_by: Vec::new(),
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Zpracuje odpověď `GET /_component_template` a doplní, které index templates ji používají
+fn parse_component_templates(response: &Value, composable: &[TemplateSummary]) -> Vec<TemplateSummary> {
+    let mut templates: Vec<TemplateSummary> = response["component_templates"].as_array()
+        .map(|items| items.iter().map(|item| {
+            let name = item["name"].as_str().unwrap_or("").to_string();
+            let def = &item["component_template"];
+            let used_by = composable.iter()
+                .filter(|t| t.composed_of.contains(&name))
+                .map(|t| t.name.clone())
+                .collect();
+            TemplateSummary {
+                managed: is_managed(&name, def),
+                name,
+                kind: TemplateKind::Component,
+                index_patterns: Vec::new(),
+                priority: None,
+                composed_of: Vec::new(),
+                version: def["version"].as_i64(),
+                has_settings: is_non_empty(&def["template"]["settings"]),
+                has_mappings: is_non_empty(&def["template"]["mappings"]),
+                has_aliases: is_non_empty(&def["template"]["aliases"]),
+                data_stream: false,
+                used_by,
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+fn matches_filter(template: &TemplateSummary, filter: &str, show_managed: bool) -> bool {
+    if !show_managed && template.managed {
+        return false;
+    }
+    filter.is_empty()
+        || filter.split(',')
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .any(|p| {
+                let pattern = if p.contains('*') { p.to_string() } else { format!("*{}*", p) };
+                matches_pattern(&template.name, &pattern)
+                    || template.index_patterns.iter().any(|ip| matches_pattern(ip, &pattern))
+            })
+}
+
+/// Composable šablona, kterou ES použije pro nový index (nejvyšší priorita)
+fn applied_template(templates: &[TemplateSummary], index_name: &str) -> Option<String> {
+    templates.iter()
+        .filter(|t| t.index_patterns.iter().any(|p| matches_pattern(index_name, p)))
+        .max_by_key(|t| t.priority.unwrap_or(0))
+        .map(|t| t.name.clone())
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+/// Rozdělí definici šablony na pole editoru
+fn editor_data_from_definition(kind: TemplateKind, name: &str, def: &Value) -> TemplateEditorData {
+    let known = match kind {
+        TemplateKind::Index => COMPOSABLE_FIELDS,
+        TemplateKind::Legacy => LEGACY_FIELDS,
+        TemplateKind::Component => COMPONENT_FIELDS,
+    };
+
+    let template = match kind {
+        // Legacy šablony mají settings/mappings/aliases přímo na top-level
+        TemplateKind::Legacy => {
+            let mut section = serde_json::Map::new();
+            for key in ["settings", "mappings", "aliases"] {
+                if let Some(value) = def.get(key) && is_non_empty(value) {
+                    section.insert(key.to_string(), value.clone());
+                }
+            }
+            Value::Object(section)
+        }
+        _ => def.get("template").cloned().unwrap_or_else(|| json!({})),
+    };
+
+    let mut extra: serde_json::Map<String, Value> = def.as_object()
+        .map(|o| o.iter()
+            .filter(|(k, _)| !known.contains(&k.as_str()))
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect())
+        .unwrap_or_default();
+
+    // data_stream s vlastními volbami (hidden, allow_custom_routing) zachovat v extra
+    if kind == TemplateKind::Index
+        && let Some(data_stream) = def.get("data_stream")
+        && data_stream.as_object().map(|o| o.values().any(|v| v != &json!(false))).unwrap_or(false)
+    {
+        extra.insert("data_stream".to_string(), data_stream.clone());
+    }
+
+    let mut index_patterns = string_list(&def["index_patterns"]);
+    if index_patterns.is_empty() && kind == TemplateKind::Legacy {
+        index_patterns = string_list(&def["template"]);
+    }
+
+    let priority_key = if kind == TemplateKind::Legacy { "order" } else { "priority" };
+
+    TemplateEditorData {
+        kind,
+        name: name.to_string(),
+        is_new: false,
+        index_patterns: index_patterns.join(", "),
+        priority: def[priority_key].as_i64().map(|p| p.to_string()).unwrap_or_default(),
+        version: def["version"].as_i64().map(|v| v.to_string()).unwrap_or_default(),
+        composed_of: string_list(&def["composed_of"]),
+        data_stream: def.get("data_stream").is_some(),
+        template_json: pretty(&template),
+        extra_json: if extra.is_empty() { String::new() } else { pretty(&Value::Object(extra)) },
+        component_names: Vec::new(),
+    }
+}
+
+fn validate_template_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Template name is required".to_string());
+    }
+    if name.chars().any(|c| c.is_whitespace() || matches!(c, '\\' | '/' | '*' | '?' | '"' | '<' | '>' | '|' | ',' | '#')) {
+        return Err(format!("Template name '{}' contains invalid characters", name));
+    }
+    Ok(())
+}
+
+fn parse_optional_number(label: &str, raw: &str) -> Result<Option<i64>, String> {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    raw.parse::<i64>()
+        .map(Some)
+        .map_err(|_| format!("{} must be a whole number", label))
+}
+
+fn parse_json_section(label: &str, raw: &str) -> Result<serde_json::Map<String, Value>, String> {
+    if raw.trim().is_empty() {
+        return Ok(serde_json::Map::new());
+    }
+    match serde_json::from_str::<Value>(raw) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(format!("{} must be a JSON object", label)),
+        Err(e) => Err(format!("{} is not valid JSON: {}", label, e)),
+    }
+}
+
+/// Sestaví body pro PUT šablony z formuláře editoru
+fn build_template_body(kind: TemplateKind, req: &TemplateSaveRequest) -> Result<Value, String> {
+    validate_template_name(req.name.trim())?;
+
+    let index_patterns: Vec<String> = req.index_patterns.split(',')
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if kind != TemplateKind::Component && index_patterns.is_empty() {
+        return Err("At least one index pattern is required".to_string());
+    }
+
+    let priority = parse_optional_number(if kind == TemplateKind::Legacy { "Order" } else { "Priority" }, &req.priority)?;
+    let version = parse_optional_number("Version", &req.version)?;
+    let template = parse_json_section("Template", &req.template)?;
+    if let Some(key) = template.keys().find(|k| !["settings", "mappings", "aliases", "lifecycle"].contains(&k.as_str())) {
+        return Err(format!("Unknown key '{}' in template section (expected settings, mappings, aliases)", key));
+    }
+
+    // Ostatní pole jako základ, formulářová pole mají přednost
+    let mut body = parse_json_section("Other fields", &req.extra)?;
+
+    if let Some(version) = version {
+        body.insert("version".to_string(), json!(version));
+    }
+
+    match kind {
+        TemplateKind::Index => {
+            body.insert("index_patterns".to_string(), json!(index_patterns));
+            if let Some(priority) = priority {
+                body.insert("priority".to_string(), json!(priority));
+            }
+            let composed_of: Vec<&str> = req.composed_of.iter()
+                .map(|c| c.trim())
+                .filter(|c| !c.is_empty())
+                .collect();
+            if !composed_of.is_empty() {
+                body.insert("composed_of".to_string(), json!(composed_of));
+            }
+            if req.data_stream {
+                body.entry("data_stream".to_string()).or_insert_with(|| json!({}));
+            } else {
+                body.remove("data_stream");
+            }
+            if !template.is_empty() {
+                body.insert("template".to_string(), Value::Object(template));
+            }
+        }
+        TemplateKind::Legacy => {
+            body.insert("index_patterns".to_string(), json!(index_patterns));
+            if let Some(order) = priority {
+                body.insert("order".to_string(), json!(order));
+            }
+            for (key, value) in template {
+                body.insert(key, value);
+            }
+        }
+        TemplateKind::Component => {
+            body.insert("template".to_string(), Value::Object(template));
+        }
+    }
+
+    Ok(Value::Object(body))
+}
+
+fn supports_composable(client: &EsClient) -> bool {
+    client.version().map(|v| v.at_least(7, 8)).unwrap_or(true)
+}
+
+/// GET /templates/index - Seznam composable a legacy index templates
+pub async fn index_templates_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<TemplatesQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_index_templates_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load index templates: {}", e);
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading index templates");
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = IndexTemplatesTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_index_templates_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &TemplatesQuery,
+) -> anyhow::Result<TemplatesData> {
+    let client = create_es_client(state, endpoint, true).await?;
+    let composable_supported = supports_composable(&client);
+    let filter = query.filter.trim().to_string();
+
+    let (mut composable, mut legacy) = if composable_supported {
+        let composable = parse_composable_templates(&client.get_index_templates().await?);
+        // Legacy API je od 7.8 deprecated, ale stále funkční
+        let legacy = match client.get::<Value>("/_template").await {
+            Ok(response) => parse_legacy_templates(&response),
+            Err(e) => {
+                tracing::warn!("Failed to load legacy templates: {}", e);
+                Vec::new()
+            }
+        };
+        (composable, legacy)
+    } else {
+        (Vec::new(), parse_legacy_templates(&client.get_index_templates().await?))
+    };
+
+    composable.retain(|t| matches_filter(t, &filter, query.show_managed));
+    legacy.retain(|t| matches_filter(t, &filter, query.show_managed));
+
+    let tab = if query.tab == "legacy" || !composable_supported { "legacy" } else { "composable" };
+
+    Ok(TemplatesData {
+        composable,
+        legacy,
+        composable_supported,
+        tab: tab.to_string(),
+        filter,
+        show_managed: query.show_managed,
+    })
+}
+
+/// GET /templates/component - Seznam component templates
+pub async fn component_templates_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<TemplatesQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_component_templates_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load component templates: {}", e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading component templates");
+            error = Some("Timeout loading component templates".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = ComponentTemplatesTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_component_templates_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &TemplatesQuery,
+) -> anyhow::Result<ComponentTemplatesData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    let response = client.get_component_templates().await?;
+    let composable = parse_composable_templates(&client.get_index_templates().await?);
+
+    let filter = query.filter.trim().to_string();
+    let mut templates = parse_component_templates(&response, &composable);
+    templates.retain(|t| matches_filter(t, &filter, query.show_managed));
+
+    Ok(ComponentTemplatesData {
+        templates,
+        filter,
+        show_managed: query.show_managed,
+    })
+}
+
+/// GET /templates/{kind}/edit - Detail a editor šablony (bez name = nová šablona)
+pub async fn template_editor_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(kind): Path<String>,
+    Query(query): Query<TemplateEditorQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let kind = TemplateKind::parse(&kind)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, format!("Unknown template kind: {}", kind)))?;
+
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+    let mut error = None;
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_template_editor_data(&state, endpoint, kind, query.name.trim())
+    ).await {
+        Ok(Ok(d)) => Some(d),
+        Ok(Err(e)) => {
+            tracing::error!("Failed to load template {}: {}", query.name, e);
+            error = Some(e.to_string());
+            None
+        }
+        Err(_) => {
+            tracing::error!("Timeout loading template {}", query.name);
+            error = Some("Timeout loading template".to_string());
+            None
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = TemplateEditorTemplate { ctx, data, error };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+async fn load_template_editor_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    kind: TemplateKind,
+    name: &str,
+) -> anyhow::Result<TemplateEditorData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    if kind != TemplateKind::Legacy && !supports_composable(&client) {
+        anyhow::bail!("{}s require Elasticsearch 7.8 or higher", kind.label());
+    }
+
+    let mut data = if name.is_empty() {
+        TemplateEditorData {
+            kind,
+            name: String::new(),
+            is_new: true,
+            index_patterns: String::new(),
+            priority: String::new(),
+            version: String::new(),
+            composed_of: Vec::new(),
+            data_stream: false,
+            template_json: pretty(&json!({ "settings": {}, "mappings": {}, "aliases": {} })),
+            extra_json: String::new(),
+            component_names: Vec::new(),
+        }
+    } else {
+        let response: Value = client.get(&kind.api_path(name)).await?;
+        let def = match kind {
+            TemplateKind::Index => response["index_templates"][0]["index_template"].clone(),
+            TemplateKind::Component => response["component_templates"][0]["component_template"].clone(),
+            TemplateKind::Legacy => response[name].clone(),
+        };
+        if def.is_null() {
+            anyhow::bail!("{} '{}' not found", kind.label(), name);
+        }
+        editor_data_from_definition(kind, name, &def)
+    };
+
+    if kind == TemplateKind::Index {
+        let components = client.get_component_templates().await?;
+        data.component_names = parse_component_templates(&components, &[])
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+    }
+
+    Ok(data)
+}
+
+/// POST /templates/{kind}/save - Vytvoří nebo přepíše šablonu
+pub async fn save_template(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path(kind): Path<String>,
+    Json(req): Json<TemplateSaveRequest>,
+) -> Result<Json<TemplateActionResponse>, (StatusCode, Json<TemplateActionResponse>)> {
+    let error_response = |status: StatusCode, error: String| {
+        (status, Json(TemplateActionResponse { success: false, message: None, error: Some(error) }))
+    };
+
+    let kind = TemplateKind::parse(&kind)
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, format!("Unknown template kind: {}", kind)))?;
+
+    let body = build_template_body(kind, &req)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, e))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let name = req.name.trim();
+    let mut path = kind.api_path(name);
+    if req.create {
+        path.push_str("?create=true");
+    }
+
+    let (status, response) = client.put_raw(&path, body).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Saved {} {}", kind.label(), name);
+
+    Ok(Json(TemplateActionResponse {
+        success: true,
+        message: Some(format!("Šablona {} uložena", name)),
+        error: None,
+    }))
+}
+
+/// POST /templates/{kind}/{name}/delete - Smaže šablonu
+pub async fn delete_template(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Path((kind, name)): Path<(String, String)>,
+) -> Result<Json<TemplateActionResponse>, (StatusCode, Json<TemplateActionResponse>)> {
+    let error_response = |status: StatusCode, error: String| {
+        (status, Json(TemplateActionResponse { success: false, message: None, error: Some(error) }))
+    };
+
+    let kind = TemplateKind::parse(&kind)
+        .ok_or_else(|| error_response(StatusCode::NOT_FOUND, format!("Unknown template kind: {}", kind)))?;
+
+    let (client, _) = active_es_client(&state, &jar, true).await
+        .map_err(|(status, e)| error_response(status, e))?;
+
+    let (status, response) = client.delete_raw(&kind.api_path(&name)).await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    if status >= 400 {
+        return Err(error_response(StatusCode::BAD_REQUEST, extract_error_reason(&response)));
+    }
+
+    tracing::info!("Deleted {} {}", kind.label(), name);
+
+    Ok(Json(TemplateActionResponse {
+        success: true,
+        message: Some(format!("Šablona {} smazána", name)),
+        error: None,
+    }))
+}
+
+/// GET /templates/simulate - Efektivní settings/mappings, které by dostal nový index
+pub async fn simulate_template_page(
+    State(state): State<Arc<AppState>>,
+    jar: CookieJar,
+    Query(query): Query<TemplateSimulateQuery>,
+) -> Result<Html<String>, (StatusCode, String)> {
+    let active_endpoint = get_active_endpoint(&state, &jar).await;
+
+    if active_endpoint.is_none() {
+        return Err((StatusCode::BAD_REQUEST, "No active endpoint selected".to_string()));
+    }
+
+    let endpoint = active_endpoint.as_ref().unwrap();
+
+    let data = match tokio::time::timeout(
+        tokio::time::Duration::from_secs(10),
+        load_simulate_data(&state, endpoint, &query)
+    ).await {
+        Ok(Ok(d)) => d,
+        Ok(Err(e)) => {
+            tracing::error!("Failed to simulate templates: {}", e);
+            simulate_error(&query, e.to_string())
+        }
+        Err(_) => {
+            tracing::error!("Timeout simulating templates");
+            simulate_error(&query, "Timeout simulating templates".to_string())
+        }
+    };
+
+    let ctx = PageContext::new(active_endpoint);
+    let template = TemplateSimulateTemplate { ctx, data };
+
+    template.render()
+        .map(Html)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+fn simulate_error(query: &TemplateSimulateQuery, error: String) -> TemplateSimulateData {
+    TemplateSimulateData {
+        index_name: query.index.trim().to_string(),
+        template_name: query.template.trim().to_string(),
+        applied_template: None,
+        settings: String::new(),
+        mappings: String::new(),
+        aliases: String::new(),
+        overlapping: Vec::new(),
+        error: Some(error),
+    }
+}
+
+async fn load_simulate_data(
+    state: &AppState,
+    endpoint: &crate::db::models::Endpoint,
+    query: &TemplateSimulateQuery,
+) -> anyhow::Result<TemplateSimulateData> {
+    let client = create_es_client(state, endpoint, true).await?;
+
+    if !client.version().map(|v| v.at_least(7, 9)).unwrap_or(true) {
+        anyhow::bail!("Template simulation requires Elasticsearch 7.9 or higher");
+    }
+
+    let templates = parse_composable_templates(&client.get_index_templates().await?);
+    let template_name = query.template.trim().to_string();
+
+    // Bez názvu indexu odvodit ukázkový název z prvního patternu šablony
+    let mut index_name = query.index.trim().to_string();
+    if index_name.is_empty()
+        && let Some(pattern) = templates.iter()
+            .find(|t| t.name == template_name)
+            .and_then(|t| t.index_patterns.first()) {
+            index_name = pattern.replace('*', "simulated");
+        }
+
+    let mut data = TemplateSimulateData {
+        index_name: index_name.clone(),
+        template_name,
+        applied_template: None,
+        settings: String::new(),
+        mappings: String::new(),
+        aliases: String::new(),
+        overlapping: Vec::new(),
+        error: None,
+    };
+
+    if index_name.is_empty() {
+        return Ok(data);
+    }
+
+    let (status, response) = client.post_raw(&format!("/_index_template/_simulate_index/{}", index_name), json!({})).await?;
+    if status >= 400 {
+        data.error = Some(extract_error_reason(&response));
+        return Ok(data);
+    }
+    let response: Value = serde_json::from_str(&response)?;
+
+    data.applied_template = applied_template(&templates, &index_name);
+    data.settings = pretty(&response["template"]["settings"]);
+    data.mappings = pretty(&response["template"]["mappings"]);
+    data.aliases = pretty(&response["template"]["aliases"]);
+    data.overlapping = response["overlapping"].as_array()
+        .map(|items| items.iter()
+            .filter_map(|o| o["name"].as_str())
+            .filter_map(|name| templates.iter().find(|t| t.name == name).cloned())
+            .collect())
+        .unwrap_or_default();
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn save_request(name: &str, patterns: &str, template: &str) -> TemplateSaveRequest {
+        TemplateSaveRequest {
+            name: name.to_string(),
+            index_patterns: patterns.to_string(),
+            priority: String::new(),
+            version: String::new(),
+            composed_of: Vec::new(),
+            data_stream: false,
+            template: template.to_string(),
+            extra: String::new(),
+            create: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_templates() {
+        let composable = parse_composable_templates(&json!({
+            "index_templates": [
+                { "name": "logs", "index_template": {
+                    "index_patterns": ["logs-*"], "priority": 200, "composed_of": ["base"],
+                    "template": { "settings": { "index": { "number_of_shards": "1" } } },
+                    "data_stream": {}, "_meta": { "managed": true }
+                } },
+                { "name": "app", "index_template": { "index_patterns": ["app-*", "logs-app-*"], "priority": 300 } }
+            ]
+        }));
+        assert_eq!(composable[0].name, "app");
+        assert!(composable[1].has_settings && !composable[1].has_mappings);
+        assert!(composable[1].data_stream && composable[1].managed);
+
+        let components = parse_component_templates(&json!({
+            "component_templates": [{ "name": "base", "component_template": { "template": { "mappings": { "properties": {} } } } }]
+        }), &composable);
+        assert_eq!(components[0].used_by, vec!["logs".to_string()]);
+
+        let legacy = parse_legacy_templates(&json!({
+            "old": { "order": 5, "template": "old-*", "settings": {}, "aliases": { "a": {} } }
+        }));
+        assert_eq!(legacy[0].index_patterns, vec!["old-*".to_string()]);
+        assert_eq!(legacy[0].priority, Some(5));
+        assert!(legacy[0].has_aliases && !legacy[0].has_settings);
+
+        assert_eq!(applied_template(&composable, "logs-app-1").as_deref(), Some("app"));
+        assert_eq!(applied_template(&composable, "logs-web").as_deref(), Some("logs"));
+        assert_eq!(applied_template(&composable, "metrics"), None);
+    }
+
+    #[test]
+    fn test_build_template_body() {
+        let body = build_template_body(TemplateKind::Index, &TemplateSaveRequest {
+            priority: "100".to_string(),
+            composed_of: vec!["base".to_string(), " ".to_string()],
+            data_stream: true,
+            extra: r#"{"_meta": {"owner": "ops"}, "data_stream": {"hidden": true}}"#.to_string(),
+            ..save_request("logs", "logs-*, logs2-*", r#"{"settings": {"number_of_replicas": 0}}"#)
+        }).unwrap();
+        assert_eq!(body["index_patterns"], json!(["logs-*", "logs2-*"]));
+        assert_eq!(body["priority"], 100);
+        assert_eq!(body["composed_of"], json!(["base"]));
+        assert_eq!(body["data_stream"]["hidden"], true);
+        assert_eq!(body["_meta"]["owner"], "ops");
+        assert_eq!(body["template"]["settings"]["number_of_replicas"], 0);
+
+        let legacy = build_template_body(TemplateKind::Legacy, &TemplateSaveRequest {
+            priority: "3".to_string(),
+            ..save_request("old", "old-*", r#"{"mappings": {"properties": {}}}"#)
+        }).unwrap();
+        assert_eq!(legacy["order"], 3);
+        assert!(legacy["mappings"].is_object());
+        assert!(legacy.get("template").is_none());
+
+        let component = build_template_body(TemplateKind::Component, &save_request("base", "", "")).unwrap();
+        assert_eq!(component, json!({ "template": {} }));
+    }
+
+    #[test]
+    fn test_build_template_body_errors() {
+        assert!(build_template_body(TemplateKind::Index, &save_request("logs", "", "")).is_err());
+        assert!(build_template_body(TemplateKind::Index, &save_request("bad name", "x-*", "")).is_err());
+        assert!(build_template_body(TemplateKind::Index, &save_request("logs", "x-*", "[1]")).is_err());
+        assert!(build_template_body(TemplateKind::Index, &save_request("logs", "x-*", r#"{"setings": {}}"#)).is_err());
+        assert!(build_template_body(TemplateKind::Legacy, &TemplateSaveRequest {
+            priority: "high".to_string(),
+            ..save_request("old", "old-*", "")
+        }).is_err());
+    }
+
+    #[test]
+    fn test_editor_data_round_trip() {
+        let def = json!({
+            "index_patterns": ["logs-*"], "priority": 10, "version": 2, "composed_of": ["base"],
+            "template": { "mappings": { "properties": { "a": { "type": "keyword" } } } },
+            "data_stream": { "hidden": false }, "_meta": { "owner": "ops" }
+        });
+        let data = editor_data_from_definition(TemplateKind::Index, "logs", &def);
+        assert_eq!(data.index_patterns, "logs-*");
+        assert_eq!(data.priority, "10");
+        assert!(data.data_stream);
+        assert!(data.extra_json.contains("owner") && !data.extra_json.contains("hidden"));
+
+        let body = build_template_body(TemplateKind::Index, &TemplateSaveRequest {
+            name: data.name,
+            index_patterns: data.index_patterns,
+            priority: data.priority,
+            version: data.version,
+            composed_of: data.composed_of,
+            data_stream: data.data_stream,
+            template: data.template_json,
+            extra: data.extra_json,
+            create: false,
+        }).unwrap();
+        assert_eq!(body, json!({
+            "index_patterns": ["logs-*"], "priority": 10, "version": 2, "composed_of": ["base"],
+            "template": { "mappings": { "properties": { "a": { "type": "keyword" } } } },
+            "data_stream": {}, "_meta": { "owner": "ops" }
+        }));
+
+        let legacy = editor_data_from_definition(TemplateKind::Legacy, "old", &json!({
+            "order": 1, "index_patterns": ["old-*"], "settings": { "index": { "number_of_shards": "1" } }, "mappings": {}, "aliases": {}
+        }));
+        assert_eq!(legacy.priority, "1");
+        assert!(legacy.template_json.contains("number_of_shards") && !legacy.template_json.contains("mappings"));
+        assert!(legacy.extra_json.is_empty());
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::broadcast;
+
+use crate::db::models::Endpoint;
+use crate::es::EsClient;
+use crate::handlers::AppState;
+use crate::handlers::recovery;
+
+/// Interval dotazů na cluster (stejný jako dřívější polling metrik nodu)
+const LIVE_INTERVAL: Duration = Duration::from_secs(5);
+const POLL_TIMEOUT: Duration = Duration::from_secs(15);
+const CHANNEL_CAPACITY: usize = 64;
+/// Horní mez přechodů v jedné události (např. smazání velkého indexu)
+const MAX_TRANSITIONS: usize = 200;
+
+/// Jedna SSE událost (`data` je už serializovaný JSON)
+#[derive(Debug, Clone)]
+pub struct LiveEvent {
+    pub name: &'static str,
+    pub data: String,
+}
+
+/// Metriky nodu v jednom ticku (pole z `/nodes/{id}/metrics` + rychlosti z rozdílu dvou ticků)
+#[derive(Debug, Serialize)]
+pub struct NodeMetricTick {
+    pub id: String,
+    pub name: String,
+    pub cpu_percent: Option<u8>,
+    pub heap_percent: Option<u8>,
+    pub ram_percent: Option<u8>,
+    pub disk_percent: Option<u8>,
+    #[serde(flatten)]
+    pub rates: NodeRates,
+}
+
+/// Kumulativní čítače nodu z `indices` statistik
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NodeCounters {
+    timestamp_ms: u64,
+    index_total: u64,
+    query_total: u64,
+    query_time_ms: u64,
+}
+
+/// Rychlosti mezi dvěma vzorky (None = první vzorek nebo restart nodu)
+#[derive(Debug, Default, Serialize, PartialEq)]
+pub struct NodeRates {
+    pub indexing_per_sec: Option<f64>,
+    pub search_per_sec: Option<f64>,
+    pub query_latency_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct ShardCopy {
+    index: String,
+    shard: String,
+    prirep: String,
+    state: String,
+    node: Option<String>,
+}
+
+/// Změna stavu jedné kopie shardu (`NONE` = kopie vznikla / zanikla)
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ShardTransition {
+    pub index: String,
+    pub shard: String,
+    pub primary: bool,
+    pub from: String,
+    pub to: String,
+    pub node: Option<String>,
+}
+
+/// Kanál jednoho endpointu - poslední health a metriky se přehrají novým odběratelům
+struct Channel {
+    tx: broadcast::Sender<LiveEvent>,
+    latest: Mutex<HashMap<&'static str, LiveEvent>>,
+}
+
+impl Channel {
+    fn publish(&self, name: &'static str, data: Value, replay: bool) {
+        let event = LiveEvent { name, data: data.to_string() };
+        if replay && let Ok(mut latest) = self.latest.lock() {
+            latest.insert(name, event.clone());
+        }
+        // Bez odběratelů send selže, poller se ukončí v dalším ticku
+        let _ = self.tx.send(event);
+    }
+}
+
+/// Sdílené SSE kanály - jeden poller na endpoint bez ohledu na počet otevřených stránek
+#[derive(Default)]
+pub struct LiveHub {
+    channels: Mutex<HashMap<i64, Arc<Channel>>>,
+}
+
+impl LiveHub {
+    /// Přihlásí odběr změn endpointu; poller se spustí s prvním odběratelem
+    pub fn subscribe(
+        &self,
+        state: Arc<AppState>,
+        endpoint: Endpoint,
+    ) -> (Vec<LiveEvent>, broadcast::Receiver<LiveEvent>) {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(channel) = channels.get(&endpoint.id) {
+            let replay = channel.latest.lock()
+                .map(|latest| latest.values().cloned().collect())
+                .unwrap_or_default();
+            return (replay, channel.tx.subscribe());
+        }
+
+        let (tx, rx) = broadcast::channel(CHANNEL_CAPACITY);
+        let channel = Arc::new(Channel { tx, latest: Mutex::new(HashMap::new()) });
+        channels.insert(endpoint.id, channel.clone());
+        tokio::spawn(run_poller(state, endpoint, channel));
+
+        (Vec::new(), rx)
+    }
+
+    /// Poller běží, dokud má kanál odběratele (kontrola pod zámkem kvůli souběhu se subscribe)
+    fn keep_running(&self, endpoint_id: i64, channel: &Arc<Channel>) -> bool {
+        let mut channels = self.channels.lock().unwrap_or_else(|e| e.into_inner());
+        if channel.tx.receiver_count() > 0 {
+            return true;
+        }
+        if channels.get(&endpoint_id).is_some_and(|c| Arc::ptr_eq(c, channel)) {
+            channels.remove(&endpoint_id);
+        }
+        false
+    }
+}
+
+async fn run_poller(state: Arc<AppState>, endpoint: Endpoint, channel: Arc<Channel>) {
+    tracing::debug!("Starting live updates for endpoint {}", endpoint.id);
+
+    let mut interval = tokio::time::interval(LIVE_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    let mut poller = Poller::default();
+
+    loop {
+        interval.tick().await;
+        if !state.live.keep_running(endpoint.id, &channel) {
+            break;
+        }
+
+        match tokio::time::timeout(POLL_TIMEOUT, poller.poll(&state, &endpoint, &channel)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => {
+                tracing::warn!("Live update failed for endpoint {}: {}", endpoint.id, e);
+                channel.publish("unavailable", json!({ "message": e.to_string() }), false);
+            }
+            Err(_) => {
+                tracing::warn!("Timeout in live update for endpoint {}", endpoint.id);
+                channel.publish("unavailable", json!({ "message": "Timeout" }), false);
+            }
+        }
+    }
+
+    tracing::debug!("Stopped live updates for endpoint {}", endpoint.id);
+}
+
+/// Stav mezi ticky - změny se posílají jen při rozdílu oproti předchozímu ticku
+#[derive(Default)]
+struct Poller {
+    client: Option<EsClient>,
+    health: Option<Value>,
+    shards: Option<Vec<ShardCopy>>,
+    counters: HashMap<String, NodeCounters>,
+}
+
+impl Poller {
+    async fn poll(&mut self, state: &AppState, endpoint: &Endpoint, channel: &Channel) -> anyhow::Result<()> {
+        if self.client.is_none() {
+            let password = state.db.get_endpoint_password(endpoint).await;
+            self.client = Some(EsClient::new(
+                endpoint.url.clone(),
+                endpoint.insecure,
+                endpoint.username.clone(),
+                password,
+            )?);
+        }
+        let client = self.client.as_ref().unwrap();
+
+        // Health - jen při změně
+        let cluster_health = client.cluster_health().await?;
+        let health = serde_json::to_value(&cluster_health)?;
+        let previous_unsettled = self.health.as_ref().is_some_and(is_unsettled);
+        let health_changed = self.health.as_ref() != Some(&health);
+        if health_changed {
+            channel.publish("health", health.clone(), true);
+        }
+
+        // Metriky nodů - každý tick, jen `_nodes/stats` (bez `_nodes` info)
+        let stats: Value = client.get("/_nodes/stats/os,jvm,fs,indices/indexing,search").await?;
+        channel.publish("metrics", json!(parse_metric_ticks(&stats, &self.counters)), true);
+        self.counters = collect_counters(&stats);
+
+        // Shardy - jen když se cluster hýbe nebo se změnil health
+        if self.shards.is_none() || health_changed || previous_unsettled || is_unsettled(&health) {
+            let shards: Vec<ShardCopy> = client.get("/_cat/shards?format=json&h=index,shard,prirep,state,node").await?;
+            if let Some(previous) = &self.shards {
+                let transitions = diff_shards(previous, &shards);
+                if !transitions.is_empty() {
+                    let total = transitions.len();
+                    let shown: Vec<ShardTransition> = transitions.into_iter().take(MAX_TRANSITIONS).collect();
+                    channel.publish("shards", json!({ "total": total, "transitions": shown }), false);
+                }
+            }
+            self.shards = Some(shards);
+        }
+
+        // Recovery - během relokace / inicializace a jednou po ní, aby monitor ukázal dokončení
+        if previous_unsettled || is_unsettled(&health) {
+            let recovery = recovery::load_recovery(client, &cluster_health).await?;
+            let html = recovery::render_recovery_table(recovery)?;
+            channel.publish("recovery", json!({ "html": html }), false);
+        }
+
+        self.health = Some(health);
+        Ok(())
+    }
+}
+
+/// Probíhá relokace nebo inicializace (trvale nepřiřazené shardy se projeví změnou health)
+fn is_unsettled(health: &Value) -> bool {
+    ["relocating_shards", "initializing_shards"]
+        .iter()
+        .any(|key| health[key].as_u64().unwrap_or(0) > 0)
+}
+
+fn node_counters(node: &Value) -> Option<NodeCounters> {
+    Some(NodeCounters {
+        timestamp_ms: node["timestamp"].as_u64()?,
+        index_total: node["indices"]["indexing"]["index_total"].as_u64()?,
+        query_total: node["indices"]["search"]["query_total"].as_u64()?,
+        query_time_ms: node["indices"]["search"]["query_time_in_millis"].as_u64()?,
+    })
+}
+
+fn collect_counters(stats: &Value) -> HashMap<String, NodeCounters> {
+    stats["nodes"].as_object()
+        .map(|nodes| nodes.iter()
+            .filter_map(|(node_id, node)| node_counters(node).map(|c| (node_id.clone(), c)))
+            .collect())
+        .unwrap_or_default()
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Rychlosti z rozdílu čítačů; pokles čítače znamená restart nodu
+fn compute_rates(previous: &NodeCounters, current: &NodeCounters) -> NodeRates {
+    if current.timestamp_ms <= previous.timestamp_ms
+        || current.index_total < previous.index_total
+        || current.query_total < previous.query_total
+        || current.query_time_ms < previous.query_time_ms
+    {
+        return NodeRates::default();
+    }
+
+    let elapsed_secs = (current.timestamp_ms - previous.timestamp_ms) as f64 / 1000.0;
+    let queries = current.query_total - previous.query_total;
+    NodeRates {
+        indexing_per_sec: Some(round1((current.index_total - previous.index_total) as f64 / elapsed_secs)),
+        search_per_sec: Some(round1(queries as f64 / elapsed_secs)),
+        // Bez dotazů v intervalu latence neexistuje
+        query_latency_ms: (queries > 0)
+            .then(|| round1((current.query_time_ms - previous.query_time_ms) as f64 / queries as f64)),
+    }
+}
+
+fn parse_metric_ticks(stats: &Value, previous: &HashMap<String, NodeCounters>) -> Vec<NodeMetricTick> {
+    let Some(nodes) = stats["nodes"].as_object() else {
+        return Vec::new();
+    };
+
+    let mut ticks: Vec<NodeMetricTick> = nodes.iter().map(|(node_id, node)| {
+        let ram_used = node["os"]["mem"]["used_in_bytes"].as_u64().unwrap_or(0);
+        let ram_total = node["os"]["mem"]["total_in_bytes"].as_u64().unwrap_or(0);
+        let disk_available = node["fs"]["total"]["available_in_bytes"].as_u64().unwrap_or(0);
+        let disk_total = node["fs"]["total"]["total_in_bytes"].as_u64().unwrap_or(0);
+        let disk_used = disk_total.saturating_sub(disk_available);
+
+        NodeMetricTick {
+            id: node_id.clone(),
+            name: node["name"].as_str().unwrap_or(node_id).to_string(),
+            cpu_percent: node["os"]["cpu"]["percent"].as_u64().map(|v| v as u8),
+            heap_percent: node["jvm"]["mem"]["heap_used_percent"].as_u64().map(|v| v as u8),
+            ram_percent: (ram_used * 100).checked_div(ram_total).map(|v| v as u8),
+            disk_percent: (disk_used * 100).checked_div(disk_total).map(|v| v as u8),
+            rates: node_counters(node)
+                .zip(previous.get(node_id))
+                .map(|(current, previous)| compute_rates(previous, &current))
+                .unwrap_or_default(),
+        }
+    }).collect();
+
+    ticks.sort_by(|a, b| a.name.cmp(&b.name));
+    ticks
+}
+
+/// Porovná dva výpisy `_cat/shards`; kopie se párují v rámci (index, shard, p/r)
+fn diff_shards(previous: &[ShardCopy], current: &[ShardCopy]) -> Vec<ShardTransition> {
+    type Group = BTreeMap<(String, String, String), Vec<(String, Option<String>)>>;
+
+    fn group(shards: &[ShardCopy]) -> Group {
+        let mut groups = Group::new();
+        for s in shards {
+            groups.entry((s.index.clone(), s.shard.clone(), s.prirep.clone()))
+                .or_default()
+                .push((s.state.clone(), s.node.clone()));
+        }
+        groups
+    }
+
+    let before = group(previous);
+    let after = group(current);
+    let mut keys: Vec<&(String, String, String)> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut transitions = Vec::new();
+    for key in keys {
+        let mut removed = before.get(key).cloned().unwrap_or_default();
+        let mut added = Vec::new();
+        for copy in after.get(key).cloned().unwrap_or_default() {
+            if let Some(pos) = removed.iter().position(|c| *c == copy) {
+                removed.remove(pos);
+            } else {
+                added.push(copy);
+            }
+        }
+
+        removed.sort();
+        added.sort();
+        for i in 0..removed.len().max(added.len()) {
+            let from = removed.get(i);
+            let to = added.get(i);
+            transitions.push(ShardTransition {
+                index: key.0.clone(),
+                shard: key.1.clone(),
+                primary: key.2 == "p",
+                from: from.map(|c| c.0.clone()).unwrap_or_else(|| "NONE".to_string()),
+                to: to.map(|c| c.0.clone()).unwrap_or_else(|| "NONE".to_string()),
+                node: to.and_then(|c| c.1.clone()).or_else(|| from.and_then(|c| c.1.clone())),
+            });
+        }
+    }
+
+    transitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn copy(index: &str, prirep: &str, state: &str, node: Option<&str>) -> ShardCopy {
+        ShardCopy {
+            index: index.to_string(),
+            shard: "0".to_string(),
+            prirep: prirep.to_string(),
+            state: state.to_string(),
+            node: node.map(|n| n.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_detects_shard_transitions() {
+        let previous = vec![
+            copy("logs", "p", "STARTED", Some("a")),
+            copy("logs", "r", "INITIALIZING", Some("b")),
+            copy("logs", "r", "UNASSIGNED", None),
+            copy("old", "p", "STARTED", Some("a")),
+        ];
+        let current = vec![
+            copy("logs", "p", "STARTED", Some("a")),
+            copy("logs", "r", "STARTED", Some("b")),
+            copy("logs", "r", "UNASSIGNED", None),
+            copy("new", "p", "INITIALIZING", Some("b")),
+        ];
+
+        let transitions = diff_shards(&previous, &current);
+        assert_eq!(transitions.len(), 3);
+
+        let replica = transitions.iter().find(|t| t.index == "logs").unwrap();
+        assert_eq!((replica.from.as_str(), replica.to.as_str()), ("INITIALIZING", "STARTED"));
+        assert_eq!(replica.node.as_deref(), Some("b"));
+        assert!(!replica.primary);
+
+        let created = transitions.iter().find(|t| t.index == "new").unwrap();
+        assert_eq!((created.from.as_str(), created.to.as_str()), ("NONE", "INITIALIZING"));
+
+        let deleted = transitions.iter().find(|t| t.index == "old").unwrap();
+        assert_eq!((deleted.from.as_str(), deleted.to.as_str()), ("STARTED", "NONE"));
+        assert_eq!(deleted.node.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn test_unchanged_shards_have_no_transitions() {
+        let shards = vec![copy("logs", "p", "STARTED", Some("a")), copy("logs", "r", "STARTED", Some("b"))];
+        assert!(diff_shards(&shards, &shards).is_empty());
+    }
+
+    #[test]
+    fn test_parses_metric_ticks() {
+        let stats = json!({
+            "nodes": {
+                "x": {
+                    "name": "node-x",
+                    "os": { "cpu": { "percent": 12 }, "mem": { "used_in_bytes": 30, "total_in_bytes": 60 } },
+                    "jvm": { "mem": { "heap_used_percent": 45 } },
+                    "fs": { "total": { "total_in_bytes": 200, "available_in_bytes": 150 } }
+                }
+            }
+        });
+
+        let ticks = parse_metric_ticks(&stats, &HashMap::new());
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].name, "node-x");
+        assert_eq!(ticks[0].cpu_percent, Some(12));
+        assert_eq!(ticks[0].ram_percent, Some(50));
+        assert_eq!(ticks[0].disk_percent, Some(25));
+        assert!(is_unsettled(&json!({ "initializing_shards": 1 })));
+        assert!(!is_unsettled(&json!({ "relocating_shards": 0, "unassigned_shards": 3 })));
+    }
+
+    #[test]
+    fn test_computes_rates_between_samples() {
+        let previous = NodeCounters { timestamp_ms: 10_000, index_total: 1_000, query_total: 100, query_time_ms: 500 };
+        let current = NodeCounters { timestamp_ms: 15_000, index_total: 1_500, query_total: 150, query_time_ms: 1_000 };
+
+        let rates = compute_rates(&previous, &current);
+        assert_eq!(rates.indexing_per_sec, Some(100.0));
+        assert_eq!(rates.search_per_sec, Some(10.0));
+        assert_eq!(rates.query_latency_ms, Some(10.0));
+
+        // Žádné dotazy = bez latence
+        let idle = NodeCounters { query_total: 100, query_time_ms: 500, ..current };
+        assert_eq!(compute_rates(&previous, &idle).query_latency_ms, None);
+
+        // Restart nodu vynuluje čítače
+        let restarted = NodeCounters { timestamp_ms: 20_000, index_total: 10, query_total: 1, query_time_ms: 1 };
+        assert_eq!(compute_rates(&current, &restarted), NodeRates::default());
+    }
+}
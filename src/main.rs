@@ -1,8 +1,12 @@
+mod alerts;
 mod config;
 mod db;
+mod diagnostics;
 mod es;
 mod handlers;
+mod live;
 mod models;
+mod sampler;
 mod templates;
 mod utils;
 
@@ -59,7 +63,15 @@ async fn main() -> Result<()> {
     tracing::info!("Database initialized successfully");
 
     // Shared state
-    let state = Arc::new(AppState { db });
+    let state = Arc::new(AppState {
+        db,
+        sampler: sampler::MetricsSampler::default(),
+        live: live::LiveHub::default(),
+        alerts: alerts::AlertEvaluator::default(),
+    });
+
+    // Vzorkování historických metrik na pozadí
+    sampler::spawn(state.clone());
 
     // Vytvoř axum router
     let app = Router::new()
@@ -74,14 +86,86 @@ async fn main() -> Result<()> {
         .route("/endpoints/{id}/test", post(handlers::endpoints::test_endpoint))
         .route("/nodes/{id}", get(handlers::nodes::node_detail))
         .route("/nodes/{id}/metrics", get(handlers::nodes::node_metrics))
+        .route("/nodes/{id}/diagnostics", get(handlers::node_diagnostics::node_diagnostics))
+        .route("/nodes/{id}/hot-threads", get(handlers::node_diagnostics::node_hot_threads))
+        .route("/events", get(handlers::events::live_events))
+        .route("/metrics/history", get(handlers::metrics::metrics_history))
+        .route("/nodes/{id}/drain-status", get(handlers::drain::drain_status))
+        .route("/nodes/{id}/drain", post(handlers::drain::drain_node))
+        .route("/nodes/{id}/undrain", post(handlers::drain::undrain_node))
         .route("/indices", get(handlers::indices::list_indices))
         .route("/indices/table", get(handlers::indices::indices_table))
         .route("/indices/metrics", get(handlers::indices::indices_metrics))
+        .route("/indices/create", get(handlers::index_create::create_index_page))
+        .route("/indices/create", post(handlers::index_create::create_index))
+        .route("/indices/create/source", get(handlers::index_create::index_create_source))
+        .route("/indices/create/validate", post(handlers::index_create::validate_create_index))
         .route("/indices/detail/{index_name}", get(handlers::indices::index_detail))
-        .route("/indices/bulk/{action}/{index_name}", post(handlers::indices::bulk_operation))
+        .route("/indices/settings/{index_name}", post(handlers::index_settings::update_index_settings))
+        .route("/indices/settings/{index_name}/preview", post(handlers::index_settings::preview_index_settings))
+        .route("/indices/mapping/{index_name}", post(handlers::mappings::update_mapping))
+        .route("/indices/mapping/{index_name}/validate", post(handlers::mappings::validate_mapping_update))
+        .route("/indices/bulk/{action}", post(handlers::indices::bulk_operation))
         .route("/search", get(handlers::search::search_page))
         .route("/search/bulk/delete", post(handlers::search::bulk_delete_documents))
         .route("/shards", get(handlers::shards::shards_page))
+        .route("/shards/explain", get(handlers::allocation::explain_allocation))
+        .route("/shards/unassigned", get(handlers::allocation::unassigned_summary_page))
+        .route("/shards/reroute", post(handlers::reroute::execute_reroute))
+        .route("/shards/reroute/preview", post(handlers::reroute::preview_reroute))
+        .route("/recovery", get(handlers::recovery::recovery_page))
+        .route("/recovery/table", get(handlers::recovery::recovery_table))
+        .route("/cluster/settings", get(handlers::cluster_settings::cluster_settings_page))
+        .route("/cluster/settings/update", post(handlers::cluster_settings::update_cluster_setting))
+        .route("/mappings", get(handlers::mappings::mappings_page))
+        .route("/aliases", get(handlers::aliases::aliases_page))
+        .route("/aliases/actions", post(handlers::aliases::alias_actions))
+        .route("/data-streams", get(handlers::data_streams::data_streams_page))
+        .route("/data-streams/create", post(handlers::data_streams::create_data_stream))
+        .route("/data-streams/{name}/rollover", post(handlers::data_streams::rollover_data_stream))
+        .route("/data-streams/{name}/delete", post(handlers::data_streams::delete_data_stream))
+        .route("/ilm", get(handlers::ilm::ilm_page))
+        .route("/ilm/policy/edit", get(handlers::ilm::ilm_policy_editor))
+        .route("/ilm/policy/save", post(handlers::ilm::save_ilm_policy))
+        .route("/ilm/policy/{name}/delete", post(handlers::ilm::delete_ilm_policy))
+        .route("/ilm/retry", post(handlers::ilm::retry_ilm))
+        .route("/ilm/move/{index_name}", post(handlers::ilm::move_to_step))
+        .route("/snapshots", get(handlers::snapshots::snapshots_page))
+        .route("/snapshots/repository/{name}/verify", post(handlers::snapshots::verify_repository))
+        .route("/snapshots/slm/{policy}/execute", post(handlers::snapshots::execute_slm_policy))
+        .route("/snapshots/{repository}/create", post(handlers::snapshots::create_snapshot))
+        .route("/snapshots/{repository}/{snapshot}/restore", post(handlers::snapshots::restore_snapshot))
+        .route("/snapshots/{repository}/{snapshot}/delete", post(handlers::snapshots::delete_snapshot))
+        .route("/snapshots/{repository}/{snapshot}/restore-progress", get(handlers::snapshots::restore_progress_page))
+        .route("/snapshots/{repository}/{snapshot}/restore-progress/table", get(handlers::snapshots::restore_progress_table))
+        .route("/pipelines", get(handlers::pipelines::pipelines_page))
+        .route("/pipelines/edit", get(handlers::pipelines::pipeline_editor_page))
+        .route("/pipelines/save", post(handlers::pipelines::save_pipeline))
+        .route("/pipelines/simulate", post(handlers::pipelines::simulate_pipeline))
+        .route("/pipelines/sample-docs", get(handlers::pipelines::sample_docs))
+        .route("/pipelines/{id}/delete", post(handlers::pipelines::delete_pipeline))
+        .route("/analyze", get(handlers::analyze::analyzer_page))
+        .route("/analyze/index-info", get(handlers::analyze::index_analysis_info))
+        .route("/analyze/run", post(handlers::analyze::run_analyze))
+        .route("/templates/index", get(handlers::templates::index_templates_page))
+        .route("/templates/component", get(handlers::templates::component_templates_page))
+        .route("/templates/simulate", get(handlers::templates::simulate_template_page))
+        .route("/templates/{kind}/edit", get(handlers::templates::template_editor_page))
+        .route("/templates/{kind}/save", post(handlers::templates::save_template))
+        .route("/templates/{kind}/{name}/delete", post(handlers::templates::delete_template))
+        .route("/tasks", get(handlers::tasks::tasks_page))
+        .route("/tasks/table", get(handlers::tasks::tasks_table))
+        .route("/tasks/detail/{task_id}", get(handlers::tasks::task_detail))
+        .route("/tasks/{task_id}/cancel", post(handlers::tasks::cancel_task))
+        .route("/advisor", get(handlers::advisor::advisor_page))
+        .route("/diagnostics/bundle", get(handlers::diagnostics::diagnostics_bundle))
+        .route("/alerts", get(handlers::alerts::alerts_page))
+        .route("/alerts/badge", get(handlers::alerts::alerts_badge))
+        .route("/alerts/rules", post(handlers::alerts::create_alert_rule))
+        .route("/alerts/rules/{id}/toggle", post(handlers::alerts::toggle_alert_rule))
+        .route("/alerts/rules/{id}/delete", post(handlers::alerts::delete_alert_rule))
+        .route("/alerts/settings", post(handlers::alerts::save_alert_settings))
+        .route("/alerts/test", post(handlers::alerts::test_alert_notification))
         .route("/console", get(handlers::console::console_page))
         .route("/console/execute", post(handlers::console::execute_request))
         .route("/console/history-table", get(handlers::console::console_history_table))
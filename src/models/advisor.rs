@@ -0,0 +1,121 @@
+use serde::Serialize;
+use crate::utils::format_bytes;
+
+/// Závažnost doporučení
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Severity {
+    Critical,
+    Warning,
+    Info,
+}
+
+/// Jedno zjištění advisoru s konkrétním doporučením
+#[derive(Debug, Serialize)]
+pub struct AdvisorFinding {
+    pub severity: Severity,
+    pub category: &'static str,
+    pub title: String,
+    pub detail: String,
+    pub suggestion: String,
+    pub items: Vec<String>,         // dotčené indexy / nody (zkráceno)
+    pub more_items: usize,          // kolik dalších se nevešlo
+}
+
+impl AdvisorFinding {
+    pub fn severity_class(&self) -> &'static str {
+        match self.severity {
+            Severity::Critical => "red",
+            Severity::Warning => "yellow",
+            Severity::Info => "azure",
+        }
+    }
+
+    pub fn severity_label(&self) -> &'static str {
+        match self.severity {
+            Severity::Critical => "Critical",
+            Severity::Warning => "Warning",
+            Severity::Info => "Info",
+        }
+    }
+}
+
+/// Souhrn nodu pro tabulku advisoru
+#[derive(Debug, Serialize)]
+pub struct NodeSizing {
+    pub name: String,
+    pub is_data: bool,
+    pub shards: usize,
+    pub store_bytes: u64,
+    pub heap_max_bytes: u64,
+    pub disk_total_bytes: u64,
+    pub disk_available_bytes: u64,
+}
+
+impl NodeSizing {
+    pub fn disk_used_percent(&self) -> f64 {
+        if self.disk_total_bytes == 0 {
+            return 0.0;
+        }
+        self.disk_total_bytes.saturating_sub(self.disk_available_bytes) as f64 * 100.0 / self.disk_total_bytes as f64
+    }
+
+    pub fn disk_used_percent_rounded(&self) -> u64 {
+        self.disk_used_percent().round() as u64
+    }
+
+    /// Počet shardů na GB heapu (doporučeno nejvýš 20)
+    pub fn shards_per_heap_gb(&self) -> Option<f64> {
+        let heap_gb = self.heap_max_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        (heap_gb > 0.0).then(|| (self.shards as f64 / heap_gb * 10.0).round() / 10.0)
+    }
+
+    pub fn store_formatted(&self) -> String {
+        format_bytes(self.store_bytes)
+    }
+
+    pub fn heap_formatted(&self) -> String {
+        format_bytes(self.heap_max_bytes)
+    }
+
+    pub fn disk_total_formatted(&self) -> String {
+        format_bytes(self.disk_total_bytes)
+    }
+}
+
+/// Data stránky advisoru
+#[derive(Debug, Serialize)]
+pub struct AdvisorData {
+    pub findings: Vec<AdvisorFinding>,
+    pub nodes: Vec<NodeSizing>,
+    pub total_shards: usize,
+    pub total_indices: usize,
+    pub total_store_bytes: u64,
+    pub watermarks: String,         // low / high / flood_stage pro zobrazení
+}
+
+impl AdvisorData {
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|f| f.severity == severity).count()
+    }
+
+    pub fn critical_count(&self) -> usize {
+        self.count(Severity::Critical)
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    pub fn info_count(&self) -> usize {
+        self.count(Severity::Info)
+    }
+
+    pub fn total_store_formatted(&self) -> String {
+        format_bytes(self.total_store_bytes)
+    }
+
+    /// Průměrná velikost shardu
+    pub fn avg_shard_formatted(&self) -> String {
+        format_bytes(self.total_store_bytes.checked_div(self.total_shards as u64).unwrap_or(0))
+    }
+}
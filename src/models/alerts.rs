@@ -0,0 +1,67 @@
+use serde::Serialize;
+use crate::db::models::{AlertEvent, AlertRule, AlertSettings};
+use crate::utils::format_duration_ms;
+
+fn format_timestamp(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Pravidlo s popisem podmínky a počtem aktivních incidentů
+#[derive(Debug, Serialize)]
+pub struct AlertRuleRow {
+    pub rule: AlertRule,
+    pub kind_label: String,
+    pub condition: String,
+    pub firing: usize,
+}
+
+/// Incident s názvem pravidla
+#[derive(Debug, Serialize)]
+pub struct AlertEventRow {
+    pub event: AlertEvent,
+    pub rule_name: String,
+}
+
+impl AlertEventRow {
+    pub fn is_open(&self) -> bool {
+        self.event.resolved_at.is_none()
+    }
+
+    /// Začátek incidentu (UTC)
+    pub fn started_formatted(&self) -> String {
+        format_timestamp(self.event.started_at)
+    }
+
+    pub fn resolved_formatted(&self) -> String {
+        self.event.resolved_at.map(format_timestamp).unwrap_or_else(|| "-".to_string())
+    }
+
+    /// Doba trvání (u otevřených do teď)
+    pub fn duration_formatted(&self) -> String {
+        let end = self.event.resolved_at.unwrap_or_else(|| chrono::Utc::now().timestamp());
+        format_duration_ms(end.saturating_sub(self.event.started_at).max(0) as u64 * 1000)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AlertsData {
+    pub rules: Vec<AlertRuleRow>,
+    pub events: Vec<AlertEventRow>,
+    pub settings: AlertSettings,
+}
+
+impl AlertsData {
+    pub fn open_count(&self) -> usize {
+        self.events.iter().filter(|e| e.is_open()).count()
+    }
+
+    pub fn webhook_url(&self) -> &str {
+        self.settings.webhook_url.as_deref().unwrap_or("")
+    }
+
+    pub fn notify_command(&self) -> &str {
+        self.settings.notify_command.as_deref().unwrap_or("")
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// Alias na konkrétním indexu (z `GET /_alias`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AliasEntry {
+    pub alias: String,
+    pub index: String,
+    pub filter: Option<String>,         // pretty JSON
+    pub index_routing: Option<String>,
+    pub search_routing: Option<String>,
+    pub is_write_index: Option<bool>,
+    pub is_hidden: bool,
+}
+
+/// Alias se všemi indexy, na které ukazuje
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AliasGroup {
+    pub name: String,
+    pub entries: Vec<AliasEntry>,
+}
+
+impl AliasGroup {
+    /// Index, do kterého alias zapisuje (explicitní is_write_index nebo jediný index)
+    pub fn write_index(&self) -> Option<&str> {
+        self.entries.iter()
+            .find(|e| e.is_write_index == Some(true))
+            .or_else(|| if self.entries.len() == 1 && self.entries[0].is_write_index != Some(false) {
+                self.entries.first()
+            } else {
+                None
+            })
+            .map(|e| e.index.as_str())
+    }
+
+    pub fn has_filter(&self) -> bool {
+        self.entries.iter().any(|e| e.filter.is_some())
+    }
+
+    pub fn has_routing(&self) -> bool {
+        self.entries.iter().any(|e| e.index_routing.is_some() || e.search_routing.is_some())
+    }
+
+    pub fn indices_json(&self) -> String {
+        let indices: Vec<&str> = self.entries.iter().map(|e| e.index.as_str()).collect();
+        serde_json::to_string(&indices).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    /// Akce pro přepnutí write indexu - `add` nahrazuje celou definici,
+    /// proto se filtr, routing a is_hidden každého indexu posílají znovu
+    pub fn write_index_actions_json(&self, index: &str) -> String {
+        let actions: Vec<serde_json::Value> = self.entries.iter().map(|e| serde_json::json!({
+            "action": "add",
+            "index": e.index,
+            "alias": self.name,
+            "filter": e.filter.as_deref().unwrap_or(""),
+            "index_routing": e.index_routing.as_deref().unwrap_or(""),
+            "search_routing": e.search_routing.as_deref().unwrap_or(""),
+            "is_hidden": e.is_hidden.then_some(true),
+            "is_write_index": e.index == index,
+        })).collect();
+        serde_json::to_string(&actions).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AliasesData {
+    pub aliases: Vec<AliasGroup>,
+    pub indices: Vec<String>,           // pro výběr v formulářích
+    pub filter: String,
+    pub hide_internal: bool,
+    pub total_entries: usize,
+}
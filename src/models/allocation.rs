@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+/// Rozhodnutí jednoho alokačního decideru
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AllocationDecider {
+    pub decider: String,                // same_shard, disk_threshold, filter, ...
+    pub decision: String,               // YES | NO | THROTTLE
+    pub explanation: String,
+}
+
+/// Rozhodnutí pro jeden node z `_cluster/allocation/explain`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NodeAllocationDecision {
+    pub node_id: String,
+    pub node_name: String,
+    pub node_decision: String,          // yes | no | throttled | worse_balance | awaiting_info
+    pub weight_ranking: Option<u64>,
+    pub store: Option<String>,          // shrnutí `store` (in-sync copy, store exception)
+    pub deciders: Vec<AllocationDecider>,
+}
+
+impl NodeAllocationDecision {
+    pub fn decision_color(&self) -> &'static str {
+        match self.node_decision.as_str() {
+            "yes" => "green",
+            "throttled" | "awaiting_info" => "yellow",
+            "worse_balance" => "azure",
+            _ => "red",
+        }
+    }
+}
+
+/// Výsledek `_cluster/allocation/explain` pro jednu kopii shardu
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AllocationExplain {
+    pub index: String,
+    pub shard: u64,
+    pub primary: bool,
+    pub current_state: String,          // unassigned | initializing | started | relocating
+    pub current_node: Option<String>,
+    pub unassigned_reason: Option<String>,
+    pub unassigned_at: Option<String>,
+    pub unassigned_details: Option<String>,
+    pub last_allocation_status: Option<String>,
+    pub can_allocate: Option<String>,
+    pub allocate_explanation: Option<String>,
+    pub can_remain_on_current_node: Option<String>,
+    pub can_rebalance_cluster: Option<String>,
+    pub rebalance_explanation: Option<String>,
+    pub node_decisions: Vec<NodeAllocationDecision>,
+}
+
+impl AllocationExplain {
+    pub fn can_allocate_color(&self) -> &'static str {
+        match self.can_allocate.as_deref() {
+            Some("yes") => "green",
+            Some("throttled") | Some("awaiting_info") | Some("allocation_delayed") => "yellow",
+            Some(_) => "red",
+            None => "secondary",
+        }
+    }
+
+    /// Decidery s rozhodnutím NO napříč všemi nody (bez duplicit, v pořadí výskytu)
+    pub fn blocking_deciders(&self) -> Vec<String> {
+        let mut deciders: Vec<String> = Vec::new();
+        for node in &self.node_decisions {
+            for d in &node.deciders {
+                if d.decision == "NO" && !deciders.contains(&d.decider) {
+                    deciders.push(d.decider.clone());
+                }
+            }
+        }
+        deciders
+    }
+}
+
+/// Počet shardů sdílejících stejnou příčinu (reason kód nebo decider)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AllocationCause {
+    pub name: String,
+    pub count: usize,
+    pub example: Option<String>,        // vysvětlení z prvního shardu s touto příčinou
+}
+
+/// Nepřiřazený shard z `_cat/shards`, případně s explain výsledkem
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnassignedShard {
+    pub index: String,
+    pub shard: String,
+    pub primary: bool,
+    pub reason: String,
+    pub explain: Option<AllocationExplain>,
+    pub error: Option<String>,
+}
+
+/// Souhrn "proč je cluster yellow/red"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnassignedSummaryData {
+    pub cluster_status: String,
+    pub total_unassigned: usize,
+    pub unassigned_primaries: usize,
+    pub explained: usize,               // počet shardů, pro které se volal explain (limit)
+    pub reasons: Vec<AllocationCause>,
+    pub deciders: Vec<AllocationCause>,
+    pub shards: Vec<UnassignedShard>,
+}
+
+impl UnassignedSummaryData {
+    pub fn status_color(&self) -> &'static str {
+        match self.cluster_status.as_str() {
+            "green" => "green",
+            "yellow" => "yellow",
+            _ => "red",
+        }
+    }
+}
+
+/// Kopie shardu z routing tabulky (`_cluster/reroute?dry_run` nebo `_cluster/state`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RoutingEntry {
+    pub index: String,
+    pub shard: u64,
+    pub primary: bool,
+    pub state: String,                  // STARTED | INITIALIZING | RELOCATING | UNASSIGNED
+    pub node: Option<String>,           // název nodu (id přeložené přes `nodes`)
+    pub relocating_node: Option<String>,
+    pub changed: bool,                  // liší se od aktuálního stavu clusteru
+}
+
+impl RoutingEntry {
+    pub fn state_color(&self) -> &'static str {
+        match self.state.as_str() {
+            "STARTED" => "green",
+            "RELOCATING" | "INITIALIZING" => "yellow",
+            _ => "red",
+        }
+    }
+}
+
+/// Vysvětlení jednoho reroute příkazu (`explain=true`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RerouteExplanation {
+    pub command: String,
+    pub parameters: String,
+    pub decisions: Vec<AllocationDecider>,
+}
+
+/// Náhled výsledku reroute (dry run)
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ReroutePreviewData {
+    pub explanations: Vec<RerouteExplanation>,
+    pub routing: Vec<RoutingEntry>,
+    pub changed: usize,
+    pub error: Option<String>,
+}
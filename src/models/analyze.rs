@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// Vestavěné analyzery Elasticsearch (pro výběr v playgroundu)
+pub const BUILTIN_ANALYZERS: &[&str] = &[
+    "standard", "simple", "whitespace", "stop", "keyword", "pattern", "fingerprint", "english", "czech", "german",
+];
+
+/// Token z `_analyze`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AnalyzeToken {
+    pub token: String,
+    pub start_offset: u64,
+    pub end_offset: u64,
+    pub position: u64,
+    pub token_type: String,             // <ALPHANUM>, <NUM>, word, SYNONYM, ...
+    pub position_length: Option<u64>,   // > 1 u víceslovných synonym
+}
+
+/// Konfigurace jedné strany playgroundu
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AnalyzeForm {
+    #[serde(default)]
+    pub mode: String,                   // field | analyzer | custom
+    #[serde(default)]
+    pub index: String,
+    #[serde(default)]
+    pub field: String,
+    #[serde(default)]
+    pub analyzer: String,               // vestavěný nebo custom analyzer indexu
+    #[serde(default)]
+    pub tokenizer: String,
+    #[serde(default)]
+    pub filters: String,                // jeden filtr na řádek (název nebo JSON objekt)
+    #[serde(default)]
+    pub char_filters: String,
+}
+
+/// Výsledek analýzy jedné strany
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct AnalyzeResult {
+    pub label: String,                  // popis konfigurace (např. `logs: message`)
+    pub tokens: Vec<AnalyzeToken>,
+    pub error: Option<String>,
+}
+
+/// Data stránky playgroundu (předvyplnění z odkazu, např. ze Search)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalyzerPageData {
+    pub text: String,
+    pub left: AnalyzeForm,
+    pub right: AnalyzeForm,
+    pub compare: bool,
+    pub indices: Vec<String>,
+}
+
+impl AnalyzerPageData {
+    pub fn builtin_analyzers(&self) -> &'static [&'static str] {
+        BUILTIN_ANALYZERS
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+/// Často měněné cluster settings (zobrazené jako formulář nad tabulkou)
+pub struct CommonSettingDef {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub options: &'static [&'static str],   // prázdné = volný text
+}
+
+pub const COMMON_SETTINGS: &[CommonSettingDef] = &[
+    CommonSettingDef {
+        key: "cluster.routing.allocation.enable",
+        label: "Shard allocation",
+        description: "Which shards may be allocated. Set to primaries before a rolling restart.",
+        options: &["all", "primaries", "new_primaries", "none"],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.rebalance.enable",
+        label: "Shard rebalancing",
+        description: "Which shards may be rebalanced between nodes.",
+        options: &["all", "primaries", "replicas", "none"],
+    },
+    CommonSettingDef {
+        key: "indices.recovery.max_bytes_per_sec",
+        label: "Recovery throttle",
+        description: "Maximum recovery traffic per node, e.g. 40mb or 200mb.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.node_concurrent_recoveries",
+        label: "Concurrent recoveries per node",
+        description: "Incoming and outgoing shard recoveries allowed on a node at once.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.disk.watermark.low",
+        label: "Disk watermark low",
+        description: "No new replicas are allocated to nodes above this disk usage.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.disk.watermark.high",
+        label: "Disk watermark high",
+        description: "Shards are relocated away from nodes above this disk usage.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.disk.watermark.flood_stage",
+        label: "Disk watermark flood stage",
+        description: "Indices with a shard on a node above this usage become read-only.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.exclude._ip",
+        label: "Excluded IPs (drain)",
+        description: "Comma-separated node IPs that should hold no shards.",
+        options: &[],
+    },
+    CommonSettingDef {
+        key: "cluster.routing.allocation.exclude._name",
+        label: "Excluded node names (drain)",
+        description: "Comma-separated node names that should hold no shards.",
+        options: &[],
+    },
+];
+
+/// Jedno nastavení z `GET /_cluster/settings?include_defaults&flat_settings`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClusterSettingRow {
+    pub key: String,
+    pub persistent: Option<String>,
+    pub transient: Option<String>,
+    pub default: Option<String>,
+}
+
+impl ClusterSettingRow {
+    /// Platná hodnota: transient > persistent > default
+    pub fn effective(&self) -> String {
+        self.transient.as_ref()
+            .or(self.persistent.as_ref())
+            .or(self.default.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn source(&self) -> &'static str {
+        if self.transient.is_some() {
+            "transient"
+        } else if self.persistent.is_some() {
+            "persistent"
+        } else {
+            "default"
+        }
+    }
+
+    /// Explicitně nastavené a liší se od defaultu
+    pub fn is_changed(&self) -> bool {
+        self.source() != "default" && self.default.as_deref() != Some(self.effective().as_str())
+    }
+}
+
+/// Formulářová položka pro běžná nastavení
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommonSetting {
+    pub key: String,
+    pub label: String,
+    pub description: String,
+    pub options: Vec<String>,
+    pub row: ClusterSettingRow,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClusterSettingsData {
+    pub common: Vec<CommonSetting>,
+    pub rows: Vec<ClusterSettingRow>,
+    pub query: String,
+    pub show: String,                       // changed | explicit | all
+    pub total: usize,                       // počet řádků odpovídajících filtru (před limitem)
+    pub transient_deprecated: bool,         // ES 7.16+ transient settings nedoporučuje
+}
+
+impl ClusterSettingsData {
+    pub fn truncated(&self) -> bool {
+        self.total > self.rows.len()
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use crate::utils::{format_bytes, format_number};
+
+/// Backing index data streamu
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BackingIndex {
+    pub name: String,
+    pub health: String,
+    pub docs_count: u64,
+    pub store_size_bytes: u64,
+    pub ilm_policy: Option<String>,
+    pub managed_by: Option<String>,     // "Index Lifecycle Management" / "Data stream lifecycle" (8.11+)
+    pub is_write_index: bool,
+}
+
+impl BackingIndex {
+    pub fn docs_count_formatted(&self) -> String {
+        format_number(self.docs_count)
+    }
+
+    pub fn store_size_formatted(&self) -> String {
+        format_bytes(self.store_size_bytes)
+    }
+}
+
+/// Data stream z `GET /_data_stream` doplněný o `_data_stream/_stats`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DataStreamInfo {
+    pub name: String,
+    pub status: String,                 // GREEN / YELLOW / RED
+    pub generation: u64,
+    pub template: String,
+    pub ilm_policy: Option<String>,
+    pub lifecycle: Option<String>,      // data stream lifecycle retention, "infinite" bez retence
+    pub timestamp_field: String,
+    pub hidden: bool,
+    pub system: bool,
+    pub backing_indices: Vec<BackingIndex>,
+    pub store_size_bytes: u64,
+    pub maximum_timestamp: Option<i64>,
+}
+
+impl DataStreamInfo {
+    pub fn store_size_formatted(&self) -> String {
+        format_bytes(self.store_size_bytes)
+    }
+
+    pub fn docs_count_formatted(&self) -> String {
+        format_number(self.backing_indices.iter().map(|i| i.docs_count).sum())
+    }
+
+    pub fn write_index(&self) -> Option<&str> {
+        self.backing_indices.iter()
+            .find(|i| i.is_write_index)
+            .map(|i| i.name.as_str())
+    }
+
+    pub fn maximum_timestamp_formatted(&self) -> String {
+        self.maximum_timestamp
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    pub fn status_color(&self) -> &'static str {
+        match self.status.to_lowercase().as_str() {
+            "green" => "success",
+            "yellow" => "warning",
+            "red" => "danger",
+            _ => "secondary",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataStreamsData {
+    pub data_streams: Vec<DataStreamInfo>,
+    pub filter: String,
+    pub show_hidden: bool,
+    pub templates: Vec<String>,         // data stream templates pro formulář "Create"
+}
+
+/// Mapa backing index → data stream (pro seskupení v seznamu indexů)
+pub fn backing_index_map(streams: &Value) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    if let Some(items) = streams["data_streams"].as_array() {
+        for ds in items {
+            let Some(name) = ds["name"].as_str() else {
+                continue;
+            };
+            for index in ds["indices"].as_array().into_iter().flatten() {
+                if let Some(index_name) = index["index_name"].as_str() {
+                    map.insert(index_name.to_string(), name.to_string());
+                }
+            }
+        }
+    }
+    map
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Fáze ILM v pořadí, v jakém jimi index prochází
+pub const ILM_PHASES: &[&str] = &["hot", "warm", "cold", "frozen", "delete"];
+
+/// Souhrn jedné fáze politiky pro seznam
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IlmPhaseSummary {
+    pub name: String,
+    pub min_age: String,
+    pub actions: Vec<String>,
+}
+
+impl IlmPhaseSummary {
+    pub fn color(&self) -> &'static str {
+        match self.name.as_str() {
+            "hot" => "red",
+            "warm" => "orange",
+            "cold" => "azure",
+            "frozen" => "indigo",
+            "delete" => "secondary",
+            _ => "secondary",
+        }
+    }
+}
+
+/// ILM politika z `GET /_ilm/policy`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IlmPolicySummary {
+    pub name: String,
+    pub version: Option<i64>,
+    pub modified_date: String,
+    pub phases: Vec<IlmPhaseSummary>,
+    pub in_use_indices: Option<usize>,  // `in_use_by` je až od ES 7.12
+    pub in_use_data_streams: Option<usize>,
+    pub managed: bool,
+}
+
+/// Stav indexu z `GET /{index}/_ilm/explain`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IlmExplain {
+    pub index: String,
+    pub managed: bool,
+    pub policy: Option<String>,
+    pub phase: Option<String>,
+    pub action: Option<String>,
+    pub step: Option<String>,
+    pub age: Option<String>,
+    pub failed_step: Option<String>,
+    pub step_info: Option<String>,      // reason chyby nebo pretty JSON
+    pub is_auto_retryable_error: bool,
+    pub failed_step_retry_count: u64,
+    pub phase_definition: Option<String>, // pretty JSON aktuální fáze
+}
+
+impl IlmExplain {
+    pub fn is_error(&self) -> bool {
+        self.step.as_deref() == Some("ERROR")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IlmPoliciesData {
+    pub policies: Vec<IlmPolicySummary>,
+    pub error_indices: Vec<IlmExplain>,
+    pub operation_mode: String,         // RUNNING / STOPPING / STOPPED
+    pub filter: String,
+    pub show_managed: bool,
+    pub tab: String,                    // policies | errors
+}
+
+/// Formulář jedné fáze ve strukturovaném editoru (hodnoty jako text, prázdné = nenastaveno)
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IlmPhaseForm {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub min_age: String,
+    #[serde(default)]
+    pub rollover_max_age: String,
+    #[serde(default)]
+    pub rollover_max_primary_shard_size: String,
+    #[serde(default)]
+    pub rollover_max_docs: String,
+    #[serde(default)]
+    pub forcemerge_segments: String,
+    #[serde(default)]
+    pub shrink_shards: String,
+    #[serde(default)]
+    pub replicas: String,               // allocate.number_of_replicas
+    #[serde(default)]
+    pub priority: String,               // set_priority
+    #[serde(default)]
+    pub readonly: bool,
+    #[serde(default)]
+    pub searchable_snapshot_repository: String,
+    #[serde(default)]
+    pub delete_index: bool,             // akce `delete` v delete fázi
+    #[serde(default)]
+    pub delete_searchable_snapshot: bool,
+    #[serde(default)]
+    pub extra_actions: String,          // JSON ostatních akcí, které formulář nepokrývá
+}
+
+/// Data pro editor politiky
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IlmPolicyEditorData {
+    pub name: String,
+    pub is_new: bool,
+    pub phases: BTreeMap<String, IlmPhaseForm>,
+    pub meta_json: String,
+    pub raw_json: String,               // celé body `{"policy": ...}`
+}
+
+impl IlmPolicyEditorData {
+    pub fn phase(&self, name: &str) -> IlmPhaseForm {
+        self.phases.get(name).cloned().unwrap_or_default()
+    }
+
+    pub fn phase_names(&self) -> &'static [&'static str] {
+        ILM_PHASES
+    }
+}
@@ -19,6 +19,10 @@ pub struct IndexInfo {
     pub pri_store_size: String, // velikost primary shards
     #[serde(skip)]
     pub aliases: Vec<String>,  // seznam aliasů
+    #[serde(skip)]
+    pub data_stream: Option<String>, // data stream, pokud jde o backing index
+    #[serde(skip)]
+    pub stream_header: Option<usize>, // první řádek skupiny: počet backing indexů streamu
 }
 
 #[derive(Debug, Deserialize)]
@@ -88,7 +92,10 @@ pub struct IndexDetail {
     pub pri_store_size: String,
     pub aliases: Vec<String>,
     pub settings: Option<String>,  // JSON formatted
+    pub settings_editable: Option<String>,  // flat JSON pro editor
     pub mappings: Option<String>,  // JSON formatted
+    pub mapping_fields: Vec<crate::models::MappingField>,
+    pub ilm: Option<crate::models::IlmExplain>, // `_ilm/explain`, None bez ILM (OSS, ES < 6.6)
     pub stats: Option<String>,     // JSON formatted
     pub stats_docs_count: Option<u64>,
     pub stats_docs_deleted: Option<u64>,
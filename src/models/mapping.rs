@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// Jeden field z mappingu indexu (strom zploštělý podle `depth`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MappingField {
+    pub path: String,                   // plná cesta, např. user.name.keyword
+    pub name: String,
+    pub depth: usize,
+    pub field_type: String,
+    pub analyzer: Option<String>,
+    pub search_analyzer: Option<String>,
+    pub normalizer: Option<String>,
+    pub index: Option<bool>,            // None = neaplikuje se (object, nested)
+    pub doc_values: Option<bool>,
+    pub is_multi_field: bool,           // field pod `fields`
+    pub is_runtime: bool,               // field z `runtime` sekce
+    pub details: Vec<String>,           // ostatní parametry (format, ignore_above, ...)
+}
+
+impl MappingField {
+    /// Field s dalšími vnořenými fieldy (object / nested)
+    pub fn is_container(&self) -> bool {
+        self.field_type == "object" || self.field_type == "nested"
+    }
+}
+
+/// Typ fieldu z `_field_caps` a indexy, kde platí
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FieldCapsType {
+    pub field_type: String,
+    pub searchable: bool,
+    pub aggregatable: bool,
+    pub indices: Vec<String>,           // prázdné = všechny indexy
+}
+
+/// Field napříč indexy (výsledek `_field_caps`)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FieldCapsEntry {
+    pub name: String,
+    pub types: Vec<FieldCapsType>,
+}
+
+impl FieldCapsEntry {
+    /// Field má v různých indexech různé typy
+    pub fn is_conflict(&self) -> bool {
+        self.types.len() > 1
+    }
+
+    pub fn types_label(&self) -> String {
+        self.types.iter()
+            .map(|t| t.field_type.as_str())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MappingExplorerData {
+    pub pattern: String,
+    pub fields: Vec<FieldCapsEntry>,
+    pub indices_count: usize,
+    pub conflicts: usize,
+    pub conflicts_only: bool,
+}
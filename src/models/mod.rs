@@ -1,7 +1,37 @@
 pub mod dashboard;
 pub mod node;
 pub mod index;
+pub mod task;
+pub mod mapping;
+pub mod alias;
+pub mod template;
+pub mod data_stream;
+pub mod ilm;
+pub mod snapshot;
+pub mod pipeline;
+pub mod analyze;
+pub mod allocation;
+pub mod recovery;
+pub mod cluster_settings;
+pub mod alerts;
+pub mod advisor;
+pub mod watermark;
 
 pub use dashboard::*;
 pub use node::*;
 pub use index::*;
+pub use task::*;
+pub use mapping::*;
+pub use alias::*;
+pub use template::*;
+pub use data_stream::*;
+pub use ilm::*;
+pub use snapshot::*;
+pub use pipeline::*;
+pub use analyze::*;
+pub use allocation::*;
+pub use recovery::*;
+pub use cluster_settings::*;
+pub use alerts::*;
+pub use advisor::*;
+pub use watermark::*;
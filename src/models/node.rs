@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::utils::{format_bytes, format_duration_ms, format_number};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeDetail {
@@ -25,6 +26,9 @@ pub struct NodeDetail {
     pub docs_count: u64,
     pub docs_deleted: u64,
     pub store_size_bytes: u64,
+
+    // Rozšířené statistiky z `_nodes/{id}/stats`
+    pub extended: NodeExtendedStats,
 }
 
 impl NodeDetail {
@@ -76,3 +80,274 @@ impl NodeDetail {
         Self::format_bytes(self.store_size_bytes)
     }
 }
+
+/// Cache nodu (fielddata, query, request)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodeCacheStats {
+    pub name: String,
+    pub memory_bytes: u64,
+    pub evictions: u64,
+    pub hit_count: Option<u64>,     // fielddata hit/miss nevrací
+    pub miss_count: Option<u64>,
+}
+
+impl NodeCacheStats {
+    pub fn memory_formatted(&self) -> String {
+        format_bytes(self.memory_bytes)
+    }
+
+    pub fn evictions_formatted(&self) -> String {
+        format_number(self.evictions)
+    }
+
+    /// Poměr zásahů v procentech
+    pub fn hit_ratio(&self) -> Option<u64> {
+        let hits = self.hit_count?;
+        (hits * 100).checked_div(hits + self.miss_count?)
+    }
+}
+
+/// Využití jedné datové cesty (`fs.data`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DataPathUsage {
+    pub path: String,
+    pub mount: String,
+    pub fs_type: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+impl DataPathUsage {
+    pub fn used_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.available_bytes)
+    }
+
+    pub fn used_percent(&self) -> u64 {
+        (self.used_bytes() * 100).checked_div(self.total_bytes).unwrap_or(0)
+    }
+
+    pub fn used_formatted(&self) -> String {
+        format_bytes(self.used_bytes())
+    }
+
+    pub fn total_formatted(&self) -> String {
+        format_bytes(self.total_bytes)
+    }
+}
+
+/// Segmenty, cache, file deskriptory, síť a datové cesty nodu
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NodeExtendedStats {
+    pub index_total: u64,
+    pub query_total: u64,
+    pub query_time_ms: u64,
+    pub segments_count: u64,
+    pub segments_memory_bytes: Option<u64>,   // od ES 8 se nepočítá (vždy 0)
+    pub caches: Vec<NodeCacheStats>,
+    pub open_file_descriptors: Option<u64>,
+    pub max_file_descriptors: Option<u64>,
+    pub http_current_open: u64,
+    pub http_total_opened: u64,
+    pub transport_server_open: u64,
+    pub transport_rx_bytes: u64,
+    pub transport_tx_bytes: u64,
+    pub transport_rx_count: u64,
+    pub transport_tx_count: u64,
+    pub data_paths: Vec<DataPathUsage>,
+}
+
+impl NodeExtendedStats {
+    pub fn file_descriptors_percent(&self) -> Option<u64> {
+        (self.open_file_descriptors? * 100).checked_div(self.max_file_descriptors?)
+    }
+
+    /// Více než 80 % limitu otevřených souborů
+    pub fn file_descriptors_high(&self) -> bool {
+        self.file_descriptors_percent().is_some_and(|pct| pct >= 80)
+    }
+
+    /// Průměrná latence dotazu od startu nodu
+    pub fn avg_query_latency_ms(&self) -> Option<u64> {
+        self.query_time_ms.checked_div(self.query_total)
+    }
+
+    pub fn segments_memory_formatted(&self) -> String {
+        match self.segments_memory_bytes {
+            Some(bytes) if bytes > 0 => format_bytes(bytes),
+            _ => "-".to_string(),
+        }
+    }
+
+    pub fn index_total_formatted(&self) -> String {
+        format_number(self.index_total)
+    }
+
+    pub fn query_total_formatted(&self) -> String {
+        format_number(self.query_total)
+    }
+
+    pub fn segments_count_formatted(&self) -> String {
+        format_number(self.segments_count)
+    }
+
+    pub fn http_total_opened_formatted(&self) -> String {
+        format_number(self.http_total_opened)
+    }
+
+    pub fn transport_rx_formatted(&self) -> String {
+        format_bytes(self.transport_rx_bytes)
+    }
+
+    pub fn transport_tx_formatted(&self) -> String {
+        format_bytes(self.transport_tx_bytes)
+    }
+
+    pub fn transport_rx_count_formatted(&self) -> String {
+        format_number(self.transport_rx_count)
+    }
+
+    pub fn transport_tx_count_formatted(&self) -> String {
+        format_number(self.transport_tx_count)
+    }
+}
+
+/// Stav vyprázdnění (drain) nodu přes `cluster.routing.allocation.exclude._name`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodeDrainStatus {
+    pub node_id: String,
+    pub node_name: String,
+    pub draining: bool,                 // název nodu je v platném exclude._name
+    pub exclude_scope: String,          // "persistent" / "transient" - odkud se exclude._name bere
+    pub other_exclusions: Vec<String>,  // další exclude pravidla, která node zasahují (_ip, _host, _id)
+    pub shards_on_node: usize,          // včetně shardů, které právě odcházejí
+    pub relocating_away: usize,
+    pub start_count: Option<usize>,     // počet shardů při spuštění drainu (z UI)
+}
+
+impl NodeDrainStatus {
+    pub fn is_empty(&self) -> bool {
+        self.shards_on_node == 0
+    }
+
+    pub fn progress_percent(&self) -> usize {
+        match self.start_count {
+            Some(start) if start > 0 => start.saturating_sub(self.shards_on_node) * 100 / start,
+            _ => if self.is_empty() { 100 } else { 0 },
+        }
+    }
+}
+
+/// Řádek z `_cat/thread_pool` pro jeden node
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ThreadPoolRow {
+    pub name: String,
+    pub pool_type: String,
+    pub size: Option<u64>,
+    pub active: u64,
+    pub queue: u64,
+    pub queue_size: Option<u64>,     // -1 = neomezená fronta
+    pub rejected: u64,
+    pub completed: u64,
+}
+
+impl ThreadPoolRow {
+    /// Pool, který právě pracuje nebo něco odmítl
+    pub fn is_busy(&self) -> bool {
+        self.active > 0 || self.queue > 0 || self.rejected > 0
+    }
+
+    pub fn queue_limit(&self) -> String {
+        self.queue_size.map(|v| v.to_string()).unwrap_or_else(|| "∞".to_string())
+    }
+
+    pub fn completed_formatted(&self) -> String {
+        format_number(self.completed)
+    }
+}
+
+/// Circuit breaker z `_nodes/stats/breaker`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BreakerRow {
+    pub name: String,
+    pub limit_bytes: u64,
+    pub estimated_bytes: u64,
+    pub overhead: f64,
+    pub tripped: u64,
+}
+
+impl BreakerRow {
+    pub fn used_percent(&self) -> u64 {
+        (self.estimated_bytes * 100).checked_div(self.limit_bytes).unwrap_or(0)
+    }
+
+    pub fn limit_formatted(&self) -> String {
+        format_bytes(self.limit_bytes)
+    }
+
+    pub fn estimated_formatted(&self) -> String {
+        format_bytes(self.estimated_bytes)
+    }
+}
+
+/// GC kolektor z `jvm.gc.collectors`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GcCollector {
+    pub name: String,
+    pub collection_count: u64,
+    pub collection_time_ms: u64,
+}
+
+impl GcCollector {
+    pub fn total_time_formatted(&self) -> String {
+        format_duration_ms(self.collection_time_ms)
+    }
+
+    pub fn avg_time_ms(&self) -> u64 {
+        self.collection_time_ms.checked_div(self.collection_count).unwrap_or(0)
+    }
+
+    pub fn count_formatted(&self) -> String {
+        format_number(self.collection_count)
+    }
+}
+
+/// Diagnostika nodu: thread pooly, circuit breakery a GC
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NodeDiagnostics {
+    pub node_id: String,
+    pub thread_pools: Vec<ThreadPoolRow>,
+    pub breakers: Vec<BreakerRow>,
+    pub gc_collectors: Vec<GcCollector>,
+    pub jvm_uptime_ms: u64,
+}
+
+impl NodeDiagnostics {
+    pub fn rejected_total(&self) -> u64 {
+        self.thread_pools.iter().map(|p| p.rejected).sum()
+    }
+
+    pub fn tripped_total(&self) -> u64 {
+        self.breakers.iter().map(|b| b.tripped).sum()
+    }
+
+    pub fn uptime_formatted(&self) -> String {
+        format_duration_ms(self.jvm_uptime_ms)
+    }
+}
+
+/// Jedno vlákno z výstupu `_nodes/{id}/hot_threads`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HotThread {
+    pub percent: f64,
+    pub summary: String,            // "12.3% [cpu=12.3%, other=0.0%] (61.5ms out of 500ms) cpu usage by thread ..."
+    pub thread_name: String,
+    pub stack: Vec<String>,
+}
+
+/// Zpracovaný výstup hot threads
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HotThreadsReport {
+    pub header: Vec<String>,
+    pub threads: Vec<HotThread>,
+    pub raw: String,
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Ingest pipeline z `GET /_ingest/pipeline`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PipelineSummary {
+    pub id: String,
+    pub description: String,
+    pub processor_types: Vec<String>,   // typy procesorů v pořadí
+    pub on_failure: bool,
+    pub version: Option<i64>,
+    pub managed: bool,
+    pub deprecated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelinesData {
+    pub pipelines: Vec<PipelineSummary>,
+    pub filter: String,
+    pub show_managed: bool,
+}
+
+/// Data pro editor pipeline
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipelineEditorData {
+    pub id: String,
+    pub is_new: bool,
+    pub description: String,
+    pub processors: Vec<String>,        // pretty JSON jednotlivých procesorů, `{"set": {...}}`
+    pub on_failure_json: String,
+    pub extra_json: String,             // ostatní pole (version, _meta, ...)
+    pub raw_json: String,               // celé body `PUT _ingest/pipeline/{id}`
+}
+
+/// Výsledek jednoho procesoru ze `_simulate?verbose`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SimulateStep {
+    pub processor_type: String,
+    pub tag: Option<String>,
+    pub status: String,                 // success, error, error_ignored, skipped, dropped
+    pub condition: Option<String>,      // `if` podmínka a její výsledek
+    pub error: Option<String>,
+    pub doc_json: Option<String>,       // dokument po procesoru
+}
+
+impl SimulateStep {
+    pub fn status_color(&self) -> &'static str {
+        match self.status.as_str() {
+            "success" => "success",
+            "error" => "danger",
+            "error_ignored" => "warning",
+            "dropped" => "purple",
+            _ => "secondary",
+        }
+    }
+}
+
+/// Výsledek simulace pro jeden dokument
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SimulatedDoc {
+    pub label: String,
+    pub steps: Vec<SimulateStep>,
+    pub result_json: Option<String>,    // výsledný dokument (non-verbose nebo poslední krok)
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct PipelineSimulateData {
+    pub docs: Vec<SimulatedDoc>,
+    pub error: Option<String>,
+}
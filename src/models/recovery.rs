@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use crate::utils::{format_bytes, format_duration_ms};
+
+/// Průběh recovery jednoho shardu z `GET /_recovery`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShardRecovery {
+    pub index: String,
+    pub shard: u64,
+    pub primary: bool,
+    pub recovery_type: String,          // SNAPSHOT, PEER, EXISTING_STORE, EMPTY_STORE, LOCAL_SHARDS
+    pub stage: String,                  // INIT, INDEX, VERIFY_INDEX, TRANSLOG, FINALIZE, DONE
+    pub source: String,                 // node nebo repository/snapshot
+    pub target_node: String,
+    pub bytes_total: u64,
+    pub bytes_recovered: u64,
+    pub files_total: u64,
+    pub files_recovered: u64,
+    pub translog_percent: f64,
+    pub total_time_ms: u64,
+    pub throttle_time_ms: u64,          // source + target throttle (indices.recovery.max_bytes_per_sec)
+}
+
+impl ShardRecovery {
+    pub fn is_done(&self) -> bool {
+        self.stage == "DONE"
+    }
+
+    pub fn bytes_percent(&self) -> f64 {
+        if self.bytes_total == 0 {
+            if self.is_done() { 100.0 } else { 0.0 }
+        } else {
+            (self.bytes_recovered as f64 / self.bytes_total as f64 * 100.0).min(100.0)
+        }
+    }
+
+    pub fn bytes_percent_formatted(&self) -> String {
+        format!("{:.1}", self.bytes_percent())
+    }
+
+    pub fn bytes_formatted(&self) -> String {
+        format!("{} / {}", format_bytes(self.bytes_recovered), format_bytes(self.bytes_total))
+    }
+
+    pub fn files_percent_formatted(&self) -> String {
+        let percent = if self.files_total == 0 {
+            if self.is_done() { 100.0 } else { 0.0 }
+        } else {
+            self.files_recovered as f64 / self.files_total as f64 * 100.0
+        };
+        format!("{:.1}", percent.min(100.0))
+    }
+
+    /// Průměrná rychlost od začátku recovery (B/s)
+    pub fn throughput(&self) -> f64 {
+        if self.total_time_ms == 0 {
+            return 0.0;
+        }
+        self.bytes_recovered as f64 / (self.total_time_ms as f64 / 1000.0)
+    }
+
+    pub fn throughput_formatted(&self) -> String {
+        format!("{}/s", format_bytes(self.throughput() as u64))
+    }
+
+    /// Odhad zbývajícího času při současné rychlosti
+    pub fn eta_ms(&self) -> Option<u64> {
+        let remaining = self.bytes_total.saturating_sub(self.bytes_recovered);
+        if remaining == 0 {
+            return Some(0);
+        }
+        let throughput = self.throughput();
+        if throughput <= 0.0 {
+            return None;
+        }
+        Some((remaining as f64 / throughput * 1000.0) as u64)
+    }
+
+    pub fn eta_formatted(&self) -> String {
+        self.eta_ms().map(format_duration_ms).unwrap_or_else(|| "-".to_string())
+    }
+
+    pub fn total_time_formatted(&self) -> String {
+        format_duration_ms(self.total_time_ms)
+    }
+
+    pub fn throttle_time_formatted(&self) -> String {
+        format_duration_ms(self.throttle_time_ms)
+    }
+}
+
+/// Data recovery monitoru (aktivní recovery a relokace)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecoveryMonitorData {
+    pub shards: Vec<ShardRecovery>,
+    pub initializing: u32,              // z `_cluster/health`
+    pub relocating: u32,
+    pub unassigned: u32,
+}
+
+impl RecoveryMonitorData {
+    pub fn bytes_total(&self) -> u64 {
+        self.shards.iter().map(|s| s.bytes_total).sum()
+    }
+
+    pub fn bytes_recovered(&self) -> u64 {
+        self.shards.iter().map(|s| s.bytes_recovered).sum()
+    }
+
+    pub fn bytes_formatted(&self) -> String {
+        format!("{} / {}", format_bytes(self.bytes_recovered()), format_bytes(self.bytes_total()))
+    }
+
+    pub fn overall_percent(&self) -> String {
+        let total = self.bytes_total();
+        let percent = if total == 0 { 0.0 } else { self.bytes_recovered() as f64 / total as f64 * 100.0 };
+        format!("{:.1}", percent)
+    }
+
+    /// Součet rychlostí běžících recovery (souběžné recovery se sčítají)
+    pub fn throughput(&self) -> f64 {
+        self.shards.iter().filter(|s| !s.is_done()).map(|s| s.throughput()).sum()
+    }
+
+    pub fn throughput_formatted(&self) -> String {
+        format!("{}/s", format_bytes(self.throughput() as u64))
+    }
+
+    /// Hrubý odhad pro celý cluster: zbývající bajty / celková rychlost.
+    /// Shardy čekající ve frontě (ještě bez `_recovery` záznamu) nezapočítává.
+    pub fn eta_ms(&self) -> Option<u64> {
+        let remaining = self.bytes_total().saturating_sub(self.bytes_recovered());
+        if remaining == 0 {
+            return None;
+        }
+        let throughput = self.throughput();
+        if throughput <= 0.0 {
+            return None;
+        }
+        Some((remaining as f64 / throughput * 1000.0) as u64)
+    }
+
+    pub fn eta_formatted(&self) -> String {
+        self.eta_ms().map(format_duration_ms).unwrap_or_else(|| "-".to_string())
+    }
+
+    /// Počet shardů, které čekají na recovery a v `_recovery` ještě nejsou
+    pub fn queued(&self) -> u32 {
+        (self.initializing + self.relocating).saturating_sub(self.shards.len() as u32)
+    }
+}
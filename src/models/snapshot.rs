@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use super::ShardRecovery;
+
+/// Snapshot repository z `GET /_snapshot`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SnapshotRepository {
+    pub name: String,
+    pub repo_type: String,              // fs, s3, gcs, azure, url, source, ...
+    pub location: String,               // location / bucket / container, pokud je v settings
+    pub settings: String,               // pretty JSON
+}
+
+/// Snapshot z `GET /_snapshot/{repo}/_all`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SnapshotInfo {
+    pub repository: String,
+    pub snapshot: String,
+    pub state: String,                  // SUCCESS, IN_PROGRESS, PARTIAL, FAILED, INCOMPATIBLE
+    pub start_time_ms: i64,
+    pub duration_ms: i64,
+    pub indices: Vec<String>,
+    pub data_streams: Vec<String>,
+    pub shards_total: u64,
+    pub shards_failed: u64,
+    pub failures: Vec<String>,
+    pub version: String,
+    pub slm_policy: Option<String>,     // metadata.policy u snapshotů z SLM
+}
+
+impl SnapshotInfo {
+    pub fn state_color(&self) -> &'static str {
+        match self.state.as_str() {
+            "SUCCESS" => "success",
+            "IN_PROGRESS" => "blue",
+            "PARTIAL" => "warning",
+            "FAILED" | "INCOMPATIBLE" => "danger",
+            _ => "secondary",
+        }
+    }
+
+    pub fn start_time_formatted(&self) -> String {
+        chrono::DateTime::from_timestamp_millis(self.start_time_ms)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    pub fn duration_formatted(&self) -> String {
+        let seconds = self.duration_ms / 1000;
+        if seconds >= 3600 {
+            format!("{}h {}m", seconds / 3600, (seconds % 3600) / 60)
+        } else if seconds >= 60 {
+            format!("{}m {}s", seconds / 60, seconds % 60)
+        } else {
+            format!("{}.{}s", seconds, (self.duration_ms % 1000) / 100)
+        }
+    }
+
+    pub fn indices_json(&self) -> String {
+        serde_json::to_string(&self.indices).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+/// SLM politika z `GET /_slm/policy`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SlmPolicyInfo {
+    pub id: String,
+    pub snapshot_name: String,
+    pub schedule: String,
+    pub repository: String,
+    pub indices: String,
+    pub retention: String,
+    pub last_success: Option<String>,   // "snapshot @ čas"
+    pub last_failure: Option<String>,
+    pub next_execution: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SnapshotsData {
+    pub repositories: Vec<SnapshotRepository>,
+    pub repository: String,             // vybraný repository
+    pub snapshots: Vec<SnapshotInfo>,
+    pub snapshots_error: Option<String>,
+    pub slm_policies: Vec<SlmPolicyInfo>,
+    pub slm_supported: bool,
+    pub snapshot_indices: String,       // předvyplněné indexy z hromadné akce v seznamu indexů
+    pub tab: String,                    // repositories | snapshots | slm
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RestoreProgressData {
+    pub indices: String,                // sledované indexy (čárkou oddělené)
+    pub shards: Vec<ShardRecovery>,
+    pub done: usize,
+}
+
+impl RestoreProgressData {
+    pub fn is_complete(&self) -> bool {
+        !self.shards.is_empty() && self.done == self.shards.len()
+    }
+
+    pub fn overall_percent(&self) -> String {
+        let total: u64 = self.shards.iter().map(|s| s.bytes_total).sum();
+        let recovered: u64 = self.shards.iter().map(|s| s.bytes_recovered).sum();
+        let percent = if total == 0 {
+            if self.is_complete() { 100.0 } else { 0.0 }
+        } else {
+            recovered as f64 / total as f64 * 100.0
+        };
+        format!("{:.1}", percent)
+    }
+}
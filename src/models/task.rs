@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use crate::utils::{format_duration_ms, format_number};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskInfo {
+    pub id: String,                     // "node_id:number"
+    pub node_id: String,
+    pub node_name: String,
+    pub action: String,                 // např. indices:data/write/reindex
+    pub task_type: String,              // transport, persistent, ...
+    pub description: String,
+    pub start_time_ms: u64,
+    pub running_time_nanos: u64,
+    pub cancellable: bool,
+    pub cancelled: bool,
+    pub parent_task_id: Option<String>,
+    pub progress: Option<TaskProgress>,
+    pub depth: usize,                   // zanoření v group_by=parents stromu
+    pub children_count: usize,
+}
+
+/// Průběh tasku z `status` (reindex, update_by_query, delete_by_query)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskProgress {
+    pub total: u64,
+    pub created: u64,
+    pub updated: u64,
+    pub deleted: u64,
+    pub noops: u64,
+    pub version_conflicts: u64,
+    pub batches: u64,
+}
+
+impl TaskProgress {
+    /// Počet již zpracovaných dokumentů
+    pub fn done(&self) -> u64 {
+        self.created + self.updated + self.deleted + self.noops + self.version_conflicts
+    }
+
+    /// Procento dokončení (0-100)
+    pub fn percent(&self) -> u8 {
+        (self.done() * 100)
+            .checked_div(self.total)
+            .unwrap_or(0)
+            .min(100) as u8
+    }
+
+    pub fn done_formatted(&self) -> String {
+        format_number(self.done())
+    }
+
+    pub fn total_formatted(&self) -> String {
+        format_number(self.total)
+    }
+}
+
+impl TaskInfo {
+    /// Vrátí running time jako human-readable formát
+    pub fn running_time_formatted(&self) -> String {
+        format_duration_ms(self.running_time_nanos / 1_000_000)
+    }
+
+    /// Vrátí čas spuštění tasku (UTC)
+    pub fn start_time_formatted(&self) -> String {
+        chrono::DateTime::from_timestamp_millis(self.start_time_ms as i64)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| "-".to_string())
+    }
+
+    /// Zkrácený popis pro tabulku
+    pub fn description_preview(&self) -> String {
+        if self.description.chars().count() > 120 {
+            format!("{}...", self.description.chars().take(120).collect::<String>())
+        } else {
+            self.description.clone()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TasksListData {
+    pub tasks: Vec<TaskInfo>,
+    pub nodes: Vec<String>,     // názvy nodů pro filtr
+    pub actions: String,        // filtr podle action pattern
+    pub node_filter: String,    // filtr podle nodu
+    pub total: usize,
+    pub cancellable: usize,
+    pub with_progress: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskDetail {
+    pub task: TaskInfo,
+    pub completed: bool,
+    pub raw: String, // JSON formatted
+}
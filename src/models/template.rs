@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+/// Druh šablony - composable (`_index_template`), legacy (`_template`) nebo component
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateKind {
+    Index,
+    Legacy,
+    Component,
+}
+
+impl TemplateKind {
+    pub fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "index" => Some(Self::Index),
+            "legacy" => Some(Self::Legacy),
+            "component" => Some(Self::Component),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Index => "index",
+            Self::Legacy => "legacy",
+            Self::Component => "component",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Index => "Index Template",
+            Self::Legacy => "Legacy Template",
+            Self::Component => "Component Template",
+        }
+    }
+
+    /// ES API cesta pro GET/PUT/DELETE šablony
+    pub fn api_path(&self, name: &str) -> String {
+        match self {
+            Self::Index => format!("/_index_template/{}", name),
+            Self::Legacy => format!("/_template/{}", name),
+            Self::Component => format!("/_component_template/{}", name),
+        }
+    }
+
+    /// Stránka se seznamem, na kterou se vrací editor
+    pub fn list_url(&self) -> &'static str {
+        match self {
+            Self::Index => "/templates/index",
+            Self::Legacy => "/templates/index?tab=legacy",
+            Self::Component => "/templates/component",
+        }
+    }
+}
+
+/// Řádek v seznamu šablon
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemplateSummary {
+    pub name: String,
+    pub kind: TemplateKind,
+    pub index_patterns: Vec<String>,
+    pub priority: Option<i64>,          // composable priority, legacy order
+    pub composed_of: Vec<String>,
+    pub version: Option<i64>,
+    pub has_settings: bool,
+    pub has_mappings: bool,
+    pub has_aliases: bool,
+    pub data_stream: bool,
+    pub managed: bool,                  // interní/spravované (název s tečkou nebo _meta.managed)
+    pub used_by: Vec<String>,           // u component templates: index templates, které ji skládají
+}
+
+impl TemplateSummary {
+    pub fn patterns_label(&self) -> String {
+        self.index_patterns.join(", ")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplatesData {
+    pub composable: Vec<TemplateSummary>,
+    pub legacy: Vec<TemplateSummary>,
+    pub composable_supported: bool,
+    pub tab: String,                    // composable | legacy
+    pub filter: String,
+    pub show_managed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComponentTemplatesData {
+    pub templates: Vec<TemplateSummary>,
+    pub filter: String,
+    pub show_managed: bool,
+}
+
+/// Data pro editor šablony (vytvoření i úprava)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplateEditorData {
+    pub kind: TemplateKind,
+    pub name: String,
+    pub is_new: bool,
+    pub index_patterns: String,         // čárkou oddělené
+    pub priority: String,               // priority / order
+    pub version: String,
+    pub composed_of: Vec<String>,
+    pub data_stream: bool,
+    pub template_json: String,          // settings / mappings / aliases
+    pub extra_json: String,             // ostatní top-level pole (_meta, ...)
+    pub component_names: Vec<String>,   // pro výběr composed_of
+}
+
+/// Výsledek simulace šablon pro nový index
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplateSimulateData {
+    pub index_name: String,
+    pub template_name: String,
+    pub applied_template: Option<String>,
+    pub settings: String,
+    pub mappings: String,
+    pub aliases: String,
+    pub overlapping: Vec<TemplateSummary>,
+    pub error: Option<String>,
+}
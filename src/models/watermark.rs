@@ -0,0 +1,88 @@
+use serde_json::Value;
+use crate::utils::{format_bytes, parse_size_to_bytes};
+
+/// Disk watermark - procento využití nebo absolutní minimum volného místa
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Watermark {
+    UsedPercent(f64),
+    FreeBytes(u64),
+}
+
+impl Watermark {
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(percent) = value.strip_suffix('%') {
+            return percent.trim().parse().ok().map(Watermark::UsedPercent);
+        }
+        if let Ok(ratio) = value.parse::<f64>() {
+            return Some(Watermark::UsedPercent(ratio * 100.0));
+        }
+        let bytes = parse_size_to_bytes(value);
+        (bytes > 0).then_some(Watermark::FreeBytes(bytes))
+    }
+
+    /// Watermark převedený na procento využití disku daného nodu
+    pub fn used_percent(&self, disk_total: u64) -> f64 {
+        match self {
+            Watermark::UsedPercent(percent) => *percent,
+            Watermark::FreeBytes(_) if disk_total == 0 => 100.0,
+            Watermark::FreeBytes(free) => 100.0 - *free as f64 * 100.0 / disk_total as f64,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Watermark::UsedPercent(percent) => format!("{}%", percent),
+            Watermark::FreeBytes(free) => format!("{} free", format_bytes(*free)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Watermarks {
+    pub low: Watermark,
+    pub high: Watermark,
+    pub flood_stage: Watermark,
+}
+
+impl Default for Watermarks {
+    fn default() -> Self {
+        Watermarks {
+            low: Watermark::UsedPercent(85.0),
+            high: Watermark::UsedPercent(90.0),
+            flood_stage: Watermark::UsedPercent(95.0),
+        }
+    }
+}
+
+impl Watermarks {
+    /// Z `_cluster/settings?include_defaults&flat_settings` (transient > persistent > defaults)
+    pub fn from_settings(settings: &Value) -> Self {
+        let lookup = |name: &str| {
+            let key = format!("cluster.routing.allocation.disk.watermark.{}", name);
+            ["transient", "persistent", "defaults"].iter()
+                .find_map(|scope| settings[scope][&key].as_str())
+                .and_then(Watermark::parse)
+        };
+        let defaults = Watermarks::default();
+        Watermarks {
+            low: lookup("low").unwrap_or(defaults.low),
+            high: lookup("high").unwrap_or(defaults.high),
+            flood_stage: lookup("flood_stage").unwrap_or(defaults.flood_stage),
+        }
+    }
+
+    /// Watermark podle názvu úrovně (`low`, `high`, `flood_stage`)
+    pub fn level(&self, name: &str) -> Option<Watermark> {
+        match name {
+            "low" => Some(self.low),
+            "high" => Some(self.high),
+            "flood_stage" => Some(self.flood_stage),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("low {} / high {} / flood stage {}", self.low.label(), self.high.label(), self.flood_stage.label())
+    }
+}
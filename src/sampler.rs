@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde_json::Value;
+
+use crate::db::Database;
+use crate::db::models::MetricSample;
+use crate::es::EsClient;
+use crate::es::api::ClusterHealth;
+use crate::handlers::AppState;
+
+/// `node_id` řádků s agregací za celý cluster
+pub const CLUSTER_NODE_ID: &str = "_cluster";
+
+/// Rozlišení úrovní a jejich retence v sekundách; každá úroveň vzniká agregací předchozí
+pub const TIERS: [(i64, i64); 3] = [
+    (30, 3 * 3600),         // raw vzorky - 3 hodiny
+    (300, 2 * 86400),       // 5 minut - 2 dny
+    (3600, 30 * 86400),     // 1 hodina - 30 dní
+];
+
+/// Endpoint se vzorkuje, dokud s ním uživatel v posledních 24 hodinách pracoval
+const ACTIVE_TIMEOUT: Duration = Duration::from_secs(24 * 3600);
+const SAMPLE_TIMEOUT: Duration = Duration::from_secs(20);
+/// Agregace a mazání podle retence běží každých N vzorkování (5 minut)
+const MAINTENANCE_EVERY: u64 = 10;
+
+/// Eviduje endpointy, se kterými uživatel pracuje (vybrané přes cookie)
+#[derive(Default)]
+pub struct MetricsSampler {
+    active: Mutex<HashMap<i64, Instant>>,
+}
+
+impl MetricsSampler {
+    /// Označí endpoint jako aktivní
+    pub fn touch(&self, endpoint_id: i64) {
+        if let Ok(mut active) = self.active.lock() {
+            active.insert(endpoint_id, Instant::now());
+        }
+    }
+
+    fn forget(&self, endpoint_id: i64) {
+        if let Ok(mut active) = self.active.lock() {
+            active.remove(&endpoint_id);
+        }
+    }
+
+    /// Endpointy k vzorkování (neaktivní vyřadí)
+    fn active_endpoints(&self) -> Vec<i64> {
+        let Ok(mut active) = self.active.lock() else {
+            return Vec::new();
+        };
+        active.retain(|_, last_used| last_used.elapsed() < ACTIVE_TIMEOUT);
+        active.keys().copied().collect()
+    }
+}
+
+/// Spustí vzorkování metrik na pozadí
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let raw_resolution = TIERS[0].0;
+        let mut interval = tokio::time::interval(Duration::from_secs(raw_resolution as u64));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut ticks: u64 = 0;
+
+        loop {
+            interval.tick().await;
+            let now = chrono::Utc::now().timestamp();
+            let ts = now / raw_resolution * raw_resolution;
+
+            for endpoint_id in state.sampler.active_endpoints() {
+                match tokio::time::timeout(SAMPLE_TIMEOUT, sample_endpoint(&state, endpoint_id, ts)).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => tracing::warn!("Failed to sample metrics for endpoint {}: {}", endpoint_id, e),
+                    Err(_) => tracing::warn!("Timeout sampling metrics for endpoint {}", endpoint_id),
+                }
+            }
+
+            ticks += 1;
+            if ticks.is_multiple_of(MAINTENANCE_EVERY)
+                && let Err(e) = maintain(&state.db, now).await
+            {
+                tracing::warn!("Metrics maintenance failed: {}", e);
+            }
+        }
+    });
+}
+
+async fn sample_endpoint(state: &AppState, endpoint_id: i64, ts: i64) -> anyhow::Result<()> {
+    let Some(endpoint) = state.db.get_endpoint(endpoint_id).await? else {
+        // Endpoint byl mezitím smazán
+        state.sampler.forget(endpoint_id);
+        return Ok(());
+    };
+
+    let password = state.db.get_endpoint_password(&endpoint).await;
+    let client = EsClient::new(
+        endpoint.url.clone(),
+        endpoint.insecure,
+        endpoint.username.clone(),
+        password,
+    )?;
+
+    let stats: Value = client.get("/_nodes/stats/os,jvm,fs").await?;
+    let health = client.cluster_health().await?;
+
+    let samples = build_samples(endpoint_id, ts, &stats, &health);
+    state.db.save_metric_samples(&samples).await?;
+
+    // Pravidla alertů se vyhodnocují nad stejným vzorkem
+    if let Err(e) = state.alerts.evaluate(&state.db, &endpoint, &client, &health, &samples, ts).await {
+        tracing::warn!("Failed to evaluate alert rules for endpoint {}: {}", endpoint_id, e);
+    }
+
+    Ok(())
+}
+
+fn percent(used: Option<u64>, total: Option<u64>) -> Option<f64> {
+    match (used, total) {
+        (Some(used), Some(total)) if total > 0 => Some(used as f64 * 100.0 / total as f64),
+        _ => None,
+    }
+}
+
+fn average(values: &[Option<f64>]) -> Option<f64> {
+    let present: Vec<f64> = values.iter().flatten().copied().collect();
+    if present.is_empty() {
+        None
+    } else {
+        Some(present.iter().sum::<f64>() / present.len() as f64)
+    }
+}
+
+/// Vzorky pro všechny nody a jeden agregovaný řádek za cluster
+fn build_samples(endpoint_id: i64, ts: i64, stats: &Value, health: &ClusterHealth) -> Vec<MetricSample> {
+    let raw_resolution = TIERS[0].0;
+    let mut samples = Vec::new();
+    let mut disk_used_total: u64 = 0;
+    let mut disk_total: u64 = 0;
+
+    if let Some(nodes) = stats["nodes"].as_object() {
+        for (node_id, node) in nodes {
+            let disk_available = node["fs"]["total"]["available_in_bytes"].as_u64();
+            let disk_size = node["fs"]["total"]["total_in_bytes"].as_u64();
+            let disk_used = disk_size.zip(disk_available).map(|(t, a)| t.saturating_sub(a));
+            if let (Some(used), Some(total)) = (disk_used, disk_size) {
+                disk_used_total += used;
+                disk_total += total;
+            }
+
+            samples.push(MetricSample {
+                endpoint_id,
+                node_id: node_id.clone(),
+                node_name: node["name"].as_str().unwrap_or(node_id).to_string(),
+                resolution: raw_resolution,
+                ts,
+                cpu_percent: node["os"]["cpu"]["percent"].as_f64(),
+                heap_percent: node["jvm"]["mem"]["heap_used_percent"].as_f64(),
+                ram_percent: percent(
+                    node["os"]["mem"]["used_in_bytes"].as_u64(),
+                    node["os"]["mem"]["total_in_bytes"].as_u64(),
+                ),
+                disk_percent: percent(disk_used, disk_size),
+                nodes: None,
+                active_shards: None,
+                unassigned_shards: None,
+            });
+        }
+    }
+
+    let cpu: Vec<Option<f64>> = samples.iter().map(|s| s.cpu_percent).collect();
+    let heap: Vec<Option<f64>> = samples.iter().map(|s| s.heap_percent).collect();
+    let ram: Vec<Option<f64>> = samples.iter().map(|s| s.ram_percent).collect();
+
+    samples.push(MetricSample {
+        endpoint_id,
+        node_id: CLUSTER_NODE_ID.to_string(),
+        node_name: health.cluster_name.clone(),
+        resolution: raw_resolution,
+        ts,
+        cpu_percent: average(&cpu),
+        heap_percent: average(&heap),
+        ram_percent: average(&ram),
+        // Disk za cluster jako poměr součtů, ne průměr procent
+        disk_percent: percent(Some(disk_used_total), Some(disk_total)),
+        nodes: Some(health.number_of_nodes as i64),
+        active_shards: Some(health.active_shards as i64),
+        unassigned_shards: Some(health.unassigned_shards as i64),
+    });
+
+    samples
+}
+
+/// Agreguje vzorky do hrubších úrovní a smaže data po retenci
+async fn maintain(db: &Database, now: i64) -> anyhow::Result<()> {
+    for pair in TIERS.windows(2) {
+        let (source, source_retention) = pair[0];
+        let (target, _) = pair[1];
+        // Přepočítá kompletní buckety, pro které ještě existují všechna zdrojová data
+        // (bucket částečně smazaný retencí by přepsal dřívější úplnou agregaci)
+        let since = (now - source_retention + target - 1) / target * target;
+        let until = now / target * target;
+        db.rollup_metric_samples(source, target, since, until).await?;
+    }
+
+    for (resolution, retention) in TIERS {
+        db.purge_metric_samples(resolution, now - retention).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn health() -> ClusterHealth {
+        ClusterHealth {
+            cluster_name: "test".to_string(),
+            status: "green".to_string(),
+            timed_out: false,
+            number_of_nodes: 2,
+            number_of_data_nodes: 2,
+            active_primary_shards: 5,
+            active_shards: 10,
+            relocating_shards: 0,
+            initializing_shards: 0,
+            unassigned_shards: 1,
+        }
+    }
+
+    #[test]
+    fn test_builds_node_and_cluster_samples() {
+        let stats = json!({
+            "nodes": {
+                "a": {
+                    "name": "node-a",
+                    "os": { "cpu": { "percent": 20 }, "mem": { "used_in_bytes": 50, "total_in_bytes": 100 } },
+                    "jvm": { "mem": { "heap_used_percent": 40 } },
+                    "fs": { "total": { "total_in_bytes": 100, "available_in_bytes": 75 } }
+                },
+                "b": {
+                    "name": "node-b",
+                    "os": { "cpu": { "percent": 60 } },
+                    "jvm": { "mem": { "heap_used_percent": 60 } },
+                    "fs": { "total": { "total_in_bytes": 300, "available_in_bytes": 100 } }
+                }
+            }
+        });
+
+        let samples = build_samples(1, 90, &stats, &health());
+        assert_eq!(samples.len(), 3);
+
+        let a = samples.iter().find(|s| s.node_id == "a").unwrap();
+        assert_eq!(a.node_name, "node-a");
+        assert_eq!(a.ram_percent, Some(50.0));
+        assert_eq!(a.disk_percent, Some(25.0));
+
+        let b = samples.iter().find(|s| s.node_id == "b").unwrap();
+        assert_eq!(b.ram_percent, None);
+
+        let cluster = samples.iter().find(|s| s.node_id == CLUSTER_NODE_ID).unwrap();
+        assert_eq!(cluster.cpu_percent, Some(40.0));
+        assert_eq!(cluster.heap_percent, Some(50.0));
+        assert_eq!(cluster.ram_percent, Some(50.0));
+        assert_eq!(cluster.disk_percent, Some(225.0 * 100.0 / 400.0));
+        assert_eq!(cluster.active_shards, Some(10));
+        assert_eq!(cluster.unassigned_shards, Some(1));
+    }
+
+    #[test]
+    fn test_forgets_inactive_endpoints() {
+        let sampler = MetricsSampler::default();
+        sampler.touch(1);
+        sampler.touch(2);
+        sampler.forget(2);
+        assert_eq!(sampler.active_endpoints(), vec![1]);
+    }
+}
@@ -36,6 +36,27 @@ pub struct NodeDetailTemplate {
     pub node_id: String,
 }
 
+#[derive(Template)]
+#[template(path = "node_drain.html")]
+pub struct NodeDrainTemplate {
+    pub data: Option<crate::models::NodeDrainStatus>,
+    pub node_id: String,
+}
+
+#[derive(Template)]
+#[template(path = "node_diagnostics.html")]
+pub struct NodeDiagnosticsTemplate {
+    pub data: Option<crate::models::NodeDiagnostics>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "node_hot_threads.html")]
+pub struct NodeHotThreadsTemplate {
+    pub report: Option<crate::models::HotThreadsReport>,
+    pub error: Option<String>,
+}
+
 #[derive(Template)]
 #[template(path = "indices.html")]
 pub struct IndicesTemplate {
@@ -49,6 +70,13 @@ pub struct IndicesTableTemplate {
     pub data: Option<crate::models::IndicesListData>,
 }
 
+#[derive(Template)]
+#[template(path = "index_create.html")]
+pub struct IndexCreateTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::handlers::index_create::IndexCreateData>,
+}
+
 #[derive(Template)]
 #[template(path = "index_detail.html")]
 pub struct IndexDetailTemplate {
@@ -76,9 +104,222 @@ pub struct ShardsTemplate {
     pub pattern: String,
 }
 
+#[derive(Template)]
+#[template(path = "mappings.html")]
+pub struct MappingsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::MappingExplorerData>,
+    pub pattern: String,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "aliases.html")]
+pub struct AliasesTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::AliasesData>,
+}
+
+#[derive(Template)]
+#[template(path = "data_streams.html")]
+pub struct DataStreamsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::DataStreamsData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "ilm.html")]
+pub struct IlmPageTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::IlmPoliciesData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "ilm_policy_editor.html")]
+pub struct IlmPolicyEditorTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::IlmPolicyEditorData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "allocation_explain.html")]
+pub struct AllocationExplainTemplate {
+    pub data: Option<crate::models::AllocationExplain>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "unassigned_shards.html")]
+pub struct UnassignedShardsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::UnassignedSummaryData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "reroute_preview.html")]
+pub struct ReroutePreviewTemplate {
+    pub data: crate::models::ReroutePreviewData,
+}
+
+#[derive(Template)]
+#[template(path = "recovery.html")]
+pub struct RecoveryTemplate {
+    pub ctx: PageContext,
+}
+
+#[derive(Template)]
+#[template(path = "recovery_table.html")]
+pub struct RecoveryTableTemplate {
+    pub data: Option<crate::models::RecoveryMonitorData>,
+}
+
+#[derive(Template)]
+#[template(path = "advisor.html")]
+pub struct AdvisorTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::AdvisorData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "alerts.html")]
+pub struct AlertsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::AlertsData>,
+    pub error: Option<String>,
+    pub kinds: Vec<(&'static str, &'static str)>,
+}
+
+#[derive(Template)]
+#[template(path = "alerts_badge.html")]
+pub struct AlertsBadgeTemplate {
+    pub open: usize,
+}
+
+#[derive(Template)]
+#[template(path = "cluster_settings.html")]
+pub struct ClusterSettingsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::ClusterSettingsData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "snapshots.html")]
+pub struct SnapshotsTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::SnapshotsData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "snapshot_restore.html")]
+pub struct SnapshotRestoreTemplate {
+    pub ctx: PageContext,
+    pub repository: String,
+    pub snapshot: String,
+}
+
+#[derive(Template)]
+#[template(path = "snapshot_recovery_table.html")]
+pub struct SnapshotRecoveryTableTemplate {
+    pub data: Option<crate::models::RestoreProgressData>,
+    pub repository: String,
+    pub snapshot: String,
+}
+
+#[derive(Template)]
+#[template(path = "pipelines.html")]
+pub struct PipelinesTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::PipelinesData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "pipeline_editor.html")]
+pub struct PipelineEditorTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::PipelineEditorData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "pipeline_simulate.html")]
+pub struct PipelineSimulateTemplate {
+    pub data: crate::models::PipelineSimulateData,
+}
+
+#[derive(Template)]
+#[template(path = "index_templates.html")]
+pub struct IndexTemplatesTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::TemplatesData>,
+}
+
+#[derive(Template)]
+#[template(path = "component_templates.html")]
+pub struct ComponentTemplatesTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::ComponentTemplatesData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "template_editor.html")]
+pub struct TemplateEditorTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::TemplateEditorData>,
+    pub error: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "template_simulate.html")]
+pub struct TemplateSimulateTemplate {
+    pub ctx: PageContext,
+    pub data: crate::models::TemplateSimulateData,
+}
+
+#[derive(Template)]
+#[template(path = "analyzer.html")]
+pub struct AnalyzerTemplate {
+    pub ctx: PageContext,
+    pub data: crate::models::AnalyzerPageData,
+}
+
+#[derive(Template)]
+#[template(path = "analyze_tokens.html")]
+pub struct AnalyzeTokensTemplate {
+    pub data: crate::handlers::analyze::AnalyzeCompareData,
+}
+
 #[derive(Template)]
 #[template(path = "console.html")]
 pub struct ConsoleTemplate {
     pub ctx: PageContext,
     pub data: Option<crate::handlers::console::ConsoleData>,
 }
+
+#[derive(Template)]
+#[template(path = "tasks.html")]
+pub struct TasksTemplate {
+    pub ctx: PageContext,
+    pub data: Option<crate::models::TasksListData>,
+}
+
+#[derive(Template)]
+#[template(path = "tasks_table.html")]
+pub struct TasksTableTemplate {
+    pub data: Option<crate::models::TasksListData>,
+}
+
+#[derive(Template)]
+#[template(path = "task_detail.html")]
+pub struct TaskDetailTemplate {
+    pub data: Option<crate::models::TaskDetail>,
+    pub task_id: String,
+}
@@ -38,6 +38,31 @@ pub fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Formátuje dobu trvání v milisekundách na human-readable formát
+/// Příklad: 1500 -> "1.5s", 3723000 -> "1h 2m 3s"
+pub fn format_duration_ms(ms: u64) -> String {
+    if ms < 1000 {
+        return format!("{}ms", ms);
+    }
+    if ms < 60_000 {
+        return format!("{:.1}s", ms as f64 / 1000.0);
+    }
+
+    let total_secs = ms / 1000;
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m {}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {}s", minutes, seconds)
+    }
+}
+
 /// Parsuje string s velikostí (např. "1.2gb") na bytes
 pub fn parse_size_to_bytes(size: &str) -> u64 {
     let size = size.trim().to_lowercase();
@@ -94,6 +119,15 @@ mod tests {
         assert_eq!(format_bytes(1073741824), "1.00 GB");
     }
 
+    #[test]
+    fn test_format_duration_ms() {
+        assert_eq!(format_duration_ms(250), "250ms");
+        assert_eq!(format_duration_ms(1500), "1.5s");
+        assert_eq!(format_duration_ms(125_000), "2m 5s");
+        assert_eq!(format_duration_ms(3_723_000), "1h 2m 3s");
+        assert_eq!(format_duration_ms(90_061_000), "1d 1h 1m");
+    }
+
     #[test]
     fn test_parse_size_to_bytes() {
         assert_eq!(parse_size_to_bytes("1kb"), 1024);
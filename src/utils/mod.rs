@@ -1,7 +1,9 @@
 pub mod browser;
 pub mod format;
 pub mod color;
+pub mod url;
 
 pub use browser::open_browser;
-pub use format::{format_number, format_bytes, parse_size_to_bytes};
+pub use format::{format_number, format_bytes, format_duration_ms, parse_size_to_bytes};
 pub use color::{generate_index_color, shard_state_color, get_text_color_for_background};
+pub use url::encode_component;
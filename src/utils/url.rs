@@ -0,0 +1,24 @@
+/// Percent-encoding hodnoty do cesty nebo query stringu (ponechá jen nerezervované znaky)
+pub fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_component() {
+        assert_eq!(encode_component("logs-2024.01.01"), "logs-2024.01.01");
+        assert_eq!(encode_component("a&b#c"), "a%26b%23c");
+        assert_eq!(encode_component("indices:admin/*"), "indices%3Aadmin%2F%2A");
+        assert_eq!(encode_component("é"), "%C3%A9");
+    }
+}